@@ -1,26 +1,34 @@
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use cosmwasm_std::{
+	to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdError,
+	StdResult, Uint128,
+};
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr, env::MinimalEnvInfo, extentions::timestamp::TimestampExtentions,
 	storage::item::StoredItem,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 use cw_utils::nonpayable;
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::{
 	error::CourtContractError,
 	msg::{
-		CourtAdminExecuteMsg, CourtExecuteMsg, CourtInstantiateMsg, CourtMigrateMsg, CourtQueryMsg, CourtQueryResponseDenom, CourtQueryResponseTotalSupply, CourtQueryResponseTransactionProposal, CourtQueryResponseUserVote, CourtQueryUserWithActiveProposal
+		CourtAdminExecuteMsg, CourtExecuteMsg, CourtInstantiateMsg, CourtMigrateMsg, CourtQueryMsg, CourtQueryResponseDenom, CourtQueryResponseOptimisticAllowlist, CourtQueryResponseProposalOption, CourtQueryResponseProposalTally, CourtQueryResponseProposals, CourtQueryResponseSimulatedProposal, CourtQueryResponseTotalSupply, CourtQueryResponseTransactionProposal, CourtQueryResponseUserVote, CourtQueryUserWithActiveProposal
 	},
-	proposed_msg::ProposedCourtMsgJsonable,
+	proposed_msg::{parse_contract_addr, ProposedCourtMsg, ProposedCourtMsgJsonable},
 	state::{
 		app::{
-			get_transaction_proposal_info_vec, get_transaction_proposal_messages_vec, CourtAppConfig,
-			CourtAppConfigJsonable,
+			get_proposer_proposal_ids, get_target_proposal_ids, get_transaction_proposal_info_vec,
+			get_transaction_proposal_messages_vec, get_transaction_proposal_metadata_store,
+			get_transaction_proposal_option_votes_store, get_transaction_proposal_options_store,
+			get_unfinalized_proposal_id_set, CourtAppConfig, CourtAppConfigJsonable, CourtOptimisticAllowlist,
+			CourtProposalTrackJsonable, CourtProposalTracks, CourtStargateAllowlist, TransactionProposalInfo,
+			TransactionProposalOption, TransactionProposalStatus,
 		},
 		user::{
-			get_all_proposal_user_votes, get_all_user_active_proposal_ids, get_proposal_user_vote_store,
-			get_user_active_proposal_id_set, get_user_stats_store, CourtUserStatsJsonable, CourtUserVoteInfoJsonable,
+			get_all_proposal_user_votes, get_all_user_active_proposal_ids, get_proposal_user_signed_vote_nonce_store,
+			get_proposal_user_vote_store, get_user_active_proposal_id_set, get_user_stats_store, CourtTotalStakedVotes,
+			CourtUserStatsJsonable, CourtUserVoteInfoJsonable, CourtUserVoteSplit,
 		},
 	},
 	workarounds::{mint_to_workaround, total_supply_workaround},
@@ -28,9 +36,13 @@ use crate::{
 
 use self::{
 	admin::AdminMsgExecutor,
-	permissionless::{process_deactivate_votes, process_execute_proposal},
-	shares::{votes_denom, VOTES_SUBDENOM},
-	user::{process_propose_transaction, process_stake, process_unstake, process_vote},
+	permissionless::{process_deactivate_votes, process_execute_proposal, proposal_cosmos_msgs},
+	shares::{quorum_supply, votes_denom, VOTES_SUBDENOM},
+	user::{
+		process_commit_vote, process_propose_multiple_choice, process_propose_signal, process_propose_transaction,
+		process_reveal_vote, process_stake, process_submit_signed_votes, process_unstake, process_vote,
+		process_vote_choice, process_vote_many, process_vote_weighted, validate_proposal,
+	},
 };
 
 pub mod admin;
@@ -51,17 +63,35 @@ pub fn instantiate(
 ) -> Result<Response<SeiMsg>, CourtContractError> {
 	nonpayable(&msg_info)?;
 	set_contract_version(deps.storage, COURT_CONTRACT_NAME, COURT_CONTRACT_VERSION)?;
-	CourtAppConfig::try_from(&CourtAppConfigJsonable {
+	let app_config = CourtAppConfig::try_from(&CourtAppConfigJsonable {
 		allow_new_proposals: true,
 		minimum_vote_proposal_percent: msg.minimum_vote_proposal_percent,
 		minimum_vote_turnout_percent: msg.minimum_vote_turnout_percent,
 		minimum_vote_pass_percent: msg.minimum_vote_pass_percent,
+		veto_threshold_percent: msg.veto_threshold_percent,
+		optimistic_opposition_threshold_percent: msg.optimistic_opposition_threshold_percent,
+		expedited_minimum_vote_turnout_percent: msg.expedited_minimum_vote_turnout_percent,
+		expedited_minimum_vote_pass_percent: msg.expedited_minimum_vote_pass_percent,
+		expedited_voting_period_seconds: msg.expedited_voting_period_seconds,
+		min_proposal_expiry_time_seconds: msg.min_proposal_expiry_time_seconds,
 		max_proposal_expiry_time_seconds: msg.max_proposal_expiry_time_seconds,
 		execution_expiry_time_seconds: msg.execution_expiry_time_seconds,
+		quiet_ending_window_seconds: msg.quiet_ending_window_seconds,
+		quiet_ending_extension_seconds: msg.quiet_ending_extension_seconds,
+		max_quiet_ending_extensions: msg.max_quiet_ending_extensions,
+		min_stake_age_seconds: msg.min_stake_age_seconds,
+		max_stake_lock_seconds: msg.max_stake_lock_seconds,
+		max_stake_lock_vote_multiplier_percent: msg.max_stake_lock_vote_multiplier_percent,
+		quadratic_voting: msg.quadratic_voting,
+		quorum_supply: msg.quorum_supply,
+		max_user_votes: msg.max_user_votes,
+		max_user_votes_bps: msg.max_user_votes_bps,
+		reveal_period_seconds: msg.reveal_period_seconds,
 		last_config_change_timestamp_ms: env.block.time.millis(),
 		admin: msg.admin,
-	})?
-	.save()?;
+	})?;
+	app_config.validate()?;
+	app_config.save()?;
 	let new_denom = votes_denom(&env);
 	let vote_share_symbol_lowercase = msg.vote_share_symbol.to_ascii_lowercase();
 	let vote_share_symbol_uppercase = msg.vote_share_symbol.to_ascii_uppercase();
@@ -113,26 +143,16 @@ pub fn execute(
 	msg_info: MessageInfo,
 	msg: CourtExecuteMsg,
 ) -> Result<Response<SeiMsg>, CourtContractError> {
+	let api = deps.api;
 	let env_info = MinimalEnvInfo::from_deps_mut(deps, env);
 	Ok(match msg {
 		CourtExecuteMsg::Admin(admin_msg) => {
 			//deps.querier.query(request)
 			let mut admin_executor = AdminMsgExecutor::new(env_info, &msg_info)?;
 			match admin_msg {
-				CourtAdminExecuteMsg::ChangeConfig {
-					minimum_vote_proposal_percent,
-					minimum_vote_turnout_percent,
-					minimum_vote_pass_percent,
-					max_proposal_expiry_time_seconds,
-					execution_expiry_time_seconds,
-				} => admin_executor.process_change_config(
-					&msg_info,
-					minimum_vote_proposal_percent,
-					minimum_vote_turnout_percent,
-					minimum_vote_pass_percent,
-					max_proposal_expiry_time_seconds,
-					execution_expiry_time_seconds,
-				)?,
+				CourtAdminExecuteMsg::ChangeConfig(changes) => {
+					admin_executor.process_change_config(&msg_info, changes)?
+				}
 				CourtAdminExecuteMsg::ChangeAdmin { admin } => admin_executor.process_change_admin(&msg_info, admin)?,
 				CourtAdminExecuteMsg::AllowNewProposals { allowed } => {
 					admin_executor.process_allow_new_proposals(&msg_info, allowed)?
@@ -140,11 +160,50 @@ pub fn execute(
 				CourtAdminExecuteMsg::MintShares { receiver, amount } => {
 					admin_executor.process_mint_shares(&msg_info, receiver, amount)?
 				}
+				CourtAdminExecuteMsg::AllowStargateMessages { type_urls, allowed } => {
+					admin_executor.process_allow_stargate_messages(&msg_info, type_urls, allowed)?
+				}
+				CourtAdminExecuteMsg::AllowOptimisticProposals {
+					proposers,
+					msg_kinds,
+					allowed,
+				} => admin_executor.process_allow_optimistic_proposals(&msg_info, proposers, msg_kinds, allowed)?,
+				CourtAdminExecuteMsg::SetProposalTrack { track } => {
+					admin_executor.process_set_proposal_track(&msg_info, track)?
+				}
+				CourtAdminExecuteMsg::RemoveProposalTrack { name } => {
+					admin_executor.process_remove_proposal_track(&msg_info, name)?
+				}
 			}
 		}
-		CourtExecuteMsg::Stake => process_stake(env_info, msg_info)?,
+		CourtExecuteMsg::Stake => process_stake(env_info, msg_info, 0)?,
+		CourtExecuteMsg::StakeLocked { lock_seconds } => process_stake(env_info, msg_info, lock_seconds)?,
 		CourtExecuteMsg::Unstake => process_unstake(env_info, msg_info)?,
 		CourtExecuteMsg::Vote { id, vote } => process_vote(env_info, msg_info, id, vote)?,
+		CourtExecuteMsg::VoteWeighted {
+			id,
+			approve,
+			oppose,
+			abstain,
+		} => process_vote_weighted(
+			env_info,
+			msg_info,
+			id,
+			CourtUserVoteSplit {
+				approve_bps: approve,
+				oppose_bps: oppose,
+				abstain_bps: abstain,
+			},
+		)?,
+		CourtExecuteMsg::VoteChoice { id, option } => process_vote_choice(env_info, msg_info, id, option)?,
+		CourtExecuteMsg::VoteMany { votes, skip_inactive } => {
+			process_vote_many(env_info, msg_info, votes, skip_inactive)?
+		}
+		CourtExecuteMsg::SubmitSignedVotes { votes, skip_invalid } => {
+			process_submit_signed_votes(env_info, api, msg_info, votes, skip_invalid)?
+		}
+		CourtExecuteMsg::CommitVote { id, hash } => process_commit_vote(env_info, msg_info, id, hash)?,
+		CourtExecuteMsg::RevealVote { id, vote, salt } => process_reveal_vote(env_info, msg_info, id, vote, salt)?,
 		CourtExecuteMsg::DeactivateVotes { user, limit } => process_deactivate_votes(
 			env_info,
 			msg_info,
@@ -154,7 +213,29 @@ pub fn execute(
 		CourtExecuteMsg::ProposeTransaction {
 			msgs,
 			expiry_time_seconds,
-		} => process_propose_transaction(env_info, msg_info, msgs, expiry_time_seconds)?,
+			metadata,
+			optimistic,
+			expedited,
+			private,
+		} => process_propose_transaction(
+			env_info,
+			msg_info,
+			msgs,
+			expiry_time_seconds,
+			metadata,
+			optimistic,
+			expedited,
+			private,
+		)?,
+		CourtExecuteMsg::ProposeMultipleChoice {
+			options,
+			expiry_time_seconds,
+			metadata,
+		} => process_propose_multiple_choice(env_info, msg_info, options, expiry_time_seconds, metadata)?,
+		CourtExecuteMsg::ProposeSignal {
+			metadata,
+			expiry_time_seconds,
+		} => process_propose_signal(env_info, msg_info, metadata, expiry_time_seconds)?,
 		CourtExecuteMsg::ExecuteProposal { id } => process_execute_proposal(env_info, msg_info, id)?,
 	})
 }
@@ -162,19 +243,24 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 #[inline]
 pub fn migrate(
-	_deps: DepsMut<SeiQueryWrapper>,
+	deps: DepsMut<SeiQueryWrapper>,
 	_env: Env,
 	_msg: CourtMigrateMsg,
 ) -> Result<Response<SeiMsg>, CourtContractError> {
-	// let contract_version = get_contract_version(deps.storage)?;
-	//contract_version.
-	todo!();
-	// cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	let contract_version = get_contract_version(deps.storage)?;
+	// Earlier versions stored config, proposals, and user stats with different layouts which can't be read as-is
+	if contract_version.contract != COURT_CONTRACT_NAME || contract_version.version != COURT_CONTRACT_VERSION {
+		return Err(CourtContractError::UnsupportedMigration {
+			contract: contract_version.contract,
+			version: contract_version.version,
+		});
+	}
+	Ok(Response::new())
 }
 
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 #[inline]
-pub fn query(_deps: Deps, env: Env, msg: CourtQueryMsg) -> Result<Binary, CourtContractError> {
+pub fn query(deps: Deps, env: Env, msg: CourtQueryMsg) -> Result<Binary, CourtContractError> {
 	Ok(match msg {
 		CourtQueryMsg::Config => to_json_binary(&CourtAppConfigJsonable::try_from(
 			CourtAppConfig::load_non_empty()?.as_ref(),
@@ -183,33 +269,54 @@ pub fn query(_deps: Deps, env: Env, msg: CourtQueryMsg) -> Result<Binary, CourtC
 			votes: votes_denom(&env),
 		})?,
 		CourtQueryMsg::TotalSupply => to_json_binary(&CourtQueryResponseTotalSupply {
-			votes: total_supply_workaround(&votes_denom(&env))
+			votes: total_supply_workaround(&votes_denom(&env)),
+			staked_votes: CourtTotalStakedVotes::load_or_default()?.votes.into(),
 		})?,
 		CourtQueryMsg::ProposalAmount => to_json_binary(&(get_transaction_proposal_info_vec().len() as u32))?,
-		CourtQueryMsg::GetProposal { id } => {
+		CourtQueryMsg::StargateAllowlist => to_json_binary(&CourtStargateAllowlist::load_or_default()?.type_urls)?,
+		CourtQueryMsg::OptimisticAllowlist => {
+			let optimistic_allowlist = CourtOptimisticAllowlist::load_or_default()?;
+			to_json_binary(&CourtQueryResponseOptimisticAllowlist {
+				proposers: optimistic_allowlist
+					.proposers
+					.into_iter()
+					.map(Addr::try_from)
+					.collect::<Result<_, _>>()?,
+				msg_kinds: optimistic_allowlist.msg_kinds,
+			})?
+		}
+		CourtQueryMsg::ProposalTracks => to_json_binary(
+			&CourtProposalTracks::load_or_default()?
+				.tracks
+				.into_iter()
+				.map(CourtProposalTrackJsonable::try_from)
+				.collect::<Result<Vec<_>, _>>()?,
+		)?,
+		CourtQueryMsg::GetProposal { id, decode, at_time_ms } => {
 			let app_config = CourtAppConfig::load_non_empty()?;
-			let total_supply = total_supply_workaround(&votes_denom(&env));
-			let proposal_msg_vec = get_transaction_proposal_messages_vec();
+			let proposal_tracks = CourtProposalTracks::load_or_default()?;
+			let current_timestamp_ms = at_time_ms.unwrap_or(env.block.time.millis());
 			to_json_binary(
 				&get_transaction_proposal_info_vec()
 					.get(id)?
 					.map(|info| -> Result<_, StdError> {
-						Ok(CourtQueryResponseTransactionProposal {
-							proposal_id: id as u32,
-							status: info.status(env.block.time.millis(), total_supply.u128(), &app_config),
-							info: info.as_ref().try_into()?,
-							messages: proposal_msg_vec
-								.get(id as u32)?
+						let mut proposal = transaction_proposal_response(
+							id,
+							&info,
+							info.status(
+								current_timestamp_ms,
+								&proposal_tracks.app_config_for(info.track(), &app_config),
+							),
+							get_transaction_proposal_messages_vec()
+								.get(id)?
 								.unwrap_or_default()
-								.into_inner()
-								.into_iter()
-								.map(|v| {
-									let mut v_jsonable = ProposedCourtMsgJsonable::try_from(v)?;
-									v_jsonable.make_pretty()?;
-									Ok(v_jsonable)
-								})
-								.collect::<Result<Vec<_>, StdError>>()?,
-						})
+								.into_inner(),
+							transaction_proposal_options(id, &info)?,
+						)?;
+						if decode.unwrap_or_default() {
+							proposal.decoded_messages = Some(proposal.messages.iter().map(|v| v.decode()).collect());
+						}
+						Ok(proposal)
 					})
 					.transpose()?,
 			)?
@@ -218,49 +325,236 @@ pub fn query(_deps: Deps, env: Env, msg: CourtQueryMsg) -> Result<Binary, CourtC
 			skip,
 			limit,
 			descending,
+			after,
+			filter,
+			omit_messages,
+			at_time_ms,
 		} => {
 			let app_config = CourtAppConfig::load_non_empty()?;
-			let total_supply = total_supply_workaround(&votes_denom(&env));
+			let proposal_tracks = CourtProposalTracks::load_or_default()?;
+			let current_timestamp_ms = at_time_ms.unwrap_or(env.block.time.millis());
+			let proposal_info_vec = get_transaction_proposal_info_vec();
 			let proposal_msg_vec = get_transaction_proposal_messages_vec();
+			let filter = filter.unwrap_or_default();
+			let proposer = filter.proposer.as_ref().map(SeiCanonicalAddr::try_from).transpose()?;
+			let target = filter.target.as_deref().map(parse_contract_addr).transpose()?;
+			let omit_messages = omit_messages.unwrap_or_default();
 
-			let iter = get_transaction_proposal_info_vec()
-				.into_iter()
-				.enumerate()
-				.map(|(index, info_result)| {
-					let info = info_result?;
-					Ok(CourtQueryResponseTransactionProposal {
-						proposal_id: index as u32,
-						status: info.status(env.block.time.millis(), total_supply.u128(), &app_config),
-						info: info.as_ref().try_into()?,
-						messages: proposal_msg_vec
-							.get(index as u32)?
-							.unwrap_or_default()
-							.into_inner()
-							.into_iter()
-							.map(|v| {
-								let mut v_jsonable = ProposedCourtMsgJsonable::try_from(v)?;
-								v_jsonable.make_pretty()?;
-								Ok(v_jsonable)
-							})
-							.collect::<Result<Vec<_>, StdError>>()?,
-					})
-				});
-			to_json_binary(&if descending {
-				iter.rev()
-					.skip(skip.unwrap_or(0) as usize)
-					.take(limit.unwrap_or(u32::MAX) as usize)
-					.collect::<StdResult<Vec<CourtQueryResponseTransactionProposal>>>()?
+			// start is inclusive, end is exclusive
+			let (start, end) = match after {
+				Some(after) if descending => (None, Some(after)),
+				Some(after) => (Some(after.saturating_add(1)), None),
+				None => (None, None),
+			};
+			let mut skip = skip.unwrap_or(0);
+			let limit = limit.unwrap_or(u32::MAX) as usize;
+			// Use the most specific index available, any other criteria are checked below.
+			let (proposal_ids, scan_limit): (Box<dyn DoubleEndedIterator<Item = u32>>, usize) =
+				if let Some(proposer) = proposer.clone() {
+					(Box::new(get_proposer_proposal_ids(proposer, start, end)?), usize::MAX)
+				} else if let Some(target) = target.clone() {
+					(Box::new(get_target_proposal_ids(target, start, end)?), usize::MAX)
+				} else if current_timestamp_ms >= env.block.time.millis()
+					&& filter.status == Some(TransactionProposalStatus::Pending)
+				{
+					// Not used for "passed" as signalling proposals are removed from this index once they've passed
+					(
+						Box::new(get_unfinalized_proposal_id_set().iter_range(start, end)?),
+						usize::MAX,
+					)
+				} else {
+					let proposal_count = proposal_info_vec.len();
+					(
+						Box::new(start.unwrap_or(0)..end.unwrap_or(proposal_count).min(proposal_count)),
+						// Statuses change as time passes, so they can't be indexed. Proposals which don't match still
+						// count towards the limit so that the amount of proposals read is bounded.
+						if filter.status.is_some() { limit } else { usize::MAX },
+					)
+				};
+			let proposal_ids: Box<dyn Iterator<Item = u32>> = if descending {
+				Box::new(proposal_ids.rev())
 			} else {
-				iter.skip(skip.unwrap_or(0) as usize)
-					.take(limit.unwrap_or(u32::MAX) as usize)
-					.collect::<StdResult<Vec<CourtQueryResponseTransactionProposal>>>()?
+				Box::new(proposal_ids)
+			};
+
+			let mut result = Vec::new();
+			let mut next_after = None;
+			let mut last_proposal_id = None;
+			let mut scanned = 0;
+			for proposal_id in proposal_ids {
+				if result.len() >= limit || scanned >= scan_limit {
+					// There's at least one more proposal which could match
+					next_after = last_proposal_id;
+					break;
+				}
+				scanned += 1;
+				last_proposal_id = Some(proposal_id);
+				let Some(info) = proposal_info_vec.get(proposal_id)? else {
+					continue;
+				};
+				let status = info.status(
+					current_timestamp_ms,
+					&proposal_tracks.app_config_for(info.track(), &app_config),
+				);
+				if filter.status.is_some_and(|filter_status| filter_status != status)
+					|| proposer.as_ref().is_some_and(|proposer| *proposer != info.proposer)
+				{
+					continue;
+				}
+				let mut messages = if omit_messages && target.is_none() {
+					Vec::new()
+				} else {
+					proposal_msg_vec.get(proposal_id)?.unwrap_or_default().into_inner()
+				};
+				let mut options = transaction_proposal_options(proposal_id, &info)?;
+				if target.as_ref().is_some_and(|target| {
+					!messages
+						.iter()
+						.chain(options.iter().flat_map(|(option, _)| option.messages.iter()))
+						.any(|msg| msg.target_contract().as_ref() == Some(target))
+				}) {
+					continue;
+				}
+				if skip > 0 {
+					skip -= 1;
+					continue;
+				}
+				if omit_messages {
+					messages.clear();
+					for (option, _) in options.iter_mut() {
+						option.messages.clear();
+					}
+				}
+				result.push(transaction_proposal_response(
+					proposal_id,
+					&info,
+					status,
+					messages,
+					options,
+				)?);
+			}
+			to_json_binary(&CourtQueryResponseProposals {
+				proposals: result,
+				next_after,
+			})?
+		}
+		CourtQueryMsg::ProposalTally { id, at_time_ms } => {
+			let app_config = CourtAppConfig::load_non_empty()?;
+			let current_timestamp_ms = at_time_ms.unwrap_or(env.block.time.millis());
+			// Only multiple-choice proposals have these
+			let option_votes = get_transaction_proposal_option_votes_store()
+				.get(&id)?
+				.unwrap_or_default()
+				.into_inner();
+			let proposal_tracks = CourtProposalTracks::load_or_default()?;
+			to_json_binary(&get_transaction_proposal_info_vec().get(id)?.map(|info| {
+				let app_config = proposal_tracks.app_config_for(info.track(), &app_config);
+				let timelock_expiry_timestamp_ms =
+					proposal_tracks.timelock_expiry_timestamp_ms(info.track(), info.voting_end_timestamp_ms());
+				let execution_expiry_timestamp_ms = info
+					.voting_end_timestamp_ms()
+					.saturating_add(app_config.execution_expiry_time_seconds.saturating_mul(1000).into());
+				CourtQueryResponseProposalTally {
+					proposal_id: id,
+					status: info.status(current_timestamp_ms, &app_config),
+					projected_status: info
+						.status(current_timestamp_ms.max(info.voting_end_timestamp_ms()), &app_config),
+					votes_for: info.votes_for.into(),
+					votes_against: info.votes_against.into(),
+					votes_abstain: info.votes_abstain.into(),
+					votes_veto: info.votes_veto.into(),
+					option_votes: option_votes.into_iter().map(Uint128::from).collect(),
+					total_supply: info.quorum_supply.into(),
+					turnout: Decimal::from_ratio(info.total_votes(), info.quorum_supply),
+					approval: Decimal::checked_from_ratio(
+						info.votes_for,
+						info.votes_for + info.votes_against + info.votes_veto,
+					)
+					.unwrap_or_default(),
+					veto: Decimal::checked_from_ratio(info.votes_veto, info.total_votes()).unwrap_or_default(),
+					minimum_vote_turnout_percent: app_config.minimum_vote_turnout_percent,
+					minimum_vote_pass_percent: app_config.minimum_vote_pass_percent,
+					veto_threshold_percent: app_config.veto_threshold_percent,
+					votes_needed_to_pass: info.votes_needed_to_pass(&app_config).map(Uint128::from),
+					votes_needed_to_block: info.votes_needed_to_block(&app_config).map(Uint128::from),
+					voting_time_remaining_ms: info.expiry_timestamp_ms.saturating_sub(current_timestamp_ms),
+					timelock_remaining_ms: timelock_expiry_timestamp_ms
+						.map(|timelock_expiry_timestamp_ms| {
+							timelock_expiry_timestamp_ms.saturating_sub(current_timestamp_ms)
+						})
+						.unwrap_or_default(),
+					execution_time_remaining_ms: execution_expiry_timestamp_ms.saturating_sub(current_timestamp_ms),
+				}
+			}))?
+		}
+		CourtQueryMsg::SimulateProposal {
+			msgs,
+			expiry_time_seconds,
+			proposer,
+		} => {
+			let app_config = CourtAppConfig::load_non_empty()?;
+			let votes_denom = votes_denom(&env);
+			let token_supply = total_supply_workaround(&votes_denom);
+			let quorum_supply = quorum_supply(&deps.querier, &env, &app_config)?;
+			let proposer = SeiCanonicalAddr::try_from(&proposer)?;
+			let proposer_votes = get_user_stats_store()
+				.get(&proposer)?
+				.unwrap_or_default()
+				.activated(env.block.time.millis(), app_config.min_stake_age_seconds)
+				.vote_weight(env.block.time.millis(), quorum_supply, &app_config);
+			let (msgs, track) = validate_proposal(
+				&QuerierWrapper::new(&*deps.querier),
+				&app_config,
+				&CourtProposalTracks::load_or_default()?,
+				quorum_supply,
+				proposer_votes,
+				msgs,
+				expiry_time_seconds,
+			)?;
+			let (cosmos_msgs, _) = proposal_cosmos_msgs(
+				msgs.clone(),
+				&TransactionProposalInfo::new(
+					proposer,
+					proposer_votes,
+					env.block.time.plus_seconds(expiry_time_seconds as u64).millis(),
+				),
+				&votes_denom,
+				token_supply.u128(),
+				&CourtStargateAllowlist::load_or_default()?,
+			)?;
+			to_json_binary(&CourtQueryResponseSimulatedProposal {
+				messages: msgs
+					.into_iter()
+					.map(|v| {
+						let mut v_jsonable = ProposedCourtMsgJsonable::try_from(v)?;
+						v_jsonable.make_pretty()?;
+						Ok(v_jsonable)
+					})
+					.collect::<Result<Vec<_>, StdError>>()?,
+				cosmos_msgs,
+				track,
 			})?
 		}
 		CourtQueryMsg::UserStats { user } => {
 			let user = SeiCanonicalAddr::try_from(&user)?;
-			to_json_binary(&CourtUserStatsJsonable::try_from(
-				get_user_stats_store().get(&user)?.unwrap_or_default().as_ref(),
-			)?)?
+			let app_config = CourtAppConfig::load_non_empty()?;
+			let user_stats = get_user_stats_store()
+				.get(&user)?
+				.unwrap_or_default()
+				.activated(env.block.time.millis(), app_config.min_stake_age_seconds);
+			let mut user_stats_jsonable = CourtUserStatsJsonable::try_from(&user_stats)?;
+			if user_stats.is_locked(env.block.time.millis()) {
+				user_stats_jsonable.vote_multiplier_percent =
+					user_stats.vote_multiplier_percent(env.block.time.millis(), &app_config);
+			}
+			user_stats_jsonable.effective_votes = user_stats
+				.vote_weight(
+					env.block.time.millis(),
+					quorum_supply(&deps.querier, &env, &app_config)?,
+					&app_config,
+				)
+				.into();
+			to_json_binary(&user_stats_jsonable)?
 		}
 		CourtQueryMsg::UserVoteInfo { user, proposal_id } => {
 			let user = SeiCanonicalAddr::try_from(&user)?;
@@ -271,6 +565,15 @@ pub fn query(_deps: Deps, env: Env, msg: CourtQueryMsg) -> Result<Binary, CourtC
 					.as_ref(),
 			)?)?
 		}
+		CourtQueryMsg::SignedVoteNonce { user, proposal_id } => {
+			let user = SeiCanonicalAddr::try_from(&user)?;
+			to_json_binary(
+				&get_proposal_user_signed_vote_nonce_store()
+					.get(&(proposal_id, user))?
+					.map(|signed_vote_nonce| signed_vote_nonce.nonce)
+					.unwrap_or_default(),
+			)?
+		}
 		CourtQueryMsg::GetUsersWithActiveProposals {
 			after,
 			limit,
@@ -368,3 +671,62 @@ pub fn query(_deps: Deps, env: Env, msg: CourtQueryMsg) -> Result<Binary, CourtC
 		}
 	})
 }
+
+/// The options of a multiple-choice proposal along with their votes, empty for other proposals.
+fn transaction_proposal_options(
+	proposal_id: u32,
+	info: &TransactionProposalInfo,
+) -> Result<Vec<(TransactionProposalOption, u128)>, StdError> {
+	if !info.is_multiple_choice() {
+		return Ok(Vec::new());
+	}
+	let options = get_transaction_proposal_options_store()
+		.get(&proposal_id)?
+		.unwrap_or_default()
+		.into_inner();
+	let option_votes = get_transaction_proposal_option_votes_store()
+		.get(&proposal_id)?
+		.unwrap_or_default()
+		.into_inner();
+	Ok(options.into_iter().zip(option_votes).collect())
+}
+
+fn transaction_proposal_response(
+	proposal_id: u32,
+	info: &TransactionProposalInfo,
+	status: TransactionProposalStatus,
+	messages: Vec<ProposedCourtMsg>,
+	options: Vec<(TransactionProposalOption, u128)>,
+) -> Result<CourtQueryResponseTransactionProposal, StdError> {
+	Ok(CourtQueryResponseTransactionProposal {
+		proposal_id,
+		status,
+		info: info.try_into()?,
+		messages: proposal_messages_response(messages)?,
+		decoded_messages: None,
+		options: options
+			.into_iter()
+			.map(|(option, votes)| {
+				Ok(CourtQueryResponseProposalOption {
+					label: option.label,
+					messages: proposal_messages_response(option.messages)?,
+					votes: votes.into(),
+				})
+			})
+			.collect::<Result<Vec<_>, StdError>>()?,
+		metadata: get_transaction_proposal_metadata_store()
+			.get(&proposal_id)?
+			.map(|metadata| metadata.into_inner().into()),
+	})
+}
+
+fn proposal_messages_response(messages: Vec<ProposedCourtMsg>) -> Result<Vec<ProposedCourtMsgJsonable>, StdError> {
+	messages
+		.into_iter()
+		.map(|v| {
+			let mut v_jsonable = ProposedCourtMsgJsonable::try_from(v)?;
+			v_jsonable.make_pretty()?;
+			Ok(v_jsonable)
+		})
+		.collect()
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Addr, MessageInfo, Response, StdError, Uint128};
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr,
 	env::MinimalEnvInfo,
@@ -10,12 +10,22 @@ use sei_cosmwasm::SeiMsg;
 
 use crate::{
 	error::CourtContractError,
-	state::{app::CourtAppConfig, user::get_user_active_proposal_id_set},
+	msg::CourtConfigChanges,
+	state::{
+		app::{
+			CourtAppConfig, CourtOptimisticAllowlist, CourtProposalTrack, CourtProposalTrackJsonable,
+			CourtProposalTracks, CourtStargateAllowlist,
+		},
+		user::get_user_active_proposal_id_set,
+	},
 	workarounds::mint_to_workaround,
 };
 
 use super::shares::votes_denom;
 
+/// The most proposal tracks there may be, as every proposal is checked against each of them
+pub const MAX_PROPOSAL_TRACKS: usize = 16;
+
 pub struct AdminMsgExecutor<'exec, Q: cosmwasm_std::CustomQuery> {
 	env_info: MinimalEnvInfo<'exec, Q>,
 	app_config: OZeroCopy<CourtAppConfig>,
@@ -37,32 +47,80 @@ impl<'exec, Q: cosmwasm_std::CustomQuery> AdminMsgExecutor<'exec, Q> {
 	pub fn process_change_config(
 		&mut self,
 		msg_info: &MessageInfo,
-		minimum_vote_proposal_percent: Option<u8>,
-		minimum_vote_turnout_percent: Option<u8>,
-		minimum_vote_pass_percent: Option<u8>,
-		max_proposal_expiry_time_seconds: Option<u32>,
-		execution_expiry_time_seconds: Option<u32>,
+		changes: CourtConfigChanges,
 	) -> Result<Response<SeiMsg>, CourtContractError> {
 		nonpayable(msg_info)?;
 		if get_user_active_proposal_id_set().iter()?.next().is_some() {
 			// Easiest way to check if there are any active proposals
 			return Err(CourtContractError::VotesActive);
 		}
-		if let Some(minimum_vote_proposal_percent) = minimum_vote_proposal_percent {
+		if let Some(minimum_vote_proposal_percent) = changes.minimum_vote_proposal_percent {
 			self.app_config.minimum_vote_proposal_percent = minimum_vote_proposal_percent;
 		}
-		if let Some(minimum_vote_turnout_percent) = minimum_vote_turnout_percent {
+		if let Some(minimum_vote_turnout_percent) = changes.minimum_vote_turnout_percent {
 			self.app_config.minimum_vote_turnout_percent = minimum_vote_turnout_percent;
 		}
-		if let Some(minimum_vote_pass_percent) = minimum_vote_pass_percent {
+		if let Some(minimum_vote_pass_percent) = changes.minimum_vote_pass_percent {
 			self.app_config.minimum_vote_pass_percent = minimum_vote_pass_percent;
 		}
-		if let Some(max_proposal_expiry_time_seconds) = max_proposal_expiry_time_seconds {
+		if let Some(veto_threshold_percent) = changes.veto_threshold_percent {
+			self.app_config.veto_threshold_percent = veto_threshold_percent;
+		}
+		if let Some(optimistic_opposition_threshold_percent) = changes.optimistic_opposition_threshold_percent {
+			self.app_config.optimistic_opposition_threshold_percent = optimistic_opposition_threshold_percent;
+		}
+		if let Some(expedited_minimum_vote_turnout_percent) = changes.expedited_minimum_vote_turnout_percent {
+			self.app_config.expedited_minimum_vote_turnout_percent = expedited_minimum_vote_turnout_percent;
+		}
+		if let Some(expedited_minimum_vote_pass_percent) = changes.expedited_minimum_vote_pass_percent {
+			self.app_config.expedited_minimum_vote_pass_percent = expedited_minimum_vote_pass_percent;
+		}
+		if let Some(expedited_voting_period_seconds) = changes.expedited_voting_period_seconds {
+			self.app_config.expedited_voting_period_seconds = expedited_voting_period_seconds;
+		}
+		if let Some(min_proposal_expiry_time_seconds) = changes.min_proposal_expiry_time_seconds {
+			self.app_config.min_proposal_expiry_time_seconds = min_proposal_expiry_time_seconds;
+		}
+		if let Some(max_proposal_expiry_time_seconds) = changes.max_proposal_expiry_time_seconds {
 			self.app_config.max_proposal_expiry_time_seconds = max_proposal_expiry_time_seconds;
 		}
-		if let Some(execution_expiry_time_seconds) = execution_expiry_time_seconds {
+		if let Some(execution_expiry_time_seconds) = changes.execution_expiry_time_seconds {
 			self.app_config.execution_expiry_time_seconds = execution_expiry_time_seconds;
 		}
+		if let Some(quiet_ending_window_seconds) = changes.quiet_ending_window_seconds {
+			self.app_config.quiet_ending_window_seconds = quiet_ending_window_seconds;
+		}
+		if let Some(quiet_ending_extension_seconds) = changes.quiet_ending_extension_seconds {
+			self.app_config.quiet_ending_extension_seconds = quiet_ending_extension_seconds;
+		}
+		if let Some(max_quiet_ending_extensions) = changes.max_quiet_ending_extensions {
+			self.app_config.max_quiet_ending_extensions = max_quiet_ending_extensions;
+		}
+		if let Some(min_stake_age_seconds) = changes.min_stake_age_seconds {
+			self.app_config.min_stake_age_seconds = min_stake_age_seconds;
+		}
+		if let Some(max_stake_lock_seconds) = changes.max_stake_lock_seconds {
+			self.app_config.max_stake_lock_seconds = max_stake_lock_seconds;
+		}
+		if let Some(max_stake_lock_vote_multiplier_percent) = changes.max_stake_lock_vote_multiplier_percent {
+			self.app_config.max_stake_lock_vote_multiplier_percent = max_stake_lock_vote_multiplier_percent;
+		}
+		if let Some(quadratic_voting) = changes.quadratic_voting {
+			self.app_config.set_quadratic_voting(quadratic_voting);
+		}
+		if let Some(quorum_supply) = changes.quorum_supply {
+			self.app_config.set_quorum_supply(quorum_supply);
+		}
+		if let Some(max_user_votes) = changes.max_user_votes {
+			self.app_config.max_user_votes = max_user_votes.u128();
+		}
+		if let Some(max_user_votes_bps) = changes.max_user_votes_bps {
+			self.app_config.max_user_votes_bps = max_user_votes_bps;
+		}
+		if let Some(reveal_period_seconds) = changes.reveal_period_seconds {
+			self.app_config.reveal_period_seconds = reveal_period_seconds;
+		}
+		self.app_config.validate()?;
 		self.app_config.last_config_change_timestamp_ms = self.env_info.env.block.time.millis();
 		self.app_config.save()?;
 		Ok(Response::new())
@@ -111,4 +169,121 @@ impl<'exec, Q: cosmwasm_std::CustomQuery> AdminMsgExecutor<'exec, Q> {
 			amount.u128(),
 		)?)
 	}
+	pub fn process_allow_stargate_messages(
+		&mut self,
+		msg_info: &MessageInfo,
+		type_urls: Vec<String>,
+		allowed: bool,
+	) -> Result<Response<SeiMsg>, CourtContractError> {
+		nonpayable(msg_info)?;
+		// Stargate messages can do almost anything, so only proposals may allow them. Disallowing them is always safe.
+		if allowed && msg_info.sender != self.env_info.env.contract.address {
+			return Err(CourtContractError::Unauthorized(
+				"Stargate messages may only be allowed by proposals, which requires this contract to be its own admin"
+					.into(),
+			));
+		}
+		let mut stargate_allowlist = CourtStargateAllowlist::load_or_default()?;
+		if allowed {
+			for type_url in type_urls {
+				if !stargate_allowlist.is_allowed(&type_url) {
+					stargate_allowlist.type_urls.push(type_url);
+				}
+			}
+		} else {
+			stargate_allowlist
+				.type_urls
+				.retain(|allowed_type_url| !type_urls.contains(allowed_type_url));
+		}
+		stargate_allowlist.save()?;
+		Ok(Response::new())
+	}
+	pub fn process_allow_optimistic_proposals(
+		&mut self,
+		msg_info: &MessageInfo,
+		proposers: Vec<Addr>,
+		msg_kinds: Vec<String>,
+		allowed: bool,
+	) -> Result<Response<SeiMsg>, CourtContractError> {
+		nonpayable(msg_info)?;
+		let proposers = proposers
+			.iter()
+			.map(SeiCanonicalAddr::try_from)
+			.collect::<Result<Vec<_>, _>>()?;
+		let mut optimistic_allowlist = CourtOptimisticAllowlist::load_or_default()?;
+		if allowed {
+			for proposer in proposers {
+				if !optimistic_allowlist.proposers.contains(&proposer) {
+					optimistic_allowlist.proposers.push(proposer);
+				}
+			}
+			for msg_kind in msg_kinds {
+				if !optimistic_allowlist.msg_kinds.contains(&msg_kind) {
+					optimistic_allowlist.msg_kinds.push(msg_kind);
+				}
+			}
+		} else {
+			optimistic_allowlist
+				.proposers
+				.retain(|allowed_proposer| !proposers.contains(allowed_proposer));
+			optimistic_allowlist
+				.msg_kinds
+				.retain(|allowed_msg_kind| !msg_kinds.contains(allowed_msg_kind));
+		}
+		optimistic_allowlist.save()?;
+		Ok(Response::new())
+	}
+	pub fn process_set_proposal_track(
+		&mut self,
+		msg_info: &MessageInfo,
+		track: CourtProposalTrackJsonable,
+	) -> Result<Response<SeiMsg>, CourtContractError> {
+		nonpayable(msg_info)?;
+		if get_user_active_proposal_id_set().iter()?.next().is_some() {
+			// Proposals which are being voted on must keep their track's voting config
+			return Err(CourtContractError::VotesActive);
+		}
+		let track = CourtProposalTrack::try_from(track)?;
+		if track.name.is_empty() || track.execution_expiry_time_seconds == 0 {
+			return Err(CourtContractError::InvalidProposalTrack);
+		}
+		let mut proposal_tracks = CourtProposalTracks::load_or_default()?;
+		if let Some(existing_track) = proposal_tracks
+			.tracks
+			.iter_mut()
+			.find(|existing_track| existing_track.name == track.name)
+		{
+			*existing_track = track;
+		} else if proposal_tracks.tracks.len() >= MAX_PROPOSAL_TRACKS {
+			return Err(CourtContractError::TooManyProposalTracks(MAX_PROPOSAL_TRACKS));
+		} else {
+			proposal_tracks.tracks.push(track);
+		}
+		proposal_tracks.save()?;
+		self.app_config.last_config_change_timestamp_ms = self.env_info.env.block.time.millis();
+		self.app_config.save()?;
+		Ok(Response::new())
+	}
+	pub fn process_remove_proposal_track(
+		&mut self,
+		msg_info: &MessageInfo,
+		name: String,
+	) -> Result<Response<SeiMsg>, CourtContractError> {
+		nonpayable(msg_info)?;
+		if get_user_active_proposal_id_set().iter()?.next().is_some() {
+			return Err(CourtContractError::VotesActive);
+		}
+		let mut proposal_tracks = CourtProposalTracks::load_or_default()?;
+		let track_index = proposal_tracks
+			.tracks
+			.iter()
+			.position(|track| track.name == name)
+			.ok_or_else(|| StdError::not_found(format!("Proposal track \"{name}\" does not exist")))?;
+		proposal_tracks.tracks.remove(track_index);
+		proposal_tracks.save()?;
+		// Proposals refer to tracks by their index, which may have changed
+		self.app_config.last_config_change_timestamp_ms = self.env_info.env.block.time.millis();
+		self.app_config.save()?;
+		Ok(Response::new())
+	}
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{CosmosMsg, Event, MessageInfo, Response, StdError};
+use cosmwasm_std::{Addr, CosmosMsg, Event, GovMsg, MessageInfo, Response, StdError, Uint128};
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr, env::MinimalEnvInfo, extentions::timestamp::TimestampExtentions,
 };
@@ -11,12 +11,14 @@ use crate::{
 	proposed_msg::ProposedCourtMsg,
 	state::{
 		app::{
-			get_transaction_proposal_info_vec, get_transaction_proposal_messages_vec, CourtAppConfig,
-			TransactionProposalExecutionStatus, TransactionProposalStatus,
+			get_transaction_proposal_info_vec, get_transaction_proposal_messages_vec,
+			get_transaction_proposal_options_store, get_unfinalized_proposal_id_set, prune_unfinalized_proposal_ids,
+			CourtAppConfig, CourtProposalTracks, CourtStargateAllowlist, TransactionProposalExecutionStatus,
+			TransactionProposalInfo, TransactionProposalStatus, UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
 		},
-		user::{get_all_user_active_proposal_ids, get_user_active_proposal_id_set},
+		user::{get_all_user_active_proposal_ids, get_proposal_user_commitment_store, get_user_active_proposal_id_set},
 	},
-	workarounds::{mint_workaround, total_supply_workaround},
+	workarounds::{record_mint_workaround, total_supply_workaround},
 };
 
 use super::shares::votes_denom;
@@ -29,11 +31,14 @@ pub fn process_deactivate_votes(
 ) -> Result<Response<SeiMsg>, CourtContractError> {
 	nonpayable(&msg_info)?;
 	let app_config = CourtAppConfig::load_non_empty()?;
-	let token_supply = total_supply_workaround(&votes_denom(&env_info.env));
 	let user = user.unwrap_or(SeiCanonicalAddr::try_from(&msg_info.sender)?);
+	let proposal_tracks = CourtProposalTracks::load_or_default()?;
 
 	let proposals = get_transaction_proposal_info_vec();
 	let active_user_proposals = get_user_active_proposal_id_set();
+	let unfinalized_proposals = get_unfinalized_proposal_id_set();
+	let commitments = get_proposal_user_commitment_store();
+	let mut response = Response::new();
 
 	// this .take() is a little fugly, though the resolution of the following issue would help clean up the code:
 	// https://github.com/rust-lang/rust/issues/63065
@@ -41,19 +46,44 @@ pub fn process_deactivate_votes(
 		get_all_user_active_proposal_ids(user)?.take(limit.map(|limit| limit as usize).unwrap_or(usize::MAX));
 
 	for proposal_id in user_vote_info_keys_iter {
-		let proposal = proposals.get(proposal_id)?.ok_or(StdError::not_found(format!(
+		let mut proposal = proposals.get(proposal_id)?.ok_or(StdError::not_found(format!(
 			"Proposal {} which the user voted for doesn't exist?!",
 			proposal_id
 		)))?;
-		if !proposal
-			.status(env_info.env.block.time.millis(), token_supply.u128(), &app_config)
-			.is_finalized()
-		{
+		let proposal_status = proposal.status(
+			env_info.env.block.time.millis(),
+			&proposal_tracks.app_config_for(proposal.track(), &app_config),
+		);
+		if !proposal.is_finalized(proposal_status) {
 			return Err(CourtContractError::ProposalNotFinalized(proposal_id));
 		}
+		if proposal.is_signal()
+			&& proposal_status == TransactionProposalStatus::Passed
+			&& proposal.execution_status() == TransactionProposalExecutionStatus::NotExecuted
+		{
+			// The outcome of a signalling proposal can't be derived anymore once the voting config changes
+			proposal.set_execution_status(TransactionProposalExecutionStatus::Executed);
+			proposals.set(proposal_id, &proposal)?;
+		}
+		if let Some(commitment) = commitments.get(&(proposal_id, user))? {
+			// The vote was never revealed, so it wasn't counted
+			commitments.remove(&(proposal_id, user));
+			response = response.add_event(
+				Event::new("unrevealed_vote")
+					.add_attribute("proposal_id", proposal_id.to_string())
+					.add_attribute("voter", Addr::try_from(user)?)
+					.add_attribute("votes", Uint128::from(commitment.votes)),
+			);
+		}
 		active_user_proposals.remove(&(user.clone(), proposal_id));
+		unfinalized_proposals.remove(&proposal_id);
 	}
-	Ok(Response::new())
+	prune_unfinalized_proposal_ids(
+		env_info.env.block.time.millis(),
+		&app_config,
+		UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
+	)?;
+	Ok(response)
 }
 
 pub fn process_execute_proposal(
@@ -69,38 +99,111 @@ pub fn process_execute_proposal(
 	let mut proposal = proposals
 		.get(proposal_id)?
 		.ok_or(StdError::not_found(format!("Proposal {} does not exist", proposal_id)))?;
-	let proposal_status = proposal.status(env_info.env.block.time.millis(), token_supply.u128(), &app_config);
+	if proposal.is_signal() {
+		return Err(CourtContractError::SignalProposalNotExecutable);
+	}
+	let proposal_tracks = CourtProposalTracks::load_or_default()?;
+	let proposal_status = proposal.status(
+		env_info.env.block.time.millis(),
+		&proposal_tracks.app_config_for(proposal.track(), &app_config),
+	);
 	proposal_status.enforce_status(TransactionProposalStatus::Passed)?;
+	if let Some(timelock_expiry_timestamp_ms) =
+		proposal_tracks.timelock_expiry_timestamp_ms(proposal.track(), proposal.voting_end_timestamp_ms())
+	{
+		if env_info.env.block.time.millis() < timelock_expiry_timestamp_ms {
+			return Err(CourtContractError::ProposalTimelocked(timelock_expiry_timestamp_ms));
+		}
+	}
 	proposal.set_execution_status(TransactionProposalExecutionStatus::Executed);
 	proposals.set(proposal_id, &proposal)?;
+	get_unfinalized_proposal_id_set().remove(&proposal_id);
+	prune_unfinalized_proposal_ids(
+		env_info.env.block.time.millis(),
+		&app_config,
+		UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
+	)?;
+
+	let mut proposal_executed_event =
+		Event::new("proposal_executed").add_attribute("proposal_id", proposal_id.to_string());
+	let proposal_msgs = get_transaction_proposal_messages_vec();
+	let msgs = if let Some(winning_option) = proposal.leading_option() {
+		// Only the winning option is executed, the messages of the others are discarded.
+		let options_store = get_transaction_proposal_options_store();
+		let mut options = options_store.get(&proposal_id)?.unwrap_or_default().into_inner();
+		let msgs = options
+			.get_mut(winning_option as usize)
+			.map(|option| core::mem::take(&mut option.messages))
+			.unwrap_or_default();
+		for option in options.iter_mut() {
+			option.messages.clear();
+		}
+		options_store.set(&proposal_id, &options)?;
+		proposal_msgs.set(proposal_id, &msgs)?;
+		proposal_executed_event = proposal_executed_event.add_attribute("option", winning_option.to_string());
+		msgs
+	} else {
+		proposal_msgs.get(proposal_id)?.unwrap_or_default().into_inner()
+	};
 
 	let votes_denom = votes_denom(&env_info.env);
+	let (cosmos_msgs, minted_votes) = proposal_cosmos_msgs(
+		msgs,
+		&proposal,
+		&votes_denom,
+		token_supply.u128(),
+		&CourtStargateAllowlist::load_or_default()?,
+	)?;
+	if minted_votes > 0 {
+		// HACK: https://github.com/sei-protocol/sei-wasmd/issues/38
+		record_mint_workaround(&votes_denom, minted_votes)?;
+	}
 	Ok(Response::new()
-		.add_event(Event::new("proposal_executed").add_attribute("proposal_id", proposal_id.to_string()))
-		.add_messages(
-			get_transaction_proposal_messages_vec()
-				.get(proposal_id)?
-				.unwrap_or_default()
-				.into_inner()
-				.into_iter()
-				.map(|p_msg| {
-					match p_msg {
-						ProposedCourtMsg::TokenfactoryMint { tokens } if tokens.denom == votes_denom => {
-							if total_supply_workaround(&votes_denom)
-								.u128()
-								.saturating_add(tokens.amount)
-								.saturating_mul(10000)
-								.eq(&u128::MAX)
-							{
-								// Allow us to "unsafely" do permyriad calculations without fear of overflow
-								return Err(CourtContractError::TooManyVotesToMint);
-							}
-							// HACK: https://github.com/sei-protocol/sei-wasmd/issues/38
-							Ok(mint_workaround(&tokens.denom, tokens.amount).map(|msg| CosmosMsg::from(msg))?)
-						}
-						_ => Ok(p_msg.try_into()?),
-					}
-				})
-				.collect::<Result<Vec<_>, CourtContractError>>()?,
-		))
+		.add_event(proposal_executed_event)
+		.add_messages(cosmos_msgs))
+}
+
+/// Converts the messages of a proposal into the messages which are executed, also returning the amount of new votes
+/// which would be minted.
+///
+/// This doesn't write anything to storage, so it can also be used to simulate proposals.
+pub fn proposal_cosmos_msgs(
+	msgs: Vec<ProposedCourtMsg>,
+	proposal: &TransactionProposalInfo,
+	votes_denom: &str,
+	token_supply: u128,
+	stargate_allowlist: &CourtStargateAllowlist,
+) -> Result<(Vec<CosmosMsg<SeiMsg>>, u128), CourtContractError> {
+	let mut minted_votes = 0u128;
+	let cosmos_msgs = msgs
+		.into_iter()
+		.map(|p_msg| match p_msg {
+			ProposedCourtMsg::TokenfactoryMint { tokens } if tokens.denom == votes_denom => {
+				if token_supply
+					.saturating_add(minted_votes)
+					.saturating_add(tokens.amount)
+					.saturating_mul(10000)
+					.eq(&u128::MAX)
+				{
+					// Allow us to "unsafely" do permyriad calculations without fear of overflow
+					return Err(CourtContractError::TooManyVotesToMint);
+				}
+				minted_votes += tokens.amount;
+				Ok(SeiMsg::MintTokens { amount: tokens.into() }.into())
+			}
+			ProposedCourtMsg::GovVoteByTally {
+				proposal_id: gov_proposal_id,
+			} => Ok(GovMsg::VoteWeighted {
+				proposal_id: gov_proposal_id,
+				options: proposal.tally_as_weighted_vote(),
+			}
+			.into()),
+			ProposedCourtMsg::Stargate { type_url, .. } if !stargate_allowlist.is_allowed(&type_url) => {
+				// The allowlist may have changed since this proposal was created
+				Err(CourtContractError::StargateMessageNotAllowed(type_url))
+			}
+			_ => Ok(p_msg.try_into()?),
+		})
+		.collect::<Result<Vec<_>, CourtContractError>>()?;
+	Ok((cosmos_msgs, minted_votes))
 }
@@ -1,4 +1,11 @@
-use cosmwasm_std::{Coin, Env};
+use crate::{
+	state::{
+		app::{CourtAppConfig, CourtQuorumSupply},
+		user::CourtTotalStakedVotes,
+	},
+	workarounds::total_supply_workaround,
+};
+use cosmwasm_std::{Coin, CustomQuery, Env, QuerierWrapper, StdError};
 
 pub static VOTES_SUBDENOM: &str = "votes";
 
@@ -11,3 +18,30 @@ pub fn votes_denom(env: &Env) -> String {
 pub fn votes_coin(env: &Env, amount: u128) -> Coin {
 	Coin::new(amount, votes_denom(env))
 }
+/// The vote supply which proposal turnout is measured against, as configured by `quorum_supply` and weighted by
+/// [CourtAppConfig::vote_weight].
+///
+/// This is never 0, so it can always be divided by.
+pub fn quorum_supply<Q: CustomQuery>(
+	querier: &QuerierWrapper<Q>,
+	env: &Env,
+	app_config: &CourtAppConfig,
+) -> Result<u128, StdError> {
+	let votes_denom = votes_denom(env);
+	let supply = match app_config.quorum_supply() {
+		CourtQuorumSupply::Total => total_supply_workaround(&votes_denom).u128(),
+		CourtQuorumSupply::Staked => CourtTotalStakedVotes::load_or_default()?.votes,
+		CourtQuorumSupply::Circulating => {
+			// Staked votes are held by this contract too, but they still belong to the users who staked them
+			let unstaked_court_votes = querier
+				.query_balance(&env.contract.address, votes_denom.as_str())?
+				.amount
+				.u128()
+				.saturating_sub(CourtTotalStakedVotes::load_or_default()?.votes);
+			total_supply_workaround(&votes_denom)
+				.u128()
+				.saturating_sub(unstaked_court_votes)
+		}
+	};
+	Ok(app_config.vote_weight(supply).max(1))
+}
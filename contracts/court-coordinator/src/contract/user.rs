@@ -1,43 +1,105 @@
-use super::shares::{votes_coin, votes_denom};
+use super::shares::{quorum_supply, votes_coin, votes_denom};
 use crate::{
 	error::CourtContractError,
-	proposed_msg::ProposedCourtMsgJsonable,
+	msg::{
+		CourtProposalOption, CourtSignedVote, CourtSignedVotePayload, CourtVoteCommitmentPayload,
+		CourtVoteSignatureType,
+	},
+	proposed_msg::{ProposedCourtMsg, ProposedCourtMsgJsonable},
 	state::{
 		app::{
-			get_transaction_proposal_info_vec, get_transaction_proposal_messages_vec, CourtAppConfig,
-			TransactionProposalInfo, TransactionProposalStatus,
+			get_proposer_proposal_id_set, get_target_proposal_id_set, get_transaction_proposal_info_vec,
+			get_transaction_proposal_messages_vec, get_transaction_proposal_metadata_store,
+			get_transaction_proposal_option_votes_store, get_transaction_proposal_options_store,
+			get_unfinalized_proposal_id_set, prune_unfinalized_proposal_ids, CourtAppConfig, CourtOptimisticAllowlist,
+			CourtProposalTracks, CourtStargateAllowlist, TransactionProposalInfo, TransactionProposalMetadata,
+			TransactionProposalMetadataJsonable, TransactionProposalOption, TransactionProposalStatus,
+			UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
 		},
 		user::{
-			get_all_user_active_proposal_ids, get_proposal_user_vote_store, get_user_active_proposal_id_set,
-			get_user_stats_store, CourtUserVoteInfoJsonable, CourtUserVoteStatus,
+			get_all_user_active_proposal_ids, get_proposal_user_commitment_store,
+			get_proposal_user_signed_vote_nonce_store, get_proposal_user_vote_store, get_user_active_proposal_id_set,
+			get_user_stats_store, CourtTotalStakedVotes, CourtUserStats, CourtUserVoteCommitment, CourtUserVoteInfo,
+			CourtUserVoteInfoJsonable, CourtUserVoteSplit, CourtUserVoteStatus,
 		},
 	},
-	workarounds::total_supply_workaround,
 };
-use cosmwasm_std::{BankMsg, Event, MessageInfo, Response, StdError, Uint128};
+use cosmwasm_std::{
+	to_json_vec, Addr, Api, BankMsg, Binary, CanonicalAddr, Decimal, Event, HexBinary, MessageInfo, QuerierWrapper,
+	Response, StdError, Uint128,
+};
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr, env::MinimalEnvInfo, extentions::timestamp::TimestampExtentions,
 };
 use cw_utils::{must_pay, nonpayable};
+use ripemd::Ripemd160;
 use sei_cosmwasm::{SeiMsg, SeiQuerier, SeiQueryWrapper};
+use sha2::{Digest, Sha256};
+use tiny_keccak::{Hasher, Keccak};
+
+/// The most options a multiple-choice proposal may have
+pub const MAX_PROPOSAL_OPTIONS: u8 = 16;
+/// The longest a proposal's title may be, in bytes
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 128;
+/// The longest a proposal's description may be, in bytes
+pub const MAX_PROPOSAL_DESCRIPTION_LEN: usize = 4096;
+/// The longest a proposal's content URI may be, in bytes
+pub const MAX_PROPOSAL_CONTENT_URI_LEN: usize = 512;
+/// The name in the EIP-712 domain of signed votes
+pub const EIP712_DOMAIN_NAME: &str = "Court";
+/// The version in the EIP-712 domain of signed votes
+pub const EIP712_DOMAIN_VERSION: &str = "1";
 
 pub fn process_stake(
 	env_info: MinimalEnvInfo<SeiQueryWrapper>,
 	msg_info: MessageInfo,
+	lock_seconds: u32,
 ) -> Result<Response<SeiMsg>, CourtContractError> {
 	let msg_sender = SeiCanonicalAddr::try_from(&msg_info.sender)?;
 	let user_payment_amount = must_pay(&msg_info, &votes_denom(&env_info.env))?;
+	let app_config = CourtAppConfig::load_non_empty()?;
+	if lock_seconds > app_config.max_stake_lock_seconds {
+		return Err(CourtContractError::StakeLockTooLong(app_config.max_stake_lock_seconds));
+	}
 	let user_stats_map = get_user_stats_store();
 
 	let mut user_stats = user_stats_map.get_or_default_autosaving(&msg_sender)?;
-	user_stats.staked_votes = user_stats.staked_votes.checked_add(user_payment_amount.into()).unwrap();
+	let mut total_staked_votes = CourtTotalStakedVotes::load_or_default()?;
+	total_staked_votes.votes = total_staked_votes
+		.votes
+		.checked_add(user_payment_amount.u128())
+		.unwrap();
+	total_staked_votes.save()?;
+	user_stats.add_votes(
+		user_payment_amount.into(),
+		env_info.env.block.time.millis(),
+		app_config.min_stake_age_seconds,
+	);
+	if lock_seconds != 0 {
+		user_stats.lock(lock_seconds, env_info.env.block.time.millis());
+	}
 
-	Ok(Response::new().add_event(
-		Event::new("stake")
-			.add_attribute("user", &msg_info.sender)
-			.add_attribute("user_new_votes", user_payment_amount)
-			.add_attribute("user_total_votes", Uint128::from(user_stats.staked_votes)),
-	))
+	let mut stake_event = Event::new("stake")
+		.add_attribute("user", &msg_info.sender)
+		.add_attribute("user_new_votes", user_payment_amount)
+		.add_attribute("user_total_votes", Uint128::from(user_stats.total_votes()));
+	if user_stats.pending_votes != 0 {
+		stake_event = stake_event.add_attribute("user_pending_votes", Uint128::from(user_stats.pending_votes));
+	}
+	if lock_seconds != 0 {
+		stake_event = stake_event
+			.add_attribute(
+				"user_lock_expiry_timestamp_ms",
+				user_stats.lock_expiry_timestamp_ms.to_string(),
+			)
+			.add_attribute(
+				"user_vote_multiplier_percent",
+				user_stats
+					.vote_multiplier_percent(env_info.env.block.time.millis(), &app_config)
+					.to_string(),
+			);
+	}
+	Ok(Response::new().add_event(stake_event))
 }
 
 pub fn process_unstake(
@@ -55,11 +117,17 @@ pub fn process_unstake(
 	let user_stats_map = get_user_stats_store();
 
 	let mut user_stats = user_stats_map.get_or_default_autosaving(&msg_sender)?;
-	if user_stats.staked_votes == 0 {
+	if user_stats.total_votes() == 0 {
 		return Err(CourtContractError::NoStakedVotes);
 	}
-	let unstake_amount = user_stats.staked_votes;
-	user_stats.staked_votes = 0;
+	if user_stats.is_locked(env_info.env.block.time.millis()) {
+		return Err(CourtContractError::StakeLocked(user_stats.lock_expiry_timestamp_ms));
+	}
+	let unstake_amount = user_stats.total_votes();
+	*user_stats = CourtUserStats::default();
+	let mut total_staked_votes = CourtTotalStakedVotes::load_or_default()?;
+	total_staked_votes.votes = total_staked_votes.votes.checked_sub(unstake_amount).unwrap();
+	total_staked_votes.save()?;
 
 	Ok(Response::new()
 		.add_event(
@@ -78,65 +146,468 @@ pub fn process_vote(
 	msg_info: MessageInfo,
 	proposal_id: u32,
 	approve: CourtUserVoteStatus,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	cast_vote(env_info, msg_info, proposal_id, simple_vote_info(approve)?)
+}
+
+pub fn process_vote_many(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	votes: Vec<(u32, CourtUserVoteStatus)>,
+	skip_inactive: bool,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	nonpayable(&msg_info)?;
+	let app_config = CourtAppConfig::load_non_empty()?;
+	let proposal_tracks = CourtProposalTracks::load_or_default()?;
+	let mut response = Response::new();
+	for (proposal_id, vote) in votes {
+		match record_vote(
+			&env_info,
+			&app_config,
+			&proposal_tracks,
+			msg_info.sender.clone(),
+			proposal_id,
+			simple_vote_info(vote)?,
+		) {
+			Ok(vote_events) => response = response.add_events(vote_events),
+			Err(CourtContractError::UnexpectedProposalStatus { actual, .. }) if skip_inactive => {
+				response = response.add_event(
+					Event::new("vote_skipped")
+						.add_attribute("proposal_id", proposal_id.to_string())
+						.add_attribute("voter", &msg_info.sender)
+						.add_attribute("status", actual.to_string()),
+				);
+			}
+			Err(err) => return Err(err),
+		}
+	}
+	Ok(response)
+}
+
+/// The vote info for approving, opposing, abstaining, or vetoing
+fn simple_vote_info(vote: CourtUserVoteStatus) -> Result<CourtUserVoteInfo, CourtContractError> {
+	if vote == CourtUserVoteStatus::Split {
+		return Err(CourtContractError::InvalidVoteSplit);
+	}
+	if vote == CourtUserVoteStatus::Choice {
+		return Err(CourtContractError::InvalidVoteChoice);
+	}
+	let mut new_vote_info = CourtUserVoteInfo::default();
+	new_vote_info.set_vote(vote);
+	Ok(new_vote_info)
+}
+
+pub fn process_vote_weighted(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	proposal_id: u32,
+	split: CourtUserVoteSplit,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	if !split.is_valid() {
+		return Err(CourtContractError::InvalidVoteSplit);
+	}
+	let mut new_vote_info = CourtUserVoteInfo::default();
+	new_vote_info.set_split(&split);
+	cast_vote(env_info, msg_info, proposal_id, new_vote_info)
+}
+
+pub fn process_vote_choice(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	proposal_id: u32,
+	option: u8,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	let mut new_vote_info = CourtUserVoteInfo::default();
+	new_vote_info.set_choice(option);
+	cast_vote(env_info, msg_info, proposal_id, new_vote_info)
+}
+
+pub fn process_submit_signed_votes(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	api: &dyn Api,
+	msg_info: MessageInfo,
+	votes: Vec<CourtSignedVote>,
+	skip_invalid: bool,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	nonpayable(&msg_info)?;
+	let app_config = CourtAppConfig::load_non_empty()?;
+	let proposal_tracks = CourtProposalTracks::load_or_default()?;
+	let mut response = Response::new();
+	for (index, signed_vote) in votes.iter().enumerate() {
+		match submit_signed_vote(&env_info, api, &app_config, &proposal_tracks, signed_vote) {
+			Ok(vote_events) => {
+				response = response.add_events(vote_events);
+			}
+			Err(err) if skip_invalid => {
+				response = response.add_event(
+					Event::new("signed_vote_skipped")
+						.add_attribute("index", index.to_string())
+						.add_attribute("reason", err.to_string()),
+				);
+			}
+			Err(err) => return Err(err),
+		}
+	}
+	Ok(response)
+}
+
+/// Records a signed vote, nothing is written if this fails so that it may be skipped
+fn submit_signed_vote(
+	env_info: &MinimalEnvInfo<SeiQueryWrapper>,
+	api: &dyn Api,
+	app_config: &CourtAppConfig,
+	proposal_tracks: &CourtProposalTracks,
+	signed_vote: &CourtSignedVote,
+) -> Result<Vec<Event>, CourtContractError> {
+	let voter = verify_signed_vote(env_info, api, signed_vote)?;
+	let mut new_vote_info = CourtUserVoteInfo::default();
+	match (signed_vote.vote, signed_vote.option) {
+		(CourtUserVoteStatus::Choice, Some(option)) => new_vote_info.set_choice(option),
+		(CourtUserVoteStatus::Split, _) => return Err(CourtContractError::InvalidVoteSplit),
+		(CourtUserVoteStatus::Choice, None) | (_, Some(_)) => return Err(CourtContractError::InvalidVoteChoice),
+		(vote, None) => new_vote_info.set_vote(vote),
+	}
+	let voter_addr: Addr = voter.try_into()?;
+	let mut events = vec![Event::new("signed_vote")
+		.add_attribute("voter", voter_addr.clone())
+		.add_attribute("nonce", signed_vote.nonce.to_string())];
+	events.extend(record_vote(
+		env_info,
+		app_config,
+		proposal_tracks,
+		voter_addr,
+		signed_vote.proposal_id,
+		new_vote_info,
+	)?);
+	Ok(events)
+}
+
+/// Checks the signature and nonce of the signed vote, returning the voter's address if they're valid
+fn verify_signed_vote(
+	env_info: &MinimalEnvInfo<SeiQueryWrapper>,
+	api: &dyn Api,
+	signed_vote: &CourtSignedVote,
+) -> Result<SeiCanonicalAddr, CourtContractError> {
+	let payload = CourtSignedVotePayload {
+		chain_id: env_info.env.block.chain_id.clone(),
+		court: env_info.env.contract.address.clone(),
+		proposal_id: signed_vote.proposal_id,
+		vote: signed_vote.vote,
+		option: signed_vote.option,
+		nonce: signed_vote.nonce,
+	};
+	let voter = match signed_vote.signature_type {
+		CourtVoteSignatureType::Secp256k1 => {
+			// Only compressed public keys are accepted as that's what Sei addresses are derived from
+			if signed_vote.pubkey.len() != 33
+				|| !api
+					.secp256k1_verify(
+						&Sha256::digest(to_json_vec(&payload)?),
+						&signed_vote.signature,
+						&signed_vote.pubkey,
+					)
+					.unwrap_or_default()
+			{
+				return Err(CourtContractError::InvalidVoteSignature);
+			}
+			SeiCanonicalAddr::try_from(&CanonicalAddr::from(
+				Ripemd160::digest(Sha256::digest(&signed_vote.pubkey)).as_slice(),
+			))?
+		}
+		CourtVoteSignatureType::Eip712 => {
+			if signed_vote.signature.len() != 65 {
+				return Err(CourtContractError::InvalidVoteSignature);
+			}
+			// Wallets use 27 and 28 for the recovery id for historical reasons
+			let recovery_id = match signed_vote.signature[64] {
+				recovery_id @ (0 | 1) => recovery_id,
+				recovery_id @ (27 | 28) => recovery_id - 27,
+				_ => return Err(CourtContractError::InvalidVoteSignature),
+			};
+			let signer_pubkey = api
+				.secp256k1_recover_pubkey(&eip712_vote_hash(&payload), &signed_vote.signature[..64], recovery_id)
+				.map_err(|_| CourtContractError::InvalidVoteSignature)?;
+			// The EVM address is the last 20 bytes of the hash of the uncompressed public key, without its prefix
+			let signer_evm_addr = format!("0x{}", hex::encode(&keccak256(&[&signer_pubkey[1..]])[12..]));
+			let signer_addr = SeiQuerier::new(&env_info.querier)
+				.get_sei_address(signer_evm_addr.clone())
+				.ok()
+				.filter(|response_addr| response_addr.sei_address.len() > 0 && response_addr.associated)
+				.map(|response| response.sei_address)
+				.ok_or(CourtContractError::UnassociatedVoteSigner(signer_evm_addr))?;
+			SeiCanonicalAddr::try_from(signer_addr.as_str())?
+		}
+	};
+	let expected_nonce = get_proposal_user_signed_vote_nonce_store()
+		.get(&(signed_vote.proposal_id, voter))?
+		.map(|signed_vote_nonce| signed_vote_nonce.nonce)
+		.unwrap_or_default();
+	if signed_vote.nonce != expected_nonce {
+		return Err(CourtContractError::InvalidVoteNonce(expected_nonce));
+	}
+	Ok(voter)
+}
+
+/// The EIP-712 hash of the payload, this is what EVM wallets sign, see [CourtVoteSignatureType::Eip712]
+fn eip712_vote_hash(payload: &CourtSignedVotePayload) -> [u8; 32] {
+	let domain_separator = keccak256(&[
+		&keccak256(&[b"EIP712Domain(string name,string version)"]),
+		&keccak256(&[EIP712_DOMAIN_NAME.as_bytes()]),
+		&keccak256(&[EIP712_DOMAIN_VERSION.as_bytes()]),
+	]);
+	// Integers are encoded as 32-byte big-endian words
+	let mut proposal_id = [0u8; 32];
+	proposal_id[28..].copy_from_slice(&payload.proposal_id.to_be_bytes());
+	let mut option = [0u8; 32];
+	option[31] = payload.option.unwrap_or_default();
+	let mut nonce = [0u8; 32];
+	nonce[24..].copy_from_slice(&payload.nonce.to_be_bytes());
+	let struct_hash = keccak256(&[
+		&keccak256(&[b"Vote(string chainId,string court,uint32 proposalId,string vote,uint8 option,uint64 nonce)"]),
+		&keccak256(&[payload.chain_id.as_bytes()]),
+		&keccak256(&[payload.court.as_bytes()]),
+		&proposal_id,
+		&keccak256(&[payload.vote.to_string().as_bytes()]),
+		&option,
+		&nonce,
+	]);
+	keccak256(&[b"\x19\x01", &domain_separator, &struct_hash])
+}
+
+fn keccak256(data: &[&[u8]]) -> [u8; 32] {
+	let mut hasher = Keccak::v256();
+	for data in data {
+		hasher.update(data);
+	}
+	let mut hash = [0u8; 32];
+	hasher.finalize(&mut hash);
+	hash
+}
+
+/// Replaces the user's vote on the proposal with `new_vote_info`, using all of the user's staked votes
+fn cast_vote(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	proposal_id: u32,
+	new_vote_info: CourtUserVoteInfo,
 ) -> Result<Response<SeiMsg>, CourtContractError> {
 	nonpayable(&msg_info)?;
-	let msg_sender = SeiCanonicalAddr::try_from(&msg_info.sender)?;
 	let app_config = CourtAppConfig::load_non_empty()?;
-	let token_supply = total_supply_workaround(&votes_denom(&env_info.env));
-	let user_stats = get_user_stats_store().get(&msg_sender)?.unwrap_or_default();
+	Ok(Response::new().add_events(record_vote(
+		&env_info,
+		&app_config,
+		&CourtProposalTracks::load_or_default()?,
+		msg_info.sender,
+		proposal_id,
+		new_vote_info,
+	)?))
+}
+
+/// Does the work of [cast_vote] on behalf of `voter`, returning the events to emit. `app_config` and `proposal_tracks`
+/// are loaded by the caller so that they can be reused for multiple votes. Nothing is changed if this fails, otherwise
+/// the voter's signed vote nonce for the proposal is incremented.
+fn record_vote(
+	env_info: &MinimalEnvInfo<SeiQueryWrapper>,
+	app_config: &CourtAppConfig,
+	proposal_tracks: &CourtProposalTracks,
+	voter: Addr,
+	proposal_id: u32,
+	mut new_vote_info: CourtUserVoteInfo,
+) -> Result<Vec<Event>, CourtContractError> {
+	let msg_sender = SeiCanonicalAddr::try_from(&voter)?;
+	let user_stats = get_user_stats_store()
+		.get(&msg_sender)?
+		.unwrap_or_default()
+		.activated(env_info.env.block.time.millis(), app_config.min_stake_age_seconds);
 
 	let proposals = get_transaction_proposal_info_vec();
 	let mut proposal = proposals
 		.get(proposal_id)?
 		.ok_or(StdError::not_found(format!("Proposal {} does not exist", proposal_id)))?;
+	if proposal.is_private() {
+		return Err(CourtContractError::VoteMustBeCommitted(proposal_id));
+	}
+	let track_app_config = proposal_tracks.app_config_for(proposal.track(), app_config);
 	proposal
-		.status(env_info.env.block.time.millis(), token_supply.u128(), &app_config)
+		.status(env_info.env.block.time.millis(), &track_app_config)
 		.enforce_status(TransactionProposalStatus::Pending)?;
-
-	let user_active_proposals = get_user_active_proposal_id_set();
-	let mut user_vote_info = get_proposal_user_vote_store().get_or_default_autosaving(&(proposal_id, msg_sender))?;
+	let old_proposal = *proposal;
+	let is_valid_vote = match new_vote_info.choice() {
+		Some(option) => option < proposal.option_count(),
+		None => !proposal.is_multiple_choice() || new_vote_info.vote() == CourtUserVoteStatus::Abstain,
+	};
+	if !is_valid_vote {
+		return Err(CourtContractError::InvalidVoteChoice);
+	}
 
 	if user_stats.staked_votes == 0 {
 		return Err(CourtContractError::NoStakedVotes);
 	}
+	let user_active_proposals = get_user_active_proposal_id_set();
+	let mut user_vote_info = get_proposal_user_vote_store().get_or_default_autosaving(&(proposal_id, msg_sender))?;
+	new_vote_info.active_votes =
+		user_stats.vote_weight(env_info.env.block.time.millis(), proposal.quorum_supply, app_config);
+	let old_vote_info = *user_vote_info;
 	if user_vote_info.active_votes != 0 {
-		if user_vote_info.active_votes == user_stats.staked_votes && user_vote_info.vote() == approve {
+		if *user_vote_info == new_vote_info {
 			return Err(CourtContractError::AlreadyVoted);
 		}
 		// User is either adding votes or chainging their vote, so we gotta take away the old votes first
-		match user_vote_info.vote() {
-			CourtUserVoteStatus::Oppose => {
-				proposal.votes_against = proposal.votes_against.checked_sub(user_vote_info.active_votes).unwrap();
-			}
-			CourtUserVoteStatus::Approve => {
-				proposal.votes_for = proposal.votes_for.checked_sub(user_vote_info.active_votes).unwrap();
-			}
-			CourtUserVoteStatus::Abstain => {
-				proposal.votes_abstain = proposal.votes_abstain.checked_sub(user_vote_info.active_votes).unwrap();
-			}
-		}
+		proposal.remove_user_votes(&user_vote_info);
 	}
-	user_vote_info.active_votes = user_stats.staked_votes;
-	user_vote_info.set_vote(approve);
-	match approve {
-		CourtUserVoteStatus::Oppose => {
-			proposal.votes_against = proposal.votes_against.checked_add(user_stats.staked_votes).unwrap();
+	*user_vote_info = new_vote_info;
+	proposal.add_user_votes(&new_vote_info);
+	if proposal.is_multiple_choice() {
+		let option_votes_store = get_transaction_proposal_option_votes_store();
+		let mut option_votes = option_votes_store.get(&proposal_id)?.unwrap_or_default().into_inner();
+		if let Some(option) = old_vote_info.choice() {
+			option_votes[option as usize] = option_votes[option as usize]
+				.checked_sub(old_vote_info.active_votes)
+				.unwrap();
 		}
-		CourtUserVoteStatus::Approve => {
-			proposal.votes_for = proposal.votes_for.checked_add(user_stats.staked_votes).unwrap();
-		}
-		CourtUserVoteStatus::Abstain => {
-			proposal.votes_abstain = proposal.votes_abstain.checked_add(user_stats.staked_votes).unwrap();
+		if let Some(option) = new_vote_info.choice() {
+			option_votes[option as usize] = option_votes[option as usize]
+				.checked_add(new_vote_info.active_votes)
+				.unwrap();
 		}
+		proposal.set_option_votes(&option_votes);
+		option_votes_store.set(&proposal_id, &option_votes)?;
 	}
 	user_active_proposals.add(&(msg_sender, proposal_id))?;
+	// Any vote uses up the nonce, so that signed votes which haven't been submitted can't override a newer vote
+	get_proposal_user_signed_vote_nonce_store()
+		.get_or_default_autosaving(&(proposal_id, msg_sender))?
+		.nonce += 1;
+	let extended =
+		proposal.extend_if_outcome_changed(&old_proposal, env_info.env.block.time.millis(), &track_app_config);
 	proposals.set(proposal_id, &proposal)?;
+
+	let mut vote_event = Event::new("vote")
+		.add_attribute("proposal_id", proposal_id.to_string())
+		.add_attribute("voter", voter)
+		.add_attribute("votes", Uint128::from(new_vote_info.active_votes))
+		.add_attribute("vote", new_vote_info.vote().to_string());
+	if let Some(split) = new_vote_info.split() {
+		vote_event = vote_event
+			.add_attribute("approve_bps", split.approve_bps.to_string())
+			.add_attribute("oppose_bps", split.oppose_bps.to_string())
+			.add_attribute("abstain_bps", split.abstain_bps.to_string());
+	}
+	if let Some(option) = new_vote_info.choice() {
+		vote_event = vote_event.add_attribute("option", option.to_string());
+	}
+	let mut events = vec![vote_event];
+	if extended {
+		events.push(
+			Event::new("proposal_extended")
+				.add_attribute("proposal_id", proposal_id.to_string())
+				.add_attribute("extensions", proposal.extensions().to_string())
+				.add_attribute("expiry_timestamp_ms", proposal.expiry_timestamp_ms.to_string()),
+		);
+	}
+	Ok(events)
+}
+
+pub fn process_commit_vote(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	proposal_id: u32,
+	hash: Binary,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	nonpayable(&msg_info)?;
+	let hash: [u8; 32] = hash
+		.as_slice()
+		.try_into()
+		.map_err(|_| CourtContractError::InvalidVoteCommitment)?;
+	let msg_sender = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let app_config = CourtAppConfig::load_non_empty()?;
+	let user_stats = get_user_stats_store()
+		.get(&msg_sender)?
+		.unwrap_or_default()
+		.activated(env_info.env.block.time.millis(), app_config.min_stake_age_seconds);
+
+	let proposal = get_transaction_proposal_info_vec()
+		.get(proposal_id)?
+		.ok_or(StdError::not_found(format!("Proposal {} does not exist", proposal_id)))?;
+	if !proposal.is_private() {
+		return Err(CourtContractError::ProposalNotPrivate(proposal_id));
+	}
+	let track_app_config = CourtProposalTracks::load_or_default()?.app_config_for(proposal.track(), &app_config);
+	proposal
+		.status(env_info.env.block.time.millis(), &track_app_config)
+		.enforce_status(TransactionProposalStatus::Pending)?;
+	if user_stats.staked_votes == 0 {
+		return Err(CourtContractError::NoStakedVotes);
+	}
+	let commitment = CourtUserVoteCommitment {
+		hash,
+		votes: user_stats.vote_weight(env_info.env.block.time.millis(), proposal.quorum_supply, &app_config),
+	};
+	get_proposal_user_commitment_store().set(&(proposal_id, msg_sender), &commitment)?;
+	get_user_active_proposal_id_set().add(&(msg_sender, proposal_id))?;
+
+	Ok(Response::new().add_event(
+		Event::new("vote_committed")
+			.add_attribute("proposal_id", proposal_id.to_string())
+			.add_attribute("voter", msg_info.sender)
+			.add_attribute("votes", Uint128::from(commitment.votes)),
+	))
+}
+
+pub fn process_reveal_vote(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	proposal_id: u32,
+	vote: CourtUserVoteStatus,
+	salt: Binary,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	nonpayable(&msg_info)?;
+	let msg_sender = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let app_config = CourtAppConfig::load_non_empty()?;
+
+	let proposals = get_transaction_proposal_info_vec();
+	let mut proposal = proposals
+		.get(proposal_id)?
+		.ok_or(StdError::not_found(format!("Proposal {} does not exist", proposal_id)))?;
+	let track_app_config = CourtProposalTracks::load_or_default()?.app_config_for(proposal.track(), &app_config);
+	proposal
+		.status(env_info.env.block.time.millis(), &track_app_config)
+		.enforce_status(TransactionProposalStatus::Revealing)?;
+
+	let commitment_store = get_proposal_user_commitment_store();
+	let commitment = commitment_store
+		.get(&(proposal_id, msg_sender))?
+		.ok_or(CourtContractError::NoVoteCommitment(proposal_id))?;
+	let payload = to_json_vec(&CourtVoteCommitmentPayload {
+		proposal_id,
+		voter: msg_info.sender.clone(),
+		vote,
+		salt,
+	})?;
+	if Sha256::digest(payload)[..] != commitment.hash[..] {
+		return Err(CourtContractError::VoteRevealMismatch);
+	}
+	if vote == CourtUserVoteStatus::Split {
+		return Err(CourtContractError::InvalidVoteSplit);
+	}
+	if vote == CourtUserVoteStatus::Choice {
+		return Err(CourtContractError::InvalidVoteChoice);
+	}
+	let mut vote_info = CourtUserVoteInfo::default();
+	vote_info.set_vote(vote);
+	vote_info.active_votes = commitment.votes;
+	commitment_store.remove(&(proposal_id, msg_sender));
+	get_proposal_user_vote_store().set(&(proposal_id, msg_sender), &vote_info)?;
+	proposal.add_user_votes(&vote_info);
+	proposals.set(proposal_id, &proposal)?;
+
 	Ok(Response::new().add_event(
 		Event::new("vote")
 			.add_attribute("proposal_id", proposal_id.to_string())
 			.add_attribute("voter", msg_info.sender)
-			.add_attribute("votes", Uint128::from(user_stats.staked_votes))
-			.add_attribute("vote", approve.to_string()),
+			.add_attribute("votes", Uint128::from(vote_info.active_votes))
+			.add_attribute("vote", vote.to_string())
+			.add_attribute("revealed", "true"),
 	))
 }
 
@@ -145,114 +616,511 @@ pub fn process_propose_transaction(
 	msg_info: MessageInfo,
 	msgs: Vec<ProposedCourtMsgJsonable>,
 	expiry_time_seconds: u32,
+	metadata: Option<TransactionProposalMetadataJsonable>,
+	optimistic: bool,
+	expedited: bool,
+	private: bool,
 ) -> Result<Response<SeiMsg>, CourtContractError> {
 	nonpayable(&msg_info)?;
 	let proposer = SeiCanonicalAddr::try_from(&msg_info.sender)?;
-	let proposer_addr = msg_info.sender;
 
 	let app_config = CourtAppConfig::load_non_empty()?;
 	let app_config = app_config.as_ref(); // Helps with debugging (maybe with perf?)
+	if expedited && (optimistic || app_config.expedited_voting_period_seconds == 0) {
+		return Err(CourtContractError::ExpeditedProposalNotAllowed);
+	}
+	if private && (optimistic || expedited || app_config.reveal_period_seconds == 0) {
+		return Err(CourtContractError::PrivateProposalNotAllowed);
+	}
+
+	let token_supply = quorum_supply(&env_info.querier, &env_info.env, app_config)?;
+	let proposer_votes = get_user_stats_store()
+		.get(&proposer)?
+		.unwrap_or_default()
+		.activated(env_info.env.block.time.millis(), app_config.min_stake_age_seconds)
+		.vote_weight(env_info.env.block.time.millis(), token_supply, app_config);
+
+	let (msgs, track) = validate_proposal(
+		&env_info.querier,
+		app_config,
+		&CourtProposalTracks::load_or_default()?,
+		token_supply,
+		proposer_votes,
+		msgs,
+		expiry_time_seconds,
+	)?;
+	let metadata = metadata.map(validate_proposal_metadata).transpose()?;
+	let expiry_timestamp_ms = env_info
+		.env
+		.block
+		.time
+		.plus_seconds(expiry_time_seconds as u64)
+		.millis();
+	let mut new_proposal = if optimistic {
+		CourtOptimisticAllowlist::load_or_default()?.enforce_allowed(&proposer, &msgs)?;
+		TransactionProposalInfo::new_optimistic(proposer, proposer_votes, expiry_timestamp_ms)
+	} else if expedited {
+		TransactionProposalInfo::new_expedited(
+			proposer,
+			proposer_votes,
+			expiry_timestamp_ms,
+			env_info
+				.env
+				.block
+				.time
+				.plus_seconds(app_config.expedited_voting_period_seconds as u64)
+				.millis(),
+		)
+	} else if private {
+		TransactionProposalInfo::new_private(
+			proposer,
+			expiry_timestamp_ms,
+			env_info
+				.env
+				.block
+				.time
+				.plus_seconds(expiry_time_seconds as u64 + app_config.reveal_period_seconds as u64)
+				.millis(),
+		)
+	} else {
+		TransactionProposalInfo::new(proposer, proposer_votes, expiry_timestamp_ms)
+	};
+	new_proposal.set_track(track);
+	new_proposal.quorum_supply = token_supply;
+	prune_unfinalized_proposal_ids(
+		env_info.env.block.time.millis(),
+		app_config,
+		UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
+	)?;
+	create_proposal(
+		msg_info.sender,
+		new_proposal,
+		proposer_votes,
+		msgs,
+		Vec::new(),
+		metadata,
+	)
+}
+
+pub fn process_propose_multiple_choice(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	options: Vec<CourtProposalOption>,
+	expiry_time_seconds: u32,
+	metadata: Option<TransactionProposalMetadataJsonable>,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	nonpayable(&msg_info)?;
+	let proposer = SeiCanonicalAddr::try_from(&msg_info.sender)?;
 
-	let token_supply = total_supply_workaround(&votes_denom(&env_info.env));
-	let user_stats = get_user_stats_store().get(&proposer)?.unwrap_or_default();
-	let user_stats = user_stats.as_ref(); // Helps with debugging (maybe with perf?)
+	let app_config = CourtAppConfig::load_non_empty()?;
+	let app_config = app_config.as_ref();
 
+	let token_supply = quorum_supply(&env_info.querier, &env_info.env, app_config)?;
+	let proposer_votes = get_user_stats_store()
+		.get(&proposer)?
+		.unwrap_or_default()
+		.activated(env_info.env.block.time.millis(), app_config.min_stake_age_seconds)
+		.vote_weight(env_info.env.block.time.millis(), token_supply, app_config);
+
+	let (options, track) = validate_multiple_choice_proposal(
+		&env_info.querier,
+		app_config,
+		&CourtProposalTracks::load_or_default()?,
+		token_supply,
+		proposer_votes,
+		options,
+		expiry_time_seconds,
+	)?;
+	let metadata = metadata.map(validate_proposal_metadata).transpose()?;
+	let mut new_proposal = TransactionProposalInfo::new_multiple_choice(
+		proposer,
+		proposer_votes,
+		env_info
+			.env
+			.block
+			.time
+			.plus_seconds(expiry_time_seconds as u64)
+			.millis(),
+		options.len() as u8,
+	);
+	new_proposal.set_track(track);
+	new_proposal.quorum_supply = token_supply;
+	prune_unfinalized_proposal_ids(
+		env_info.env.block.time.millis(),
+		app_config,
+		UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
+	)?;
+	create_proposal(
+		msg_info.sender,
+		new_proposal,
+		proposer_votes,
+		Vec::new(),
+		options,
+		metadata,
+	)
+}
+
+pub fn process_propose_signal(
+	env_info: MinimalEnvInfo<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	metadata: TransactionProposalMetadataJsonable,
+	expiry_time_seconds: u32,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	nonpayable(&msg_info)?;
+	let proposer = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+
+	let app_config = CourtAppConfig::load_non_empty()?;
+	let app_config = app_config.as_ref();
+
+	let token_supply = quorum_supply(&env_info.querier, &env_info.env, app_config)?;
+	let proposer_votes = get_user_stats_store()
+		.get(&proposer)?
+		.unwrap_or_default()
+		.activated(env_info.env.block.time.millis(), app_config.min_stake_age_seconds)
+		.vote_weight(env_info.env.block.time.millis(), token_supply, app_config);
+
+	let metadata = validate_proposal_metadata(metadata)?;
+	validate_proposer(app_config, token_supply, proposer_votes, expiry_time_seconds)?;
+	let mut new_proposal = TransactionProposalInfo::new_signal(
+		proposer,
+		proposer_votes,
+		env_info
+			.env
+			.block
+			.time
+			.plus_seconds(expiry_time_seconds as u64)
+			.millis(),
+	);
+	new_proposal.quorum_supply = token_supply;
+	prune_unfinalized_proposal_ids(
+		env_info.env.block.time.millis(),
+		app_config,
+		UNFINALIZED_PROPOSAL_PRUNE_LIMIT,
+	)?;
+	create_proposal(
+		msg_info.sender,
+		new_proposal,
+		proposer_votes,
+		Vec::new(),
+		Vec::new(),
+		Some(metadata),
+	)
+}
+
+/// Stores a validated proposal along with the proposer's vote, `options` must be empty unless `new_proposal` is a
+/// multiple-choice proposal. The proposer's vote is committed rather than counted if `new_proposal` is private.
+fn create_proposal(
+	proposer_addr: Addr,
+	new_proposal: TransactionProposalInfo,
+	proposer_votes: u128,
+	msgs: Vec<ProposedCourtMsg>,
+	options: Vec<TransactionProposalOption>,
+	metadata: Option<TransactionProposalMetadata>,
+) -> Result<Response<SeiMsg>, CourtContractError> {
+	let proposer = new_proposal.proposer;
+	let mut proposal_infos = get_transaction_proposal_info_vec();
+	let mut proposal_msgs = get_transaction_proposal_messages_vec();
+	let new_proposal_id = proposal_infos.len();
+	proposal_infos.push(&new_proposal)?;
+	proposal_msgs.push(&msgs)?;
+	assert_eq!(proposal_infos.len(), proposal_msgs.len());
+	if new_proposal.is_multiple_choice() {
+		get_transaction_proposal_option_votes_store().set(&new_proposal_id, &vec![0; options.len()])?;
+		get_transaction_proposal_options_store().set(&new_proposal_id, &options)?;
+	}
+	if let Some(metadata) = metadata.as_ref() {
+		get_transaction_proposal_metadata_store().set(&new_proposal_id, metadata)?;
+	}
+
+	get_proposer_proposal_id_set().add(&(proposer, new_proposal_id))?;
+	let target_proposal_ids = get_target_proposal_id_set();
+	for target in msgs
+		.iter()
+		.chain(options.iter().flat_map(|option| option.messages.iter()))
+		.filter_map(|msg| msg.target_contract())
+	{
+		target_proposal_ids.add(&(target, new_proposal_id))?;
+	}
+	get_unfinalized_proposal_id_set().add(&new_proposal_id)?;
+
+	// The proposer has yet to choose an option of a multiple-choice proposal
+	let proposer_vote = if new_proposal.is_multiple_choice() {
+		CourtUserVoteStatus::Abstain
+	} else {
+		CourtUserVoteStatus::Approve
+	};
+	if new_proposal.is_private() {
+		get_proposal_user_commitment_store().set(
+			&(new_proposal_id, proposer),
+			&CourtUserVoteCommitment {
+				hash: Sha256::digest(to_json_vec(&CourtVoteCommitmentPayload {
+					proposal_id: new_proposal_id,
+					voter: proposer_addr.clone(),
+					vote: proposer_vote,
+					salt: Binary::default(),
+				})?)
+				.into(),
+				votes: proposer_votes,
+			},
+		)?;
+	} else {
+		get_proposal_user_vote_store().set(
+			&(new_proposal_id, proposer),
+			&(&CourtUserVoteInfoJsonable {
+				active_votes: proposer_votes.into(),
+				vote: proposer_vote,
+				split: None,
+				choice: None,
+			})
+				.try_into()?,
+		)?;
+	}
+	get_user_active_proposal_id_set().add(&(proposer, new_proposal_id))?;
+
+	let mut proposal_event = Event::new("proposal")
+		.add_attribute("proposal_id", new_proposal_id.to_string())
+		.add_attribute("proposer", proposer_addr.clone());
+	if new_proposal.is_multiple_choice() {
+		proposal_event = proposal_event.add_attribute("option_count", new_proposal.option_count().to_string());
+	}
+	if new_proposal.is_optimistic() {
+		proposal_event = proposal_event.add_attribute("optimistic", "true");
+	}
+	if new_proposal.is_expedited() {
+		proposal_event = proposal_event.add_attribute("expedited", "true");
+	}
+	if new_proposal.is_private() {
+		proposal_event = proposal_event.add_attribute("private", "true");
+	}
+	if let Some(metadata) = metadata {
+		proposal_event = proposal_event.add_attribute("title", metadata.title);
+		if !metadata.description.is_empty() {
+			proposal_event = proposal_event.add_attribute("description", metadata.description);
+		}
+		if let Some(content_uri) = metadata.content_uri {
+			proposal_event = proposal_event.add_attribute("content_uri", content_uri);
+		}
+		if let Some(content_sha256) = metadata.content_sha256 {
+			proposal_event = proposal_event.add_attribute("content_sha256", HexBinary::from(content_sha256).to_hex());
+		}
+	}
+	let vote_event = if new_proposal.is_private() {
+		Event::new("vote_committed")
+			.add_attribute("proposal_id", new_proposal_id.to_string())
+			.add_attribute("voter", proposer_addr)
+			.add_attribute("votes", Uint128::from(proposer_votes))
+	} else {
+		Event::new("vote")
+			.add_attribute("proposal_id", new_proposal_id.to_string())
+			.add_attribute("voter", proposer_addr)
+			.add_attribute("votes", Uint128::from(proposer_votes))
+			.add_attribute("vote", proposer_vote.to_string())
+	};
+	Ok(Response::new().add_event(proposal_event).add_event(vote_event))
+}
+
+/// Runs all the checks a new proposal must pass and returns its messages in the form they're stored in, along with
+/// the track it belongs to.
+///
+/// Used both when proposing a transaction and when simulating a proposal, so this must not write to storage.
+pub fn validate_proposal(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	app_config: &CourtAppConfig,
+	proposal_tracks: &CourtProposalTracks,
+	token_supply: u128,
+	proposer_votes: u128,
+	msgs: Vec<ProposedCourtMsgJsonable>,
+	expiry_time_seconds: u32,
+) -> Result<(Vec<ProposedCourtMsg>, Option<u8>), CourtContractError> {
 	if msgs.len() == 0 {
 		return Err(CourtContractError::EmptyProposal);
 	}
+	let msgs = validate_proposal_msgs(
+		querier,
+		&CourtStargateAllowlist::load_or_default()?,
+		msgs,
+		"propose_transaction.msgs",
+	)?;
+	let track = proposal_tracks.classify(msgs.iter());
+	validate_proposer(
+		&proposal_tracks.app_config_for(track, app_config),
+		token_supply,
+		proposer_votes,
+		expiry_time_seconds,
+	)?;
+	Ok((msgs, track))
+}
+
+/// Like [validate_proposal], but for each option of a multiple-choice proposal.
+pub fn validate_multiple_choice_proposal(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	app_config: &CourtAppConfig,
+	proposal_tracks: &CourtProposalTracks,
+	token_supply: u128,
+	proposer_votes: u128,
+	options: Vec<CourtProposalOption>,
+	expiry_time_seconds: u32,
+) -> Result<(Vec<TransactionProposalOption>, Option<u8>), CourtContractError> {
+	if options.len() < 2 || options.len() > MAX_PROPOSAL_OPTIONS as usize {
+		return Err(CourtContractError::InvalidProposalOptionCount(MAX_PROPOSAL_OPTIONS));
+	}
+	if options.iter().any(|option| option.msgs.is_empty()) {
+		return Err(CourtContractError::EmptyProposal);
+	}
+	let stargate_allowlist = CourtStargateAllowlist::load_or_default()?;
+	let options = options
+		.into_iter()
+		.enumerate()
+		.map(|(index, option)| {
+			// "Yes" and "no" don't map onto the options of a multiple-choice proposal
+			if let Some(msg_index) = option
+				.msgs
+				.iter()
+				.position(|msg| matches!(msg, ProposedCourtMsgJsonable::GovVoteByTally { .. }))
+			{
+				return Err(CourtContractError::GovVoteByTallyNotAllowed {
+					proprety_name: format!("propose_multiple_choice.options[{index}].msgs[{msg_index}]"),
+				});
+			}
+			Ok(TransactionProposalOption {
+				label: option.label,
+				messages: validate_proposal_msgs(
+					querier,
+					&stargate_allowlist,
+					option.msgs,
+					&format!("propose_multiple_choice.options[{index}].msgs"),
+				)?,
+			})
+		})
+		.collect::<Result<Vec<_>, CourtContractError>>()?;
+	// Any of the options may end up being executed, so all of their messages are considered
+	let track = proposal_tracks.classify(options.iter().flat_map(|option| option.messages.iter()));
+	validate_proposer(
+		&proposal_tracks.app_config_for(track, app_config),
+		token_supply,
+		proposer_votes,
+		expiry_time_seconds,
+	)?;
+	Ok((options, track))
+}
+
+fn validate_proposal_metadata(
+	metadata: TransactionProposalMetadataJsonable,
+) -> Result<TransactionProposalMetadata, CourtContractError> {
+	if metadata.title.is_empty()
+		|| metadata.title.len() > MAX_PROPOSAL_TITLE_LEN
+		|| metadata.description.len() > MAX_PROPOSAL_DESCRIPTION_LEN
+	{
+		return Err(CourtContractError::InvalidProposalMetadata {
+			max_title_len: MAX_PROPOSAL_TITLE_LEN,
+			max_description_len: MAX_PROPOSAL_DESCRIPTION_LEN,
+		});
+	}
+	if metadata
+		.content_uri
+		.as_ref()
+		.is_some_and(|content_uri| content_uri.is_empty() || content_uri.len() > MAX_PROPOSAL_CONTENT_URI_LEN)
+		|| metadata
+			.content_sha256
+			.as_ref()
+			.is_some_and(|content_sha256| content_sha256.len() != 32)
+	{
+		return Err(CourtContractError::InvalidProposalContent {
+			max_content_uri_len: MAX_PROPOSAL_CONTENT_URI_LEN,
+		});
+	}
+	Ok(metadata.try_into()?)
+}
+
+fn validate_proposer(
+	app_config: &CourtAppConfig,
+	token_supply: u128,
+	proposer_votes: u128,
+	expiry_time_seconds: u32,
+) -> Result<(), CourtContractError> {
 	if expiry_time_seconds > app_config.max_proposal_expiry_time_seconds {
 		return Err(CourtContractError::ProposalLivesTooLong);
 	}
+	if expiry_time_seconds < app_config.min_proposal_expiry_time_seconds {
+		return Err(CourtContractError::ProposalLivesTooShort);
+	}
 	if !app_config.allow_new_proposals() {
 		return Err(CourtContractError::NewProposalsNotAllowed);
 	}
-	let proposer_vote_percent = u8::try_from(
-		user_stats
-			.staked_votes
-			.checked_mul(100u128.into())
-			.unwrap()
-			.checked_div(token_supply.into())
-			.unwrap(),
-	)
-	.unwrap();
-	if proposer_vote_percent < app_config.minimum_vote_proposal_percent {
+	// Thanks to locked votes, this may be more than 100%
+	let proposer_vote_percent = proposer_votes
+		.checked_mul(100)
+		.unwrap()
+		.checked_div(token_supply)
+		.unwrap();
+	if proposer_vote_percent < app_config.minimum_vote_proposal_percent as u128 {
 		return Err(CourtContractError::InsufficientVotesForProposal);
 	}
-	let msgs = msgs
-		.into_iter()
+	Ok(())
+}
+
+/// Resolves the addresses of the proposed messages and checks that they're allowed, `msgs_proprety_name` is used for
+/// error messages.
+fn validate_proposal_msgs(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	stargate_allowlist: &CourtStargateAllowlist,
+	msgs: Vec<ProposedCourtMsgJsonable>,
+	msgs_proprety_name: &str,
+) -> Result<Vec<ProposedCourtMsg>, CourtContractError> {
+	msgs.into_iter()
 		.enumerate()
 		.map(|(index, mut proposal)| {
 			match &mut proposal {
 				ProposedCourtMsgJsonable::SendCoin { to, denom, amount: _ } => {
 					if denom.is_erc20() {
 						if !to.starts_with("0x") {
-							*to = SeiQuerier::new(&env_info.querier)
+							*to = SeiQuerier::new(querier)
 								.get_evm_address(to.clone())
 								.ok()
 								.filter(|response_addr| response_addr.evm_address.len() > 0 && response_addr.associated)
 								.map(|response| response.evm_address)
 								.ok_or(CourtContractError::EvmAddressRequired {
 									wrong_addr: to.clone(),
-									proprety_name: format!("propose_transaction.msgs[{index}].to"),
+									proprety_name: format!("{msgs_proprety_name}[{index}].to"),
 								})?;
 						}
 					} else {
 						if to.starts_with("0x") {
-							*to = SeiQuerier::new(&env_info.querier)
+							*to = SeiQuerier::new(querier)
 								.get_sei_address(to.clone())
 								.ok()
 								.filter(|response_addr| response_addr.sei_address.len() > 0 && response_addr.associated)
 								.map(|response| response.sei_address)
 								.ok_or(CourtContractError::SeiAddressRequired {
 									wrong_addr: to.clone(),
-									proprety_name: format!("propose_transaction.msgs[{index}].to"),
+									proprety_name: format!("{msgs_proprety_name}[{index}].to"),
 								})?;
 						}
 					}
 				}
+				ProposedCourtMsgJsonable::Stargate { type_url, .. } => {
+					stargate_allowlist.enforce_allowed(type_url)?;
+				}
+				ProposedCourtMsgJsonable::GovVoteWeighted { options, .. } => {
+					let total_weight = options
+						.iter()
+						.try_fold(Decimal::zero(), |total_weight, option| {
+							total_weight.checked_add(option.weight)
+						})
+						.unwrap_or_default();
+					let has_duplicates = options
+						.iter()
+						.enumerate()
+						.any(|(i, option)| options[..i].iter().any(|other| other.option == option.option));
+					if total_weight != Decimal::one() || has_duplicates {
+						return Err(CourtContractError::InvalidGovVoteWeights {
+							proprety_name: format!("{msgs_proprety_name}[{index}].options"),
+						});
+					}
+				}
 				_ => {}
 			}
 			Ok(proposal.try_into()?)
 		})
-		.collect::<Result<_, CourtContractError>>()?;
-
-	let mut proposal_infos = get_transaction_proposal_info_vec();
-	let mut proposal_msgs = get_transaction_proposal_messages_vec();
-	let new_proposal = TransactionProposalInfo::new(
-		proposer.clone(),
-		user_stats.staked_votes,
-		env_info
-			.env
-			.block
-			.time
-			.plus_seconds(expiry_time_seconds as u64)
-			.millis(),
-	);
-	let new_proposal_id = proposal_infos.len();
-	proposal_infos.push(&new_proposal)?;
-	proposal_msgs.push(&msgs)?;
-	assert_eq!(proposal_infos.len(), proposal_msgs.len());
-
-	get_proposal_user_vote_store().set(
-		&(new_proposal_id, proposer),
-		&(&CourtUserVoteInfoJsonable {
-			active_votes: user_stats.staked_votes.into(),
-			vote: CourtUserVoteStatus::Approve,
-		})
-			.try_into()?,
-	)?;
-	get_user_active_proposal_id_set().add(&(proposer, new_proposal_id))?;
-	Ok(Response::new()
-		.add_event(
-			Event::new("proposal")
-				.add_attribute("proposal_id", new_proposal_id.to_string())
-				.add_attribute("proposer", proposer_addr.clone()),
-		)
-		.add_event(
-			Event::new("vote")
-				.add_attribute("proposal_id", new_proposal_id.to_string())
-				.add_attribute("voter", proposer_addr)
-				.add_attribute("votes", Uint128::from(user_stats.staked_votes))
-				.add_attribute("vote", "approve"),
-		))
+		.collect()
 }
@@ -30,6 +30,8 @@ pub enum CourtContractError {
 	EmptyProposal,
 	#[error("Proposal takes too long to expire")]
 	ProposalLivesTooLong,
+	#[error("Proposal expires too soon")]
+	ProposalLivesTooShort,
 	#[error("User doesn't have enough staked votes to submit a proposal")]
 	InsufficientVotesForProposal,
 	#[error("New proposals currently aren't being accepted")]
@@ -42,5 +44,66 @@ pub enum CourtContractError {
 	EvmAddressRequired { wrong_addr: String, proprety_name: String },
 	#[error("Invalid address \"{wrong_addr}\", an address beginning with \"sei1\" is required for {proprety_name}")]
 	SeiAddressRequired { wrong_addr: String, proprety_name: String },
+	#[error("Stargate messages of type \"{0}\" are not allowed")]
+	StargateMessageNotAllowed(String),
+	#[error("Split votes must be cast with VoteWeighted and must add up to 10000 basis points")]
+	InvalidVoteSplit,
+	#[error("Multiple-choice proposals must have at least 2 options and at most {0}")]
+	InvalidProposalOptionCount(u8),
+	#[error("Multiple-choice proposals can only be voted on by choosing an option which exists or by abstaining")]
+	InvalidVoteChoice,
+	#[error("Weighted vote options for {proprety_name} must be unique and their weights must add up to 1")]
+	InvalidGovVoteWeights { proprety_name: String },
+	#[error("Signalling proposals have nothing to execute")]
+	SignalProposalNotExecutable,
+	#[error("Proposal title must be 1-{max_title_len} bytes, description at most {max_description_len} bytes")]
+	InvalidProposalMetadata {
+		max_title_len: usize,
+		max_description_len: usize,
+	},
+	#[error("Proposal content URI must be 1-{max_content_uri_len} bytes, content SHA-256 hash must be 32 bytes")]
+	InvalidProposalContent { max_content_uri_len: usize },
+	#[error("Optimistic proposals can only be made by designated proposers or with allowlisted kinds of messages")]
+	OptimisticProposalNotAllowed,
+	#[error("Proposal tracks must have a name and a non-zero execution expiry time")]
+	InvalidProposalTrack,
+	#[error("There can be at most {0} proposal tracks")]
+	TooManyProposalTracks(usize),
+	#[error("Proposal can't be executed until {0} (unix milliseconds) due to its track's timelock")]
+	ProposalTimelocked(u64),
+	#[error("Expedited proposals are disabled, and cannot be optimistic")]
+	ExpeditedProposalNotAllowed,
+	#[error("Staked votes can be locked for at most {0} seconds")]
+	StakeLockTooLong(u32),
+	#[error("Staked votes are locked until {0} (unix milliseconds)")]
+	StakeLocked(u64),
+	#[error("Signed vote has an invalid public key or signature")]
+	InvalidVoteSignature,
+	#[error("Signed vote nonce must be {0}")]
+	InvalidVoteNonce(u64),
+	#[error("EVM address {0} which signed the vote isn't associated with a Sei address")]
+	UnassociatedVoteSigner(String),
+	#[error("Private proposals are disabled, and they can't be optimistic or expedited")]
+	PrivateProposalNotAllowed,
+	#[error("Votes on proposal {0} must be committed and then revealed")]
+	VoteMustBeCommitted(u32),
+	#[error("Proposal {0} isn't private, so votes on it can't be committed")]
+	ProposalNotPrivate(u32),
+	#[error("Vote commitments must be a 32-byte SHA-256 hash")]
+	InvalidVoteCommitment,
+	#[error("No vote was committed to proposal {0}")]
+	NoVoteCommitment(u32),
+	#[error("The revealed vote doesn't match the one which was committed")]
+	VoteRevealMismatch,
+	#[error("{proprety_name} can't vote by tally, as multiple-choice proposals have no yes or no outcome")]
+	GovVoteByTallyNotAllowed { proprety_name: String },
+	#[error("{proprety_name} must be at most {max}")]
+	ConfigValueTooHigh { proprety_name: String, max: u32 },
+	#[error("min_proposal_expiry_time_seconds must not be greater than max_proposal_expiry_time_seconds")]
+	InvalidProposalExpiryTimeRange,
+	#[error("Quadratic voting measures turnout against the staked votes, so quorum_supply must be \"staked\"")]
+	QuadraticVotingRequiresStakedQuorum,
+	#[error("Migrating from {contract} {version} isn't supported, as its storage layout is incompatible")]
+	UnsupportedMigration { contract: String, version: String },
 }
 impl_from_cosmwasm_std_error_common!(CourtContractError);
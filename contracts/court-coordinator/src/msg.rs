@@ -3,17 +3,22 @@ use cosmwasm_schema::{
 	schemars::{self, JsonSchema},
 	QueryResponses,
 };
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, CosmosMsg, Decimal, Uint128};
+use sei_cosmwasm::SeiMsg;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-	proposed_msg::ProposedCourtMsgJsonable,
+	proposed_msg::{ProposedCourtMsgDecoded, ProposedCourtMsgJsonable},
 	state::{
-		app::{CourtAppConfigJsonable, TransactionProposalInfoJsonable, TransactionProposalStatus},
+		app::{
+			CourtAppConfigJsonable, CourtProposalTrackJsonable, CourtQuorumSupply, TransactionProposalInfoJsonable,
+			TransactionProposalMetadataJsonable, TransactionProposalStatus,
+		},
 		user::{CourtUserStatsJsonable, CourtUserVoteInfoJsonable, CourtUserVoteStatus},
 	},
 };
 
+/// Contracts can only be migrated from the same version, as the storage layouts of earlier versions are incompatible
 #[cw_serde]
 pub struct CourtMigrateMsg {}
 
@@ -25,8 +30,68 @@ pub struct CourtInstantiateMsg {
 	pub minimum_vote_proposal_percent: u8,
 	pub minimum_vote_turnout_percent: u8,
 	pub minimum_vote_pass_percent: u8,
+	/// Proposals are vetoed if more than this percentage of votes cast are vetoes, 0 disables vetoing.
+	#[serde(default)]
+	pub veto_threshold_percent: u8,
+	/// Optimistic proposals are rejected if the votes opposing or vetoing them are more than this percentage of the
+	/// vote supply. By default, any opposition rejects them.
+	#[serde(default)]
+	pub optimistic_opposition_threshold_percent: u8,
+	/// Expedited proposals pass once `expedited_voting_period_seconds` have passed if this percentage of the vote
+	/// supply has voted...
+	#[serde(default)]
+	pub expedited_minimum_vote_turnout_percent: u8,
+	/// ...and this percentage of the votes cast approve.
+	#[serde(default)]
+	pub expedited_minimum_vote_pass_percent: u8,
+	/// 0 disables expedited proposals.
+	#[serde(default)]
+	pub expedited_voting_period_seconds: u32,
+	#[serde(default)]
+	pub min_proposal_expiry_time_seconds: u32,
 	pub max_proposal_expiry_time_seconds: u32,
 	pub execution_expiry_time_seconds: u32,
+	/// If a vote cast within this many seconds of a proposal's expiry changes its outcome...
+	#[serde(default)]
+	pub quiet_ending_window_seconds: u32,
+	/// ...voting on it is extended by this many seconds...
+	#[serde(default)]
+	pub quiet_ending_extension_seconds: u32,
+	/// ...up to this many times. 0 disables extensions.
+	#[serde(default)]
+	pub max_quiet_ending_extensions: u8,
+	/// Newly staked votes can't be voted or proposed with until they've been staked for this long. 0 makes them usable
+	/// immediately.
+	#[serde(default)]
+	pub min_stake_age_seconds: u32,
+	/// The longest users may lock their staked votes for, 0 disables locking.
+	#[serde(default)]
+	pub max_stake_lock_seconds: u32,
+	/// How much voting power votes locked for `max_stake_lock_seconds` have, e.g. 400 is 4x. Votes which remain locked
+	/// for less time have proportionally less.
+	#[serde(default)]
+	pub max_stake_lock_vote_multiplier_percent: u16,
+	/// If enabled, users vote and propose with the square root of their voting power, and turnout is measured against
+	/// the square root of the staked votes, so `quorum_supply` must be `staked`. Votes split between many addresses
+	/// count for more than if they were held by one, so this relies on each voter only using one address. As there's no
+	/// bound on how many votes could still be cast, proposals can't pass before voting ends unless they're expedited.
+	#[serde(default)]
+	pub quadratic_voting: bool,
+	/// Which votes turnout is measured against, the total supply by default. This must be `staked` if quadratic voting
+	/// is enabled.
+	#[serde(default)]
+	pub quorum_supply: CourtQuorumSupply,
+	/// The most votes a single user may vote or propose with, any more are ignored. 0 disables this cap.
+	#[serde(default)]
+	pub max_user_votes: Uint128,
+	/// Like `max_user_votes`, but in basis points of the vote supply which turnout is measured against. 0 disables this
+	/// cap. Like the votes they cap, both are square roots if quadratic voting is enabled.
+	#[serde(default)]
+	pub max_user_votes_bps: u16,
+	/// How long votes committed to private proposals may be revealed for once voting on them ends. 0 disables private
+	/// proposals.
+	#[serde(default)]
+	pub reveal_period_seconds: u32,
 	pub vote_share_name: String,
 	pub vote_share_symbol: String,
 	pub vote_share_description: String,
@@ -37,13 +102,7 @@ pub enum CourtAdminExecuteMsg {
 	/// Change config options
 	/// 
 	/// This cannot be called while proposals can be added
-	ChangeConfig {
-		minimum_vote_proposal_percent: Option<u8>,
-		minimum_vote_turnout_percent: Option<u8>,
-		minimum_vote_pass_percent: Option<u8>,
-		max_proposal_expiry_time_seconds: Option<u32>,
-		execution_expiry_time_seconds: Option<u32>,
-	},
+	ChangeConfig(CourtConfigChanges),
 	/// Change the admin to the address specified
 	/// 
 	/// This is an immediate change
@@ -59,6 +118,61 @@ pub enum CourtAdminExecuteMsg {
 		receiver: Addr,
 		amount: Uint128,
 	},
+	/// Adds or removes message types from the list of `type_url`s which stargate proposals may use.
+	///
+	/// Message types may only be added by proposals, so this contract has to be its own admin to do so.
+	AllowStargateMessages {
+		type_urls: Vec<String>,
+		allowed: bool,
+	},
+	/// Adds or removes users who may make optimistic proposals, and kinds of messages (e.g. "send_coin") which anyone
+	/// may make optimistic proposals with.
+	AllowOptimisticProposals {
+		proposers: Vec<Addr>,
+		msg_kinds: Vec<String>,
+		allowed: bool,
+	},
+	/// Adds a proposal track, or replaces the one with the same name. Proposals are assigned to the first track which
+	/// any of their messages match, so stricter tracks should be added first.
+	///
+	/// This cannot be called while there are active votes
+	SetProposalTrack {
+		track: CourtProposalTrackJsonable,
+	},
+	/// Removes the proposal track with the specified name
+	///
+	/// This cannot be called while there are active votes
+	RemoveProposalTrack {
+		name: String,
+	},
+}
+
+/// Config options to change, options which aren't specified are left as-is.
+#[cw_serde]
+#[derive(Default)]
+pub struct CourtConfigChanges {
+	pub minimum_vote_proposal_percent: Option<u8>,
+	pub minimum_vote_turnout_percent: Option<u8>,
+	pub minimum_vote_pass_percent: Option<u8>,
+	pub veto_threshold_percent: Option<u8>,
+	pub optimistic_opposition_threshold_percent: Option<u8>,
+	pub expedited_minimum_vote_turnout_percent: Option<u8>,
+	pub expedited_minimum_vote_pass_percent: Option<u8>,
+	pub expedited_voting_period_seconds: Option<u32>,
+	pub min_proposal_expiry_time_seconds: Option<u32>,
+	pub max_proposal_expiry_time_seconds: Option<u32>,
+	pub execution_expiry_time_seconds: Option<u32>,
+	pub quiet_ending_window_seconds: Option<u32>,
+	pub quiet_ending_extension_seconds: Option<u32>,
+	pub max_quiet_ending_extensions: Option<u8>,
+	pub min_stake_age_seconds: Option<u32>,
+	pub max_stake_lock_seconds: Option<u32>,
+	pub max_stake_lock_vote_multiplier_percent: Option<u16>,
+	pub quadratic_voting: Option<bool>,
+	pub quorum_supply: Option<CourtQuorumSupply>,
+	pub max_user_votes: Option<Uint128>,
+	pub max_user_votes_bps: Option<u16>,
+	pub reveal_period_seconds: Option<u32>,
 }
 
 #[cw_serde]
@@ -67,6 +181,11 @@ pub enum CourtExecuteMsg {
 	Admin(CourtAdminExecuteMsg),
 	/// "Stake" your voting shares, doing this is what allows you to vote on proposals
 	Stake,
+	/// Like `Stake`, but all your staked votes can't be unstaked until `lock_seconds` from now, and they have more
+	/// voting power the longer they remain locked for.
+	StakeLocked {
+		lock_seconds: u32,
+	},
 	/// Have your voting shares which you previously staked returned to you.
 	/// 
 	/// You may not do this while you are voting on active proposals
@@ -76,6 +195,50 @@ pub enum CourtExecuteMsg {
 		id: u32,
 		vote: CourtUserVoteStatus,
 	},
+	/// Like `Vote`, but your votes are split between the options. The amounts are in basis points and must add up to
+	/// 10000.
+	VoteWeighted {
+		id: u32,
+		approve: u16,
+		oppose: u16,
+		abstain: u16,
+	},
+	/// Vote for one of the options of a multiple-choice proposal, the first option is 0.
+	VoteChoice {
+		id: u32,
+		option: u8,
+	},
+	/// Like `Vote`, but for multiple proposals at once, as a list of `(id, vote)` pairs.
+	VoteMany {
+		votes: Vec<(u32, CourtUserVoteStatus)>,
+		/// If `true`, proposals which are no longer pending are skipped rather than failing the whole transaction.
+		#[serde(default)]
+		skip_inactive: bool,
+	},
+	/// Casts votes which users have signed on their behalf, so that they don't need to pay gas to vote. Anyone may
+	/// submit these.
+	SubmitSignedVotes {
+		votes: Vec<CourtSignedVote>,
+		/// If `true`, votes which can't be cast for any reason, such as an invalid signature or nonce or the proposal no
+		/// longer being pending, are skipped rather than failing the whole transaction.
+		#[serde(default)]
+		skip_invalid: bool,
+	},
+	/// Commit to a vote on a private proposal without revealing it, `hash` is the SHA-256 hash of the
+	/// [CourtVoteCommitmentPayload] of your vote. You can replace your commitment until voting ends.
+	///
+	/// Committed votes can't be unstaked until the proposal is finalized, even if they're never revealed. Once it is,
+	/// `DeactivateVotes` has to be called to unlock them, like with any other vote.
+	CommitVote {
+		id: u32,
+		hash: Binary,
+	},
+	/// Reveal the vote you committed to once voting on a private proposal has ended, only revealed votes are counted.
+	RevealVote {
+		id: u32,
+		vote: CourtUserVoteStatus,
+		salt: Binary,
+	},
 	/// This must be done before unstaking
 	DeactivateVotes {
 		user: Option<Addr>,
@@ -85,6 +248,38 @@ pub enum CourtExecuteMsg {
 	ProposeTransaction {
 		msgs: Vec<ProposedCourtMsgJsonable>,
 		expiry_time_seconds: u32,
+		/// What the proposal is about, for display purposes
+		metadata: Option<TransactionProposalMetadataJsonable>,
+		/// If `true`, the proposal passes once voting ends unless enough votes oppose or veto it, regardless of
+		/// turnout. Only designated proposers may do this, unless all messages are of an allowlisted kind.
+		#[serde(default)]
+		optimistic: bool,
+		/// If `true`, the proposal may pass once the configured expedited voting period has passed, as long as it meets
+		/// the higher expedited turnout and pass requirements. Otherwise it's treated like any other proposal.
+		#[serde(default)]
+		expedited: bool,
+		/// If `true`, votes are committed without revealing them until voting ends, so that the running tally doesn't
+		/// sway anyone. They're revealed during the configured reveal period, and only the revealed votes count.
+		///
+		/// Your approving vote is committed with an empty salt, so you have to reveal it like everyone else. You may
+		/// replace it with a commitment of your own.
+		#[serde(default)]
+		private: bool,
+	},
+	/// Propose a choice between several transactions, only the option with the most votes may be executed.
+	///
+	/// Your votes are counted as abstaining until you vote for an option yourself.
+	ProposeMultipleChoice {
+		options: Vec<CourtProposalOption>,
+		expiry_time_seconds: u32,
+		/// What the proposal is about, for display purposes
+		metadata: Option<TransactionProposalMetadataJsonable>,
+	},
+	/// Propose a non-binding poll which only has a title, a description, and optionally a link to its content. It's
+	/// voted on like any other proposal, but there's nothing to execute once it passes.
+	ProposeSignal {
+		metadata: TransactionProposalMetadataJsonable,
+		expiry_time_seconds: u32,
 	},
 	/// If a proposal has passed, this is how you execute it.
 	ExecuteProposal {
@@ -92,6 +287,79 @@ pub enum CourtExecuteMsg {
 	},
 }
 
+#[cw_serde]
+pub struct CourtProposalOption {
+	pub label: String,
+	pub msgs: Vec<ProposedCourtMsgJsonable>,
+}
+
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum CourtVoteSignatureType {
+	/// A signature of the SHA-256 hash of the [CourtSignedVotePayload], serialized as compact JSON.
+	#[default]
+	Secp256k1,
+	/// An EIP-712 typed data signature made with an EVM wallet, 65 bytes with the recovery id at the end. The voter is
+	/// the Sei address associated with the signer's EVM address.
+	///
+	/// The domain is `EIP712Domain(string name,string version)` with the name `Court` and version `1`, and the message
+	/// is `Vote(string chainId,string court,uint32 proposalId,string vote,uint8 option,uint64 nonce)` with the fields
+	/// of the [CourtSignedVotePayload], where `option` is 0 if it's null.
+	Eip712,
+}
+
+#[cw_serde]
+pub struct CourtSignedVote {
+	#[serde(default)]
+	pub signature_type: CourtVoteSignatureType,
+	/// The voter's compressed secp256k1 public key, their address is derived from this. Not needed for EIP-712
+	/// signatures, as the signer is recovered from them.
+	#[serde(default)]
+	pub pubkey: Binary,
+	pub proposal_id: u32,
+	/// Must be `choice` if `option` is set, votes can't be split.
+	pub vote: CourtUserVoteStatus,
+	/// The option of a multiple-choice proposal which is voted for, the first option is 0.
+	pub option: Option<u8>,
+	/// Must be the voter's `SignedVoteNonce` for the proposal, which is incremented by every vote of theirs which is cast
+	/// on it, signed or not. Voting directly therefore invalidates any signed votes which haven't been submitted yet.
+	/// Nonces are kept per proposal so that a withheld signed vote only holds up later signed votes on the same
+	/// proposal, and the voter can always vote on it themselves instead.
+	pub nonce: u64,
+	/// The signature of the [CourtSignedVotePayload] of this vote, see [CourtVoteSignatureType]
+	pub signature: Binary,
+}
+
+/// What users sign in order to vote via [CourtExecuteMsg::SubmitSignedVotes]
+///
+/// This is serialized as compact JSON with the fields in this order, `option` is omitted if it's null. e.g.
+/// `{"chain_id":"pacific-1","court":"sei1...","proposal_id":1,"vote":"approve","nonce":0}`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtSignedVotePayload {
+	pub chain_id: String,
+	/// The address of this contract
+	pub court: Addr,
+	pub proposal_id: u32,
+	pub vote: CourtUserVoteStatus,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub option: Option<u8>,
+	pub nonce: u64,
+}
+
+/// What the hash of a [CourtExecuteMsg::CommitVote] commits to
+///
+/// This is serialized as compact JSON with the fields in this order, e.g.
+/// `{"proposal_id":1,"voter":"sei1...","vote":"approve","salt":"aGVsbG8="}`. The salt should be at least 32 random
+/// bytes so that the vote can't be guessed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtVoteCommitmentPayload {
+	pub proposal_id: u32,
+	pub voter: Addr,
+	/// Can't be `split` or `choice`
+	pub vote: CourtUserVoteStatus,
+	pub salt: Binary,
+}
+
 //#[derive(Debug, BorshDeserialize, BorshSerialize, BorshSchema)]
 //pub enum Cw20ReceiveMsgData {}
 
@@ -110,26 +378,72 @@ pub enum CourtQueryMsg {
 	/// The number of proposals which exist
 	#[returns(u32)]
 	ProposalAmount,
+	/// The `type_url`s which stargate proposals may use
+	#[returns(Vec<String>)]
+	StargateAllowlist,
+	/// Who may make optimistic proposals, and which kinds of messages anyone may make optimistic proposals with
+	#[returns(CourtQueryResponseOptimisticAllowlist)]
+	OptimisticAllowlist,
+	/// The proposal tracks in the order proposals are checked against them, `info.track` of a proposal is an index of this
+	#[returns(Vec<CourtProposalTrackJsonable>)]
+	ProposalTracks,
 	#[returns(Option<CourtQueryResponseTransactionProposal>)]
 	/// Gets a specific proposal, may be null
 	GetProposal {
 		/// The proposal ID
-		id: u32
+		id: u32,
+		/// If `true`, `decoded_messages` will contain human-readable details of each message
+		decode: Option<bool>,
+		/// Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming
+		/// nothing else changes. Statuses are projected from the current votes, so past times may not be accurate.
+		at_time_ms: Option<u64>,
 	},
 	/// Returns a list of proposals based on the range provided
-	#[returns(Vec<CourtQueryResponseTransactionProposal>)]
+	#[returns(CourtQueryResponseProposals)]
 	GetProposals {
-		/// Where to start the array from
+		/// Where to start the array from, only counting proposals which match the `filter`. Use `after` with the
+		/// `next_after` cursor returned to get the next page instead.
 		skip: Option<u32>,
 		/// The maximum length of the array
 		limit: Option<u32>,
 		/// if `false`, array will be in ascending order. if `true`, descending order.
 		descending: bool,
+		/// Only include proposals which come after this proposal ID in the specified order, applied before `skip`.
+		///
+		/// Useful for pagination, as this can be set to the last `proposal_id` of the previous page.
+		after: Option<u32>,
+		/// Only include proposals which match all the specified criteria
+		filter: Option<CourtQueryProposalFilter>,
+		/// If `true`, `messages` will be empty for every proposal returned, including the messages of each option
+		omit_messages: Option<bool>,
+		/// Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming
+		/// nothing else changes. Statuses are projected from the current votes, so past times may not be accurate.
+		at_time_ms: Option<u64>,
+	},
+	/// Returns a breakdown of a proposal's votes and what it would take for it to pass or fail, may be null
+	#[returns(Option<CourtQueryResponseProposalTally>)]
+	ProposalTally {
+		/// The proposal ID
+		id: u32,
+		/// Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming
+		/// nothing else changes. Statuses are projected from the current votes, so past times may not be accurate.
+		at_time_ms: Option<u64>,
+	},
+	/// Runs the same checks as `ProposeTransaction` without creating a proposal, and returns what would be executed
+	/// if it passes, assuming no one else votes on it.
+	#[returns(CourtQueryResponseSimulatedProposal)]
+	SimulateProposal {
+		msgs: Vec<ProposedCourtMsgJsonable>,
+		expiry_time_seconds: u32,
+		proposer: Addr,
 	},
 	#[returns(CourtUserStatsJsonable)]
 	UserStats { user: Addr },
 	#[returns(CourtUserVoteInfoJsonable)]
 	UserVoteInfo { user: Addr, proposal_id: u32 },
+	/// The nonce the next vote the user signs for the proposal must have, see [CourtSignedVote]
+	#[returns(u64)]
+	SignedVoteNonce { user: Addr, proposal_id: u32 },
 	#[returns(Vec<CourtQueryUserWithActiveProposal>)]
 	GetUsersWithActiveProposals {
 		after: Option<CourtQueryUserWithActiveProposal>,
@@ -162,6 +476,38 @@ pub struct CourtQueryResponseDenom {
 pub struct CourtQueryResponseTotalSupply {
 	/// Total supply of voting shares
 	pub votes: Uint128,
+	/// Voting shares which users have staked
+	#[serde(default)]
+	pub staked_votes: Uint128,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtQueryResponseOptimisticAllowlist {
+	pub proposers: Vec<Addr>,
+	pub msg_kinds: Vec<String>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CourtQueryProposalFilter {
+	/// Only include proposals made by this user
+	pub proposer: Option<Addr>,
+	/// Only include proposals with messages which interact with this contract, may be a 0x address.
+	pub target: Option<String>,
+	/// Only include proposals with this status
+	///
+	/// Unless `proposer` or `target` is also specified, or the status is `pending` at the current time, every proposal
+	/// checked counts towards `limit`, even if it doesn't match. So fewer proposals may be returned even though more
+	/// match, use the `next_after` cursor to continue from where the search stopped.
+	pub status: Option<TransactionProposalStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtQueryResponseProposals {
+	pub proposals: Vec<CourtQueryResponseTransactionProposal>,
+	/// If there may be more proposals, set `after` to this to get the next page. This may be set even if `proposals`
+	/// is empty, as only so many proposals are checked when filtering by status.
+	pub next_after: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -170,6 +516,71 @@ pub struct CourtQueryResponseTransactionProposal {
 	pub status: TransactionProposalStatus,
 	pub info: TransactionProposalInfoJsonable,
 	pub messages: Vec<ProposedCourtMsgJsonable>,
+	/// Only present when requested, see [ProposedCourtMsgJsonable::decode]
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub decoded_messages: Option<Vec<ProposedCourtMsgDecoded>>,
+	/// Only present for multiple-choice proposals
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub options: Vec<CourtQueryResponseProposalOption>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<TransactionProposalMetadataJsonable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtQueryResponseProposalOption {
+	pub label: String,
+	/// Empty once the proposal has been executed, the winning option's messages are moved to the proposal's messages
+	pub messages: Vec<ProposedCourtMsgJsonable>,
+	pub votes: Uint128,
+}
+
+#[cw_serde]
+pub struct CourtQueryResponseProposalTally {
+	pub proposal_id: u32,
+	pub status: TransactionProposalStatus,
+	/// The status this proposal will have once voting ends, assuming no one else votes or changes their vote. For
+	/// private proposals, this is once the reveal period ends and assumes no more votes are revealed.
+	pub projected_status: TransactionProposalStatus,
+	pub votes_for: Uint128,
+	pub votes_against: Uint128,
+	pub votes_abstain: Uint128,
+	pub votes_veto: Uint128,
+	/// The votes each option has, only present for multiple-choice proposals
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub option_votes: Vec<Uint128>,
+	/// The vote supply which turnout is measured against as of when the proposal was made, or its square root if
+	/// quadratic voting is enabled
+	pub total_supply: Uint128,
+	/// All votes cast divided by the total supply
+	pub turnout: Decimal,
+	/// Votes for divided by votes for, against, and vetoes, zero if no one has voted either way
+	pub approval: Decimal,
+	/// Vetoes divided by all votes cast
+	pub veto: Decimal,
+	pub minimum_vote_turnout_percent: u8,
+	pub minimum_vote_pass_percent: u8,
+	pub veto_threshold_percent: u8,
+	/// The additional approving votes needed for this proposal to pass, null if there aren't enough uncast votes
+	pub votes_needed_to_pass: Option<Uint128>,
+	/// The additional opposing or veto votes needed to prevent this proposal from passing, null if there aren't
+	/// enough uncast votes
+	pub votes_needed_to_block: Option<Uint128>,
+	/// Milliseconds until voting ends
+	pub voting_time_remaining_ms: u64,
+	/// Milliseconds until this proposal can be executed because of its track's timelock, assuming it passes
+	pub timelock_remaining_ms: u64,
+	/// Milliseconds until this proposal can no longer be executed, assuming it passes
+	pub execution_time_remaining_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CourtQueryResponseSimulatedProposal {
+	/// The proposal's messages as they would be stored, with any addresses resolved
+	pub messages: Vec<ProposedCourtMsgJsonable>,
+	/// The messages which would be sent when the proposal is executed
+	pub cosmos_msgs: Vec<CosmosMsg<SeiMsg>>,
+	/// The index of the proposal track the proposal would belong to, see the `ProposalTracks` query
+	pub track: Option<u8>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
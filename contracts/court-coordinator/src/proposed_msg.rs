@@ -1,6 +1,9 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use cosmwasm_schema::schemars::{self, JsonSchema};
-use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, StdError, Uint128, WasmMsg};
+use cosmwasm_std::{
+	from_json, to_json_vec, Addr, Binary, Coin, CosmosMsg, Decimal, GovMsg, StdError, Uint128, VoteOption, WasmMsg,
+	WeightedVoteOption,
+};
 use crownfi_cw_common::{
 	data_types::{
 		asset::{FungibleAssetKind, FungibleAssetKindString},
@@ -9,7 +12,11 @@ use crownfi_cw_common::{
 	utils::{bytes_to_ethereum_address, checksumify_ethereum_address, parse_ethereum_address},
 };
 use sei_cosmwasm::SeiMsg;
-use serde::{Deserialize, Serialize};
+use serde::{
+	de::{MapAccess, SeqAccess, Visitor},
+	ser::{SerializeMap, SerializeSeq},
+	Deserialize, Deserializer, Serialize, Serializer,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
 pub struct BorshableCoin {
@@ -33,6 +40,57 @@ impl From<BorshableCoin> for Coin {
 	}
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum BorshableVoteOption {
+	Yes,
+	No,
+	Abstain,
+	NoWithVeto,
+}
+impl From<VoteOption> for BorshableVoteOption {
+	fn from(value: VoteOption) -> Self {
+		match value {
+			VoteOption::Yes => Self::Yes,
+			VoteOption::No => Self::No,
+			VoteOption::Abstain => Self::Abstain,
+			VoteOption::NoWithVeto => Self::NoWithVeto,
+		}
+	}
+}
+impl From<BorshableVoteOption> for VoteOption {
+	fn from(value: BorshableVoteOption) -> Self {
+		match value {
+			BorshableVoteOption::Yes => Self::Yes,
+			BorshableVoteOption::No => Self::No,
+			BorshableVoteOption::Abstain => Self::Abstain,
+			BorshableVoteOption::NoWithVeto => Self::NoWithVeto,
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct BorshableWeightedVoteOption {
+	pub option: BorshableVoteOption,
+	/// `Decimal` atomics
+	pub weight: u128,
+}
+impl From<WeightedVoteOption> for BorshableWeightedVoteOption {
+	fn from(value: WeightedVoteOption) -> Self {
+		Self {
+			option: value.option.into(),
+			weight: value.weight.atomics().u128(),
+		}
+	}
+}
+impl From<BorshableWeightedVoteOption> for WeightedVoteOption {
+	fn from(value: BorshableWeightedVoteOption) -> Self {
+		Self {
+			option: value.option.into(),
+			weight: Decimal::raw(value.weight),
+		}
+	}
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
 pub enum ProposedCourtMsg {
 	/// Sends a coin. Native or contract-driven
@@ -67,6 +125,21 @@ pub enum ProposedCourtMsg {
 	TokenfactoryMint {
 		tokens: BorshableCoin,
 	},
+	Stargate {
+		type_url: String,
+		value: Vec<u8>,
+	},
+	GovVote {
+		proposal_id: u64,
+		option: BorshableVoteOption,
+	},
+	GovVoteWeighted {
+		proposal_id: u64,
+		options: Vec<BorshableWeightedVoteOption>,
+	},
+	GovVoteByTally {
+		proposal_id: u64,
+	},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -104,7 +177,88 @@ pub enum ProposedCourtMsgJsonable {
 	TokenfactoryMint {
 		tokens: Coin,
 	},
+	/// A raw protobuf-encoded message, `type_url` must be in the stargate allowlist
+	Stargate {
+		type_url: String,
+		value: Binary,
+	},
+	/// Vote on a chain governance proposal
+	GovVote {
+		proposal_id: u64,
+		option: VoteOption,
+	},
+	/// Vote on a chain governance proposal with a split vote, the weights must add up to 1
+	GovVoteWeighted {
+		proposal_id: u64,
+		options: Vec<WeightedVoteOption>,
+	},
+	/// Vote on a chain governance proposal with a split vote proportional to the final tally of the court proposal
+	/// this message is in.
+	GovVoteByTally {
+		proposal_id: u64,
+	},
+}
+impl ProposedCourtMsg {
+	/// The name of this message's variant, as it appears in JSON
+	pub fn kind(&self) -> &'static str {
+		match self {
+			ProposedCourtMsg::SendCoin { .. } => "send_coin",
+			ProposedCourtMsg::ExecuteEvmContract { .. } => "execute_evm_contract",
+			ProposedCourtMsg::ExecuteWasmContract { .. } => "execute_wasm_contract",
+			ProposedCourtMsg::UpgradeWasmContract { .. } => "upgrade_wasm_contract",
+			ProposedCourtMsg::ChangeWasmContractAdmin { .. } => "change_wasm_contract_admin",
+			ProposedCourtMsg::ClearWasmContractAdmin { .. } => "clear_wasm_contract_admin",
+			ProposedCourtMsg::TokenfactoryMint { .. } => "tokenfactory_mint",
+			ProposedCourtMsg::Stargate { .. } => "stargate",
+			ProposedCourtMsg::GovVote { .. } => "gov_vote",
+			ProposedCourtMsg::GovVoteWeighted { .. } => "gov_vote_weighted",
+			ProposedCourtMsg::GovVoteByTally { .. } => "gov_vote_by_tally",
+		}
+	}
+	/// The contract this message interacts with, if any. Used for indexing proposals.
+	pub fn target_contract(&self) -> Option<SeiCanonicalAddr> {
+		match self {
+			ProposedCourtMsg::ExecuteEvmContract { contract, .. } => Some((*contract).into()),
+			ProposedCourtMsg::ExecuteWasmContract { contract, .. }
+			| ProposedCourtMsg::UpgradeWasmContract { contract, .. }
+			| ProposedCourtMsg::ChangeWasmContractAdmin { contract, .. }
+			| ProposedCourtMsg::ClearWasmContractAdmin { contract } => Some(contract.clone()),
+			_ => None,
+		}
+	}
+	/// The amount of `denom` this message sends or mints. Used for assigning proposals to tracks.
+	pub fn sent_amount(&self, denom: &FungibleAssetKind) -> u128 {
+		let is_native_denom = |native_denom: &str| {
+			FungibleAssetKind::try_from(FungibleAssetKindString::Native(native_denom.into()))
+				.is_ok_and(|native_denom_kind| native_denom_kind == *denom)
+		};
+		match self {
+			ProposedCourtMsg::SendCoin {
+				denom: msg_denom,
+				amount,
+				..
+			} if msg_denom == denom => *amount,
+			ProposedCourtMsg::ExecuteEvmContract { value, .. } if is_native_denom("usei") => *value,
+			ProposedCourtMsg::ExecuteWasmContract { funds, .. } => funds
+				.iter()
+				.filter(|coin| is_native_denom(&coin.denom))
+				.map(|coin| coin.amount)
+				.sum(),
+			ProposedCourtMsg::TokenfactoryMint { tokens } if is_native_denom(&tokens.denom) => tokens.amount,
+			_ => 0,
+		}
+	}
 }
+
+/// Parses a contract address which may either be a 0x address or a sei1 address.
+pub fn parse_contract_addr(addr: &str) -> Result<SeiCanonicalAddr, StdError> {
+	if addr.starts_with("0x") {
+		Ok(parse_ethereum_address(addr)?.into())
+	} else {
+		Addr::unchecked(addr).try_into()
+	}
+}
+
 impl ProposedCourtMsgJsonable {
 	/// Re-formats some stuff if applicable, this currently checksum-case-ifies 0x* addresses.
 	///
@@ -129,7 +283,259 @@ impl ProposedCourtMsgJsonable {
 		}
 		Ok(self)
 	}
+	/// Decodes the contents of this message into something which can be reviewed without a separate decoder.
+	///
+	/// Like `make_pretty`, this may require a significant amount of gas, so it's only used for smart queries.
+	pub fn decode(&self) -> ProposedCourtMsgDecoded {
+		match self {
+			ProposedCourtMsgJsonable::SendCoin { denom, amount, .. } => ProposedCourtMsgDecoded {
+				msg_json: None,
+				amounts: match denom {
+					FungibleAssetKindString::Native(denom) => vec![display_amount(denom, *amount)],
+					_ => vec![],
+				},
+			},
+			ProposedCourtMsgJsonable::ExecuteEvmContract { value, .. } => ProposedCourtMsgDecoded {
+				msg_json: None,
+				amounts: if value.is_zero() {
+					vec![]
+				} else {
+					// EVM values are always in asei
+					Decimal::from_atomics(*value, 18)
+						.map(|value| vec![format!("{value} sei")])
+						.unwrap_or_else(|_| vec![format!("{value} asei")])
+				},
+			},
+			ProposedCourtMsgJsonable::ExecuteWasmContract { msg, funds, .. } => ProposedCourtMsgDecoded {
+				msg_json: from_json(msg).ok(),
+				amounts: funds
+					.iter()
+					.map(|coin| display_amount(&coin.denom, coin.amount))
+					.collect(),
+			},
+			ProposedCourtMsgJsonable::UpgradeWasmContract { msg, .. } => ProposedCourtMsgDecoded {
+				msg_json: from_json(msg).ok(),
+				amounts: vec![],
+			},
+			ProposedCourtMsgJsonable::TokenfactoryMint { tokens } => ProposedCourtMsgDecoded {
+				msg_json: None,
+				amounts: vec![display_amount(&tokens.denom, tokens.amount)],
+			},
+			ProposedCourtMsgJsonable::Stargate { value, .. } => ProposedCourtMsgDecoded {
+				msg_json: if value.len() <= MAX_DECODED_PROTOBUF_LEN {
+					decode_raw_protobuf(value.as_slice(), 0)
+				} else {
+					None
+				},
+				amounts: vec![],
+			},
+			ProposedCourtMsgJsonable::GovVote { .. }
+			| ProposedCourtMsgJsonable::GovVoteWeighted { .. }
+			| ProposedCourtMsgJsonable::GovVoteByTally { .. } => ProposedCourtMsgDecoded {
+				msg_json: self.variant_fields(),
+				amounts: vec![],
+			},
+			_ => ProposedCourtMsgDecoded::default(),
+		}
+	}
+	/// The fields of this message's variant as a JSON object
+	fn variant_fields(&self) -> Option<ProposedCourtMsgJsonValue> {
+		match from_json(to_json_vec(self).ok()?).ok()? {
+			ProposedCourtMsgJsonValue::Object(mut variants) if variants.len() == 1 => {
+				variants.pop().map(|(_, fields)| fields)
+			}
+			_ => None,
+		}
+	}
+}
+
+/// Human-readable details of a proposed message, see [ProposedCourtMsgJsonable::decode]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct ProposedCourtMsgDecoded {
+	/// The payload of the message as a JSON value, if it has one which could be decoded. This is the `msg` of Wasm
+	/// messages, the fields of stargate messages keyed by their protobuf field number, or the vote of governance
+	/// messages.
+	pub msg_json: Option<ProposedCourtMsgJsonValue>,
+	/// The native coins this message sends or mints, amounts of usei are shown in sei
+	pub amounts: Vec<String>,
 }
+
+/// An arbitrary JSON value. Unlike `serde_json::Value`, numbers can only be integers, as floating point operations
+/// aren't allowed in contracts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProposedCourtMsgJsonValue {
+	Null,
+	Bool(bool),
+	Int(i64),
+	UInt(u64),
+	String(String),
+	Array(Vec<ProposedCourtMsgJsonValue>),
+	/// Fields are kept in the order they appeared in
+	Object(Vec<(String, ProposedCourtMsgJsonValue)>),
+}
+impl Serialize for ProposedCourtMsgJsonValue {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self {
+			ProposedCourtMsgJsonValue::Null => serializer.serialize_unit(),
+			ProposedCourtMsgJsonValue::Bool(value) => serializer.serialize_bool(*value),
+			ProposedCourtMsgJsonValue::Int(value) => serializer.serialize_i64(*value),
+			ProposedCourtMsgJsonValue::UInt(value) => serializer.serialize_u64(*value),
+			ProposedCourtMsgJsonValue::String(value) => serializer.serialize_str(value),
+			ProposedCourtMsgJsonValue::Array(values) => {
+				let mut seq = serializer.serialize_seq(Some(values.len()))?;
+				for value in values {
+					seq.serialize_element(value)?;
+				}
+				seq.end()
+			}
+			ProposedCourtMsgJsonValue::Object(fields) => {
+				let mut map = serializer.serialize_map(Some(fields.len()))?;
+				for (key, value) in fields {
+					map.serialize_entry(key, value)?;
+				}
+				map.end()
+			}
+		}
+	}
+}
+impl<'de> Deserialize<'de> for ProposedCourtMsgJsonValue {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct JsonValueVisitor;
+		impl<'de> Visitor<'de> for JsonValueVisitor {
+			type Value = ProposedCourtMsgJsonValue;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+				formatter.write_str("a JSON value without fractional numbers")
+			}
+			fn visit_unit<E>(self) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::Null)
+			}
+			fn visit_none<E>(self) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::Null)
+			}
+			fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::Bool(value))
+			}
+			fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::Int(value))
+			}
+			fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::UInt(value))
+			}
+			fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::String(value.into()))
+			}
+			fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+				Ok(ProposedCourtMsgJsonValue::String(value))
+			}
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut values = Vec::new();
+				while let Some(value) = seq.next_element()? {
+					values.push(value);
+				}
+				Ok(ProposedCourtMsgJsonValue::Array(values))
+			}
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+				let mut fields = Vec::new();
+				while let Some(field) = map.next_entry()? {
+					fields.push(field);
+				}
+				Ok(ProposedCourtMsgJsonValue::Object(fields))
+			}
+		}
+		deserializer.deserialize_any(JsonValueVisitor)
+	}
+}
+impl JsonSchema for ProposedCourtMsgJsonValue {
+	fn schema_name() -> String {
+		"ProposedCourtMsgJsonValue".into()
+	}
+	fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+		// Any JSON value
+		schemars::schema::Schema::Bool(true)
+	}
+}
+
+/// Stargate messages larger than this aren't decoded
+const MAX_DECODED_PROTOBUF_LEN: usize = 2048;
+/// Messages nested deeper than this are shown as base64
+const MAX_DECODED_PROTOBUF_DEPTH: u8 = 4;
+
+/// Decodes a protobuf message without knowing its schema, like `protoc --decode_raw`. Fields are keyed by their field
+/// number and repeated fields become arrays. Length-delimited fields are shown as text if they're printable, as a
+/// nested message if they can be decoded as one within [MAX_DECODED_PROTOBUF_DEPTH], and as base64 otherwise.
+fn decode_raw_protobuf(mut bytes: &[u8], depth: u8) -> Option<ProposedCourtMsgJsonValue> {
+	let mut fields: Vec<(String, ProposedCourtMsgJsonValue)> = Vec::new();
+	while !bytes.is_empty() {
+		let tag = read_protobuf_varint(&mut bytes)?;
+		if tag >> 3 == 0 {
+			return None;
+		}
+		let value = match tag & 7 {
+			0 => ProposedCourtMsgJsonValue::UInt(read_protobuf_varint(&mut bytes)?),
+			1 => ProposedCourtMsgJsonValue::UInt(u64::from_le_bytes(take_bytes(&mut bytes, 8)?.try_into().ok()?)),
+			2 => {
+				let len = usize::try_from(read_protobuf_varint(&mut bytes)?).ok()?;
+				let value = take_bytes(&mut bytes, len)?;
+				match std::str::from_utf8(value) {
+					Ok(text) if !text.chars().any(char::is_control) => ProposedCourtMsgJsonValue::String(text.into()),
+					_ => (depth < MAX_DECODED_PROTOBUF_DEPTH)
+						.then(|| decode_raw_protobuf(value, depth + 1))
+						.flatten()
+						.unwrap_or_else(|| ProposedCourtMsgJsonValue::String(Binary::from(value).to_base64())),
+				}
+			}
+			5 => {
+				ProposedCourtMsgJsonValue::UInt(u32::from_le_bytes(take_bytes(&mut bytes, 4)?.try_into().ok()?).into())
+			}
+			// Groups are deprecated and unused by the chain
+			_ => return None,
+		};
+		let key = (tag >> 3).to_string();
+		match fields.iter_mut().find(|(field_key, _)| *field_key == key) {
+			Some((_, ProposedCourtMsgJsonValue::Array(values))) => values.push(value),
+			Some((_, existing_value)) => {
+				let first_value = std::mem::replace(existing_value, ProposedCourtMsgJsonValue::Null);
+				*existing_value = ProposedCourtMsgJsonValue::Array(vec![first_value, value]);
+			}
+			None => fields.push((key, value)),
+		}
+	}
+	Some(ProposedCourtMsgJsonValue::Object(fields))
+}
+
+fn read_protobuf_varint(bytes: &mut &[u8]) -> Option<u64> {
+	let mut result = 0u64;
+	for shift in (0..64).step_by(7) {
+		let (byte, rest) = bytes.split_first()?;
+		*bytes = rest;
+		result |= u64::from(byte & 0x7f) << shift;
+		if byte & 0x80 == 0 {
+			return Some(result);
+		}
+	}
+	None
+}
+
+fn take_bytes<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+	if bytes.len() < len {
+		return None;
+	}
+	let (taken, rest) = bytes.split_at(len);
+	*bytes = rest;
+	Some(taken)
+}
+
+fn display_amount(denom: &str, amount: Uint128) -> String {
+	// Metadata queries need a newer CosmWasm version than the chain supports, so only sei's own exponent is known
+	if denom == "usei" {
+		if let Ok(amount) = Decimal::from_atomics(amount, 6) {
+			return format!("{amount} sei");
+		}
+	}
+	format!("{amount} {denom}")
+}
+
 impl TryFrom<ProposedCourtMsg> for CosmosMsg<SeiMsg> {
 	type Error = StdError;
 
@@ -176,6 +582,23 @@ impl TryFrom<ProposedCourtMsg> for CosmosMsg<SeiMsg> {
 			}
 			.into()),
 			ProposedCourtMsg::TokenfactoryMint { tokens } => Ok(SeiMsg::MintTokens { amount: tokens.into() }.into()),
+			ProposedCourtMsg::Stargate { type_url, value } => Ok(CosmosMsg::Stargate {
+				type_url,
+				value: value.into(),
+			}),
+			ProposedCourtMsg::GovVote { proposal_id, option } => Ok(GovMsg::Vote {
+				proposal_id,
+				vote: option.into(),
+			}
+			.into()),
+			ProposedCourtMsg::GovVoteWeighted { proposal_id, options } => Ok(GovMsg::VoteWeighted {
+				proposal_id,
+				options: options.into_iter().map(|v| v.into()).collect(),
+			}
+			.into()),
+			ProposedCourtMsg::GovVoteByTally { .. } => Err(StdError::generic_err(
+				"GovVoteByTally can only be converted with the tally of the proposal it's in",
+			)),
 		}
 	}
 }
@@ -227,6 +650,21 @@ impl TryFrom<ProposedCourtMsg> for ProposedCourtMsgJsonable {
 			ProposedCourtMsg::TokenfactoryMint { tokens } => {
 				ProposedCourtMsgJsonable::TokenfactoryMint { tokens: tokens.into() }
 			}
+			ProposedCourtMsg::Stargate { type_url, value } => ProposedCourtMsgJsonable::Stargate {
+				type_url,
+				value: value.into(),
+			},
+			ProposedCourtMsg::GovVote { proposal_id, option } => ProposedCourtMsgJsonable::GovVote {
+				proposal_id,
+				option: option.into(),
+			},
+			ProposedCourtMsg::GovVoteWeighted { proposal_id, options } => ProposedCourtMsgJsonable::GovVoteWeighted {
+				proposal_id,
+				options: options.into_iter().map(|v| v.into()).collect(),
+			},
+			ProposedCourtMsg::GovVoteByTally { proposal_id } => {
+				ProposedCourtMsgJsonable::GovVoteByTally { proposal_id }
+			}
 		})
 	}
 }
@@ -279,6 +717,21 @@ impl TryFrom<ProposedCourtMsgJsonable> for ProposedCourtMsg {
 			ProposedCourtMsgJsonable::TokenfactoryMint { tokens } => {
 				ProposedCourtMsg::TokenfactoryMint { tokens: tokens.into() }
 			}
+			ProposedCourtMsgJsonable::Stargate { type_url, value } => ProposedCourtMsg::Stargate {
+				type_url,
+				value: value.0,
+			},
+			ProposedCourtMsgJsonable::GovVote { proposal_id, option } => ProposedCourtMsg::GovVote {
+				proposal_id,
+				option: option.into(),
+			},
+			ProposedCourtMsgJsonable::GovVoteWeighted { proposal_id, options } => ProposedCourtMsg::GovVoteWeighted {
+				proposal_id,
+				options: options.into_iter().map(|v| v.into()).collect(),
+			},
+			ProposedCourtMsgJsonable::GovVoteByTally { proposal_id } => {
+				ProposedCourtMsg::GovVoteByTally { proposal_id }
+			}
 		})
 	}
 }
@@ -1,15 +1,31 @@
-use crate::{error::CourtContractError, proposed_msg::ProposedCourtMsg};
+use crate::{
+	error::CourtContractError,
+	proposed_msg::{parse_contract_addr, ProposedCourtMsg},
+	state::user::CourtUserVoteInfo,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use cosmwasm_schema::schemars::{self, JsonSchema};
-use cosmwasm_std::{Addr, StdError, Uint128};
+use cosmwasm_std::{Addr, Decimal, HexBinary, StdError, Uint128, VoteOption, WeightedVoteOption};
 use crownfi_cw_common::{
-	data_types::canonical_addr::SeiCanonicalAddr,
+	data_types::{
+		asset::{FungibleAssetKind, FungibleAssetKindString},
+		canonical_addr::SeiCanonicalAddr,
+	},
 	impl_serializable_as_ref,
-	storage::{item::StoredItem, vec::StoredVec, OZeroCopy, SerializableItem},
+	storage::{
+		item::StoredItem,
+		map::{StoredMap, StoredMapKeyIter},
+		set::StoredSet,
+		vec::StoredVec,
+		OZeroCopy, SerializableItem,
+	},
 };
 use serde::{Deserialize, Serialize};
 
 pub const CONFIG_NAMESPACE: &str = "app_config";
+/// The most voting power locked votes can have, in percent. This keeps vote counts from overflowing.
+pub const MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT: u16 = 1000;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
 #[repr(C)]
@@ -20,8 +36,26 @@ pub struct CourtAppConfig {
 	pub minimum_vote_pass_percent: u8,
 	pub max_proposal_expiry_time_seconds: u32,
 	pub execution_expiry_time_seconds: u32,
-	_unused: [u8; 4],
+	pub veto_threshold_percent: u8,
+	pub optimistic_opposition_threshold_percent: u8,
+	pub expedited_minimum_vote_turnout_percent: u8,
+	pub expedited_minimum_vote_pass_percent: u8,
+	pub expedited_voting_period_seconds: u32,
+	pub min_proposal_expiry_time_seconds: u32,
+	pub quiet_ending_window_seconds: u32,
+	pub quiet_ending_extension_seconds: u32,
+	pub max_quiet_ending_extensions: u8,
+	_unused: [u8; 3],
+	pub min_stake_age_seconds: u32,
+	pub max_stake_lock_seconds: u32,
+	pub max_stake_lock_vote_multiplier_percent: u16,
+	quadratic_voting: u8, // bool
+	quorum_supply: u8,    // CourtQuorumSupply
+	pub max_user_votes: u128,
 	pub last_config_change_timestamp_ms: u64,
+	pub max_user_votes_bps: u16,
+	_unused_2: [u8; 2],
+	pub reveal_period_seconds: u32,
 	pub admin: SeiCanonicalAddr,
 }
 
@@ -31,8 +65,60 @@ pub struct CourtAppConfigJsonable {
 	pub minimum_vote_proposal_percent: u8,
 	pub minimum_vote_turnout_percent: u8,
 	pub minimum_vote_pass_percent: u8,
+	/// Proposals are vetoed if more than this percentage of votes cast are vetoes, 0 disables vetoing.
+	pub veto_threshold_percent: u8,
+	/// Optimistic proposals are rejected if the votes opposing or vetoing them are more than this percentage of the
+	/// vote supply.
+	pub optimistic_opposition_threshold_percent: u8,
+	/// Expedited proposals pass once `expedited_voting_period_seconds` have passed if this percentage of the vote
+	/// supply has voted...
+	pub expedited_minimum_vote_turnout_percent: u8,
+	/// ...and this percentage of the votes cast approve. These should be higher than the normal requirements.
+	pub expedited_minimum_vote_pass_percent: u8,
+	/// 0 disables expedited proposals.
+	pub expedited_voting_period_seconds: u32,
+	pub min_proposal_expiry_time_seconds: u32,
 	pub max_proposal_expiry_time_seconds: u32,
 	pub execution_expiry_time_seconds: u32,
+	/// If a vote cast within this many seconds of a proposal's expiry changes its outcome...
+	pub quiet_ending_window_seconds: u32,
+	/// ...voting on it is extended by this many seconds...
+	pub quiet_ending_extension_seconds: u32,
+	/// ...up to this many times. 0 disables extensions.
+	pub max_quiet_ending_extensions: u8,
+	/// Newly staked votes can't be voted or proposed with until they've been staked for this long, so that votes can't
+	/// be bought to sway a proposal within a single block. 0 makes them usable immediately.
+	pub min_stake_age_seconds: u32,
+	/// The longest users may lock their staked votes for, 0 disables locking.
+	pub max_stake_lock_seconds: u32,
+	/// How much voting power votes locked for `max_stake_lock_seconds` have, e.g. 400 is 4x. Votes which remain locked
+	/// for less time have proportionally less. This is capped at [MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT].
+	///
+	/// Proposals only pass before voting ends if they would still pass with this many times the vote supply voting,
+	/// so a higher multiplier makes early passes rarer. Changing it also changes the voting power of votes which are
+	/// already locked.
+	pub max_stake_lock_vote_multiplier_percent: u16,
+	/// If enabled, users vote and propose with the square root of their voting power, and turnout is measured against
+	/// the square root of the staked votes, so `quorum_supply` must be `staked`. Votes split between many addresses
+	/// count for more than if they were held by one, so this relies on each voter only using one address. As there's no
+	/// bound on how many votes could still be cast, proposals can't pass before voting ends unless they're expedited.
+	#[serde(default)]
+	pub quadratic_voting: bool,
+	/// Which votes turnout is measured against, this must be `staked` if quadratic voting is enabled.
+	#[serde(default)]
+	pub quorum_supply: CourtQuorumSupply,
+	/// The most votes a single user may vote or propose with, any more are ignored. 0 disables this cap.
+	#[serde(default)]
+	pub max_user_votes: Uint128,
+	/// Like `max_user_votes`, but in basis points of the vote supply which turnout is measured against. 0 disables this
+	/// cap. If both are set, the lower one applies. Like the votes they cap, both are square roots if quadratic voting
+	/// is enabled.
+	#[serde(default)]
+	pub max_user_votes_bps: u16,
+	/// How long votes committed to private proposals may be revealed for once voting on them ends. 0 disables private
+	/// proposals.
+	#[serde(default)]
+	pub reveal_period_seconds: u32,
 	pub last_config_change_timestamp_ms: u64,
 	pub admin: Addr,
 }
@@ -49,6 +135,93 @@ impl CourtAppConfig {
 	pub fn set_allow_new_proposals(&mut self, value: bool) {
 		self.allow_new_proposals = value as u8;
 	}
+	pub fn quadratic_voting(&self) -> bool {
+		self.quadratic_voting != 0
+	}
+	pub fn set_quadratic_voting(&mut self, value: bool) {
+		self.quadratic_voting = value as u8;
+	}
+	pub fn quorum_supply(&self) -> CourtQuorumSupply {
+		self.quorum_supply.into()
+	}
+	pub fn set_quorum_supply(&mut self, value: CourtQuorumSupply) {
+		self.quorum_supply = value.into();
+	}
+	/// How much `votes` count for, this is their square root if quadratic voting is enabled.
+	///
+	/// This applies both to the votes of individual users, and to the vote supply which turnout is measured against.
+	pub fn vote_weight(&self, votes: u128) -> u128 {
+		if self.quadratic_voting() {
+			votes.isqrt()
+		} else {
+			votes
+		}
+	}
+	/// Limits the weighted votes of a single user to `max_user_votes` and `max_user_votes_bps` of `quorum_supply`
+	pub fn capped_user_votes(&self, votes: u128, quorum_supply: u128) -> u128 {
+		let mut votes = votes;
+		if self.max_user_votes != 0 {
+			votes = votes.min(self.max_user_votes);
+		}
+		if self.max_user_votes_bps != 0 {
+			// The Mint function makes sure that permyriad calculations on the supply can't overflow
+			votes = votes.min(quorum_supply * self.max_user_votes_bps.min(10000) as u128 / 10000);
+		}
+		votes
+	}
+	/// Makes sure that percentages and basis points are within range, that proposals can have a valid expiry time, and
+	/// that quadratic voting measures turnout against the staked votes
+	pub fn validate(&self) -> Result<(), CourtContractError> {
+		for (proprety_name, value, max) in [
+			(
+				"minimum_vote_proposal_percent",
+				self.minimum_vote_proposal_percent.into(),
+				100,
+			),
+			(
+				"minimum_vote_turnout_percent",
+				self.minimum_vote_turnout_percent.into(),
+				100,
+			),
+			("minimum_vote_pass_percent", self.minimum_vote_pass_percent.into(), 100),
+			("veto_threshold_percent", self.veto_threshold_percent.into(), 100),
+			(
+				"optimistic_opposition_threshold_percent",
+				self.optimistic_opposition_threshold_percent.into(),
+				100,
+			),
+			(
+				"expedited_minimum_vote_turnout_percent",
+				self.expedited_minimum_vote_turnout_percent.into(),
+				100,
+			),
+			(
+				"expedited_minimum_vote_pass_percent",
+				self.expedited_minimum_vote_pass_percent.into(),
+				100,
+			),
+			("max_user_votes_bps", self.max_user_votes_bps.into(), 10000),
+			(
+				"max_stake_lock_vote_multiplier_percent",
+				self.max_stake_lock_vote_multiplier_percent.into(),
+				MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT.into(),
+			),
+		] {
+			if value > max {
+				return Err(CourtContractError::ConfigValueTooHigh {
+					proprety_name: proprety_name.into(),
+					max,
+				});
+			}
+		}
+		if self.min_proposal_expiry_time_seconds > self.max_proposal_expiry_time_seconds {
+			return Err(CourtContractError::InvalidProposalExpiryTimeRange);
+		}
+		if self.quadratic_voting() && self.quorum_supply() != CourtQuorumSupply::Staked {
+			return Err(CourtContractError::QuadraticVotingRequiresStakedQuorum);
+		}
+		Ok(())
+	}
 	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
 	where
 		Self: Sized,
@@ -69,8 +242,25 @@ impl TryFrom<&CourtAppConfigJsonable> for CourtAppConfig {
 			minimum_vote_proposal_percent: value.minimum_vote_proposal_percent,
 			minimum_vote_turnout_percent: value.minimum_vote_turnout_percent,
 			minimum_vote_pass_percent: value.minimum_vote_pass_percent,
+			veto_threshold_percent: value.veto_threshold_percent,
+			optimistic_opposition_threshold_percent: value.optimistic_opposition_threshold_percent,
+			expedited_minimum_vote_turnout_percent: value.expedited_minimum_vote_turnout_percent,
+			expedited_minimum_vote_pass_percent: value.expedited_minimum_vote_pass_percent,
+			expedited_voting_period_seconds: value.expedited_voting_period_seconds,
+			min_proposal_expiry_time_seconds: value.min_proposal_expiry_time_seconds,
 			max_proposal_expiry_time_seconds: value.max_proposal_expiry_time_seconds,
 			execution_expiry_time_seconds: value.execution_expiry_time_seconds,
+			quiet_ending_window_seconds: value.quiet_ending_window_seconds,
+			quiet_ending_extension_seconds: value.quiet_ending_extension_seconds,
+			max_quiet_ending_extensions: value.max_quiet_ending_extensions,
+			min_stake_age_seconds: value.min_stake_age_seconds,
+			max_stake_lock_seconds: value.max_stake_lock_seconds,
+			max_stake_lock_vote_multiplier_percent: value.max_stake_lock_vote_multiplier_percent,
+			quadratic_voting: value.quadratic_voting as u8,
+			quorum_supply: value.quorum_supply.into(),
+			max_user_votes: value.max_user_votes.u128(),
+			max_user_votes_bps: value.max_user_votes_bps,
+			reveal_period_seconds: value.reveal_period_seconds,
 			last_config_change_timestamp_ms: value.last_config_change_timestamp_ms,
 			admin: (&value.admin).try_into()?,
 			..Zeroable::zeroed()
@@ -85,41 +275,370 @@ impl TryFrom<&CourtAppConfig> for CourtAppConfigJsonable {
 			minimum_vote_proposal_percent: value.minimum_vote_proposal_percent,
 			minimum_vote_turnout_percent: value.minimum_vote_turnout_percent,
 			minimum_vote_pass_percent: value.minimum_vote_pass_percent,
+			veto_threshold_percent: value.veto_threshold_percent,
+			optimistic_opposition_threshold_percent: value.optimistic_opposition_threshold_percent,
+			expedited_minimum_vote_turnout_percent: value.expedited_minimum_vote_turnout_percent,
+			expedited_minimum_vote_pass_percent: value.expedited_minimum_vote_pass_percent,
+			expedited_voting_period_seconds: value.expedited_voting_period_seconds,
+			min_proposal_expiry_time_seconds: value.min_proposal_expiry_time_seconds,
 			max_proposal_expiry_time_seconds: value.max_proposal_expiry_time_seconds,
 			execution_expiry_time_seconds: value.execution_expiry_time_seconds,
+			quiet_ending_window_seconds: value.quiet_ending_window_seconds,
+			quiet_ending_extension_seconds: value.quiet_ending_extension_seconds,
+			max_quiet_ending_extensions: value.max_quiet_ending_extensions,
+			min_stake_age_seconds: value.min_stake_age_seconds,
+			max_stake_lock_seconds: value.max_stake_lock_seconds,
+			max_stake_lock_vote_multiplier_percent: value.max_stake_lock_vote_multiplier_percent,
+			quadratic_voting: value.quadratic_voting(),
+			quorum_supply: value.quorum_supply(),
+			max_user_votes: value.max_user_votes.into(),
+			max_user_votes_bps: value.max_user_votes_bps,
+			reveal_period_seconds: value.reveal_period_seconds,
 			last_config_change_timestamp_ms: value.last_config_change_timestamp_ms,
 			admin: value.admin.try_into()?,
 		})
 	}
 }
 
+/// Which votes proposal turnout is measured against
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[repr(u8)]
+#[serde(rename_all = "snake_case")]
+pub enum CourtQuorumSupply {
+	/// All votes which exist
+	#[default]
+	Total = 0,
+	/// Only the votes which users have staked, including ones which are still pending. Locked votes count for more when
+	/// voting but not here, so turnout may be more than 100%.
+	Staked = 1,
+	/// All votes except the ones held by this contract which users haven't staked, such as the ones in its treasury
+	Circulating = 2,
+}
+impl From<u8> for CourtQuorumSupply {
+	fn from(value: u8) -> Self {
+		match value {
+			1 => Self::Staked,
+			2 => Self::Circulating,
+			_ => Self::Total,
+		}
+	}
+}
+impl From<CourtQuorumSupply> for u8 {
+	fn from(value: CourtQuorumSupply) -> Self {
+		value as u8
+	}
+}
+
+const STARGATE_ALLOWLIST_NAMESPACE: &str = "app_sg_allow";
+
+/// The `type_url`s which `ProposedCourtMsg::Stargate` messages are allowed to use, empty by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct CourtStargateAllowlist {
+	pub type_urls: Vec<String>,
+}
+impl StoredItem for CourtStargateAllowlist {
+	fn namespace() -> &'static [u8] {
+		STARGATE_ALLOWLIST_NAMESPACE.as_bytes()
+	}
+}
+impl CourtStargateAllowlist {
+	pub fn load_or_default() -> Result<Self, StdError> {
+		Ok(Self::load()?.unwrap_or_default().into_inner())
+	}
+	pub fn is_allowed(&self, type_url: &str) -> bool {
+		self.type_urls.iter().any(|allowed_type_url| allowed_type_url == type_url)
+	}
+	pub fn enforce_allowed(&self, type_url: &str) -> Result<(), CourtContractError> {
+		if self.is_allowed(type_url) {
+			Ok(())
+		} else {
+			Err(CourtContractError::StargateMessageNotAllowed(type_url.into()))
+		}
+	}
+}
+
+const OPTIMISTIC_ALLOWLIST_NAMESPACE: &str = "app_opt_allow";
+
+/// Who may make optimistic proposals, and which kinds of messages (see [ProposedCourtMsg::kind]) optimistic proposals
+/// may contain when made by anyone else. Both are empty by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct CourtOptimisticAllowlist {
+	pub proposers: Vec<SeiCanonicalAddr>,
+	pub msg_kinds: Vec<String>,
+}
+impl StoredItem for CourtOptimisticAllowlist {
+	fn namespace() -> &'static [u8] {
+		OPTIMISTIC_ALLOWLIST_NAMESPACE.as_bytes()
+	}
+}
+impl CourtOptimisticAllowlist {
+	pub fn load_or_default() -> Result<Self, StdError> {
+		Ok(Self::load()?.unwrap_or_default().into_inner())
+	}
+	pub fn is_allowed(&self, proposer: &SeiCanonicalAddr, msgs: &[ProposedCourtMsg]) -> bool {
+		self.proposers.contains(proposer)
+			|| msgs
+				.iter()
+				.all(|msg| self.msg_kinds.iter().any(|msg_kind| msg_kind == msg.kind()))
+	}
+	pub fn enforce_allowed(
+		&self,
+		proposer: &SeiCanonicalAddr,
+		msgs: &[ProposedCourtMsg],
+	) -> Result<(), CourtContractError> {
+		if self.is_allowed(proposer, msgs) {
+			Ok(())
+		} else {
+			Err(CourtContractError::OptimisticProposalNotAllowed)
+		}
+	}
+}
+
+const PROPOSAL_TRACKS_NAMESPACE: &str = "app_tracks";
+
+/// Voting rules for proposals containing certain messages, so that e.g. upgrading a contract can require more votes
+/// than a small payment. These replace the corresponding options of the voting config.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct CourtProposalTrack {
+	pub name: String,
+	pub minimum_vote_proposal_percent: u8,
+	pub minimum_vote_turnout_percent: u8,
+	pub minimum_vote_pass_percent: u8,
+	pub max_proposal_expiry_time_seconds: u32,
+	pub execution_expiry_time_seconds: u32,
+	/// Proposals can't be executed until this long after voting ends, even if they pass early. The execution expiry
+	/// time only starts counting down once this has passed.
+	pub timelock_seconds: u32,
+	/// Messages must be one of these kinds (see [ProposedCourtMsg::kind]) to match, empty matches any kind.
+	pub msg_kinds: Vec<String>,
+	/// Messages must target one of these contracts (see [ProposedCourtMsg::target_contract]) to match, empty matches
+	/// any message.
+	pub targets: Vec<SeiCanonicalAddr>,
+	/// Messages must send at least this amount of the asset (see [ProposedCourtMsg::sent_amount]) to match.
+	pub min_amount: Option<(FungibleAssetKind, u128)>,
+}
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtProposalTrackJsonable {
+	pub name: String,
+	pub minimum_vote_proposal_percent: u8,
+	pub minimum_vote_turnout_percent: u8,
+	pub minimum_vote_pass_percent: u8,
+	pub max_proposal_expiry_time_seconds: u32,
+	pub execution_expiry_time_seconds: u32,
+	/// Proposals can't be executed until this long after voting ends, even if they pass early. The execution expiry
+	/// time only starts counting down once this has passed.
+	#[serde(default)]
+	pub timelock_seconds: u32,
+	/// Messages must be one of these kinds (e.g. "send_coin" or "upgrade_wasm_contract") to match, empty matches any
+	/// kind.
+	#[serde(default)]
+	pub msg_kinds: Vec<String>,
+	/// Messages must target one of these contracts to match, empty matches any message. EVM contracts may be specified
+	/// with their 0x address, but are listed with the equivalent sei1 address.
+	#[serde(default)]
+	pub targets: Vec<String>,
+	/// Messages must send or mint at least this amount of the asset to match.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub min_amount: Option<CourtProposalTrackAmount>,
+}
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CourtProposalTrackAmount {
+	pub denom: FungibleAssetKindString,
+	pub amount: Uint128,
+}
+impl CourtProposalTrack {
+	/// Checks if a proposal containing `msg` belongs to this track.
+	pub fn matches(&self, msg: &ProposedCourtMsg) -> bool {
+		(self.msg_kinds.is_empty() || self.msg_kinds.iter().any(|msg_kind| msg_kind == msg.kind()))
+			&& (self.targets.is_empty()
+				|| msg
+					.target_contract()
+					.is_some_and(|target| self.targets.contains(&target)))
+			&& !self
+				.min_amount
+				.as_ref()
+				.is_some_and(|(denom, min_amount)| msg.sent_amount(denom) < *min_amount)
+	}
+}
+impl TryFrom<CourtProposalTrackJsonable> for CourtProposalTrack {
+	type Error = StdError;
+	fn try_from(value: CourtProposalTrackJsonable) -> Result<Self, Self::Error> {
+		Ok(Self {
+			name: value.name,
+			minimum_vote_proposal_percent: value.minimum_vote_proposal_percent,
+			minimum_vote_turnout_percent: value.minimum_vote_turnout_percent,
+			minimum_vote_pass_percent: value.minimum_vote_pass_percent,
+			max_proposal_expiry_time_seconds: value.max_proposal_expiry_time_seconds,
+			execution_expiry_time_seconds: value.execution_expiry_time_seconds,
+			timelock_seconds: value.timelock_seconds,
+			msg_kinds: value.msg_kinds,
+			targets: value
+				.targets
+				.iter()
+				.map(|target| parse_contract_addr(target))
+				.collect::<Result<_, _>>()?,
+			min_amount: value
+				.min_amount
+				.map(|min_amount| -> Result<_, StdError> {
+					Ok((min_amount.denom.try_into()?, min_amount.amount.u128()))
+				})
+				.transpose()?,
+		})
+	}
+}
+impl TryFrom<CourtProposalTrack> for CourtProposalTrackJsonable {
+	type Error = StdError;
+	fn try_from(value: CourtProposalTrack) -> Result<Self, Self::Error> {
+		Ok(Self {
+			name: value.name,
+			minimum_vote_proposal_percent: value.minimum_vote_proposal_percent,
+			minimum_vote_turnout_percent: value.minimum_vote_turnout_percent,
+			minimum_vote_pass_percent: value.minimum_vote_pass_percent,
+			max_proposal_expiry_time_seconds: value.max_proposal_expiry_time_seconds,
+			execution_expiry_time_seconds: value.execution_expiry_time_seconds,
+			timelock_seconds: value.timelock_seconds,
+			msg_kinds: value.msg_kinds,
+			targets: value
+				.targets
+				.into_iter()
+				.map(|target| Ok(Addr::try_from(target)?.into_string()))
+				.collect::<Result<_, StdError>>()?,
+			min_amount: value
+				.min_amount
+				.map(|(denom, amount)| -> Result<_, StdError> {
+					Ok(CourtProposalTrackAmount {
+						denom: denom.try_into()?,
+						amount: amount.into(),
+					})
+				})
+				.transpose()?,
+		})
+	}
+}
+
+/// Proposals are assigned to the first of these tracks which any of their messages match, proposals which don't match
+/// any use the voting config as-is. Empty by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct CourtProposalTracks {
+	pub tracks: Vec<CourtProposalTrack>,
+}
+impl StoredItem for CourtProposalTracks {
+	fn namespace() -> &'static [u8] {
+		PROPOSAL_TRACKS_NAMESPACE.as_bytes()
+	}
+}
+impl CourtProposalTracks {
+	pub fn load_or_default() -> Result<Self, StdError> {
+		Ok(Self::load()?.unwrap_or_default().into_inner())
+	}
+	/// The index of the track a proposal with these messages belongs to, if any.
+	pub fn classify<'a>(&self, msgs: impl Iterator<Item = &'a ProposedCourtMsg> + Clone) -> Option<u8> {
+		self.tracks
+			.iter()
+			.position(|track| msgs.clone().any(|msg| track.matches(msg)))
+			.map(|index| index as u8)
+	}
+	pub fn get(&self, track: Option<u8>) -> Option<&CourtProposalTrack> {
+		track.and_then(|track| self.tracks.get(track as usize))
+	}
+	/// The voting config with the options of the specified track applied, proposals must be evaluated with this.
+	///
+	/// The execution expiry time includes the track's timelock, as it's counted from when voting ends.
+	pub fn app_config_for(&self, track: Option<u8>, app_config: &CourtAppConfig) -> CourtAppConfig {
+		let mut track_app_config = *app_config;
+		if let Some(track) = self.get(track) {
+			track_app_config.minimum_vote_proposal_percent = track.minimum_vote_proposal_percent;
+			track_app_config.minimum_vote_turnout_percent = track.minimum_vote_turnout_percent;
+			track_app_config.minimum_vote_pass_percent = track.minimum_vote_pass_percent;
+			track_app_config.max_proposal_expiry_time_seconds = track.max_proposal_expiry_time_seconds;
+			track_app_config.execution_expiry_time_seconds = track
+				.execution_expiry_time_seconds
+				.saturating_add(track.timelock_seconds);
+		}
+		track_app_config
+	}
+	/// When a passed proposal in the specified track which stops being voted on at `expiry_timestamp_ms` may be
+	/// executed, `None` if it may be executed as soon as it passes.
+	pub fn timelock_expiry_timestamp_ms(&self, track: Option<u8>, expiry_timestamp_ms: u64) -> Option<u64> {
+		self.get(track)
+			.filter(|track| track.timelock_seconds != 0)
+			.map(|track| expiry_timestamp_ms.saturating_add(track.timelock_seconds.saturating_mul(1000).into()))
+	}
+}
+
 const PROPOSAL_INFO_NAMESPACE: &str = "app_prop_i";
 const PROPOSAL_MSG_NAMESPACE: &str = "app_prop_m";
+const PROPOSAL_OPTIONS_NAMESPACE: &str = "app_prop_o";
+const PROPOSAL_OPTION_VOTES_NAMESPACE: &str = "app_prop_ov";
+const PROPOSAL_METADATA_NAMESPACE: &str = "app_prop_md";
+const PROPOSAL_BY_PROPOSER_NAMESPACE: &str = "app_prop_p";
+const PROPOSAL_BY_TARGET_NAMESPACE: &str = "app_prop_t";
+const PROPOSAL_UNFINALIZED_NAMESPACE: &str = "app_prop_uf";
 
 /// Transaction proposal status, this is derived from the actual proposal struct rather than as a property.
 ///
-/// The way this is derived is documented below.
+/// The way this is derived is documented below. `quorum_supply` is the vote supply which turnout is measured against as
+/// of when the proposal was made, see [quorum_supply](crate::contract::shares::quorum_supply). As locked votes count
+/// for more, `max_total_votes` is `quorum_supply` multiplied by `max_stake_lock_vote_multiplier_percent`, and
+/// `uncast_votes` is the difference between it and `total_votes`. With quadratic voting, neither of these are bounded, so proposals can only pass before they
+/// expire if they're expedited.
 /// ```rust,ignore
 /// let proposal_status = if transaction_executed_status == TransactionExecutionStatus::Executed {
-/// 	TransactionProposalStatus::Executed
+/// 	if is_signal {
+/// 		TransactionProposalStatus::Passed
+/// 	} else {
+/// 		TransactionProposalStatus::Executed
+/// 	}
 /// } else if transaction_executed_status == TransactionExecutionStatus::Expired {
 /// 	TransactionProposalStatus::ExecutionExpired
 /// } else if expiry < last_config_change_time {
 /// 	TransactionProposalStatus::Rejected
+/// } else if current_time < expiry && is_private {
+/// 	TransactionProposalStatus::Pending
+/// } else if is_private && current_time < reveal_expiry {
+/// 	TransactionProposalStatus::Revealing
+/// } else if current_time < expiry && is_optimistic {
+/// 	if !((votes_against + votes_veto + uncast_votes) * 100 > optimistic_opposition_threshold_percent * quorum_supply) {
+/// 		TransactionProposalStatus::Passed
+/// 	} else {
+/// 		TransactionProposalStatus::Pending
+/// 	}
 /// } else if current_time < expiry {
 /// 	if
-/// 		((votes_for + votes_against) * 100 / token_supply) >= minimum_vote_turnout_percent ||
-/// 		(votes_for * 100 / token_supply) >= minimum_vote_pass_percent
+/// 		((votes_for + votes_against) * 100 / quorum_supply) >= minimum_vote_turnout_percent ||
+/// 		(votes_for * 100 / max_total_votes) >= minimum_vote_pass_percent &&
+/// 		!(the proposal would be vetoed if all remaining votes were vetoes) &&
+/// 		!(is_multiple_choice && another option could still win if it got all the remaining votes)
+/// 	{
+/// 		TransactionProposalStatus::Passed
+/// 	} else if
+/// 		is_expedited && current_time >= expedited_expiry &&
+/// 		(total_votes * 100 / quorum_supply) >= expedited_minimum_vote_turnout_percent &&
+/// 		(votes_for * 100 / (votes_for + votes_against + votes_veto)) >= expedited_minimum_vote_pass_percent &&
+/// 		!(veto_threshold_percent != 0 && votes_veto * 100 > veto_threshold_percent * total_votes)
 /// 	{
 /// 		TransactionProposalStatus::Passed
 /// 	} else {
 /// 		TransactionProposalStatus::Pending
 /// 	}
-/// } else if current_time >= expiry && (
-/// 	((votes_for + votes_against) * 100 / token_supply) < minimum_vote_turnout_percent ||
-/// 	(votes_for * 100 / (votes_for + votes_against)) < minimum_vote_pass_percent
-/// ) {
+/// } else if is_optimistic {
+/// 	if (votes_against + votes_veto) * 100 > optimistic_opposition_threshold_percent * quorum_supply {
+/// 		TransactionProposalStatus::Rejected
+/// 	} else if current_time > expiry + execution_expiry_time {
+/// 		TransactionProposalStatus::ExecutionExpired
+/// 	} else {
+/// 		TransactionProposalStatus::Passed
+/// 	}
+/// } else if ((votes_for + votes_against) * 100 / quorum_supply) < minimum_vote_turnout_percent {
 /// 	TransactionProposalStatus::Rejected
+/// } else if is_multiple_choice && (no option has more votes than every other option) {
+/// 	TransactionProposalStatus::Rejected
+/// } else if veto_threshold_percent != 0 && votes_veto * 100 > veto_threshold_percent * total_votes {
+/// 	TransactionProposalStatus::Vetoed
+/// } else if (votes_for * 100 / (votes_for + votes_against + votes_veto)) < minimum_vote_pass_percent {
+/// 	TransactionProposalStatus::Rejected
+/// } else if !is_signal && current_time > max(expiry, reveal_expiry) + execution_expiry_time {
+/// 	TransactionProposalStatus::ExecutionExpired
 /// } else {
 /// 	TransactionProposalStatus::Passed
 /// }
@@ -141,6 +660,10 @@ pub enum TransactionProposalStatus {
 	ExecutionExpired = 4,
 	/// Either "Rejected" or "ExecutionExpired", but cannot tell due to a change in the voting config
 	RejectedOrExpired = 5,
+	/// Too many of the votes cast were vetoes
+	Vetoed = 6,
+	/// Voting on a private proposal has ended, and the votes committed to it are being revealed
+	Revealing = 7,
 }
 // SAFTY: TransactionProposalStatus::Pending is explicitly defined as 0
 unsafe impl Zeroable for TransactionProposalStatus {}
@@ -151,7 +674,8 @@ impl TransactionProposalStatus {
 			TransactionProposalStatus::Rejected
 			| TransactionProposalStatus::Executed
 			| TransactionProposalStatus::ExecutionExpired
-			| TransactionProposalStatus::RejectedOrExpired => true,
+			| TransactionProposalStatus::RejectedOrExpired
+			| TransactionProposalStatus::Vetoed => true,
 			_ => false,
 		}
 	}
@@ -175,6 +699,8 @@ impl std::fmt::Display for TransactionProposalStatus {
 			TransactionProposalStatus::Executed => f.write_str("executed"),
 			TransactionProposalStatus::ExecutionExpired => f.write_str("execution_expired"),
 			TransactionProposalStatus::RejectedOrExpired => f.write_str("rejected_or_expired"),
+			TransactionProposalStatus::Vetoed => f.write_str("vetoed"),
+			TransactionProposalStatus::Revealing => f.write_str("revealing"),
 		}
 	}
 }
@@ -187,6 +713,9 @@ pub enum TransactionProposalExecutionStatus {
 	/// Proposal has not been executed
 	NotExecuted = 0,
 	/// Proposal has been been executed
+	///
+	/// Signalling proposals are never executed, for them this means that they passed and the outcome has been recorded
+	/// so that it can still be known after the voting config changes.
 	Executed = 1,
 }
 // SAFTY: TransactionProposalStatus::Pending is explicitly defined as 0
@@ -221,9 +750,22 @@ pub struct TransactionProposalInfo {
 	pub votes_for: u128,
 	pub votes_against: u128,
 	pub votes_abstain: u128,
+	pub votes_veto: u128,
+	/// The vote supply which turnout is measured against as of when this proposal was made, so that staking,
+	/// unstaking, or moving votes afterwards can't change its outcome. See
+	/// [quorum_supply](crate::contract::shares::quorum_supply).
+	pub quorum_supply: u128,
 	execution_status: u8, // bool
-	_unused: [u8; 7],
+	option_count: u8,
+	leading_option: u8, // 0 is none, otherwise the option index + 1
+	signal: u8,         // bool
+	optimistic: u8,     // bool
+	track: u8,          // 0 is none, otherwise the track index + 1
+	extensions: u8,
+	_unused: u8,
+	reveal_expiry_timestamp_ms: u64, // 0 if not private
 	pub expiry_timestamp_ms: u64,
+	expedited_expiry_timestamp_ms: u64, // 0 if not expedited
 }
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct TransactionProposalInfoJsonable {
@@ -231,9 +773,42 @@ pub struct TransactionProposalInfoJsonable {
 	pub votes_for: Uint128,
 	pub votes_against: Uint128,
 	pub votes_abstain: Uint128,
+	pub votes_veto: Uint128,
+	/// The vote supply which turnout is measured against, as of when this proposal was made. This is its square root if
+	/// quadratic voting is enabled.
+	#[serde(default)]
+	pub quorum_supply: Uint128,
 	pub execution_status: TransactionProposalExecutionStatus,
+	/// The amount of options a multiple-choice proposal has, 0 for regular proposals.
+	///
+	/// For multiple-choice proposals, `votes_for` are the votes of the leading option, and `votes_against` are the
+	/// votes of all the other options.
+	#[serde(default)]
+	pub option_count: u8,
+	/// The option of a multiple-choice proposal which has the most votes, null if there's a tie.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub leading_option: Option<u8>,
+	/// Signalling proposals have no messages and are never executed, they only record the opinion of the voters.
+	#[serde(default)]
+	pub is_signal: bool,
+	/// Optimistic proposals pass once voting ends unless enough votes oppose or veto them, regardless of turnout.
+	#[serde(default)]
+	pub is_optimistic: bool,
+	/// The index of the proposal track this proposal belongs to, null if it uses the voting config as-is.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub track: Option<u8>,
+	/// How many times voting on this proposal has been extended due to late votes changing its outcome.
+	#[serde(default)]
+	pub extensions: u8,
 	// Serializing numbers as strings is cringe. It's a unix timestamp, it'll fit in 2**53.
 	pub expiry_timestamp_ms: u64,
+	/// When an expedited proposal may pass early if it meets the higher expedited requirements, null if this isn't an
+	/// expedited proposal.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expedited_expiry_timestamp_ms: Option<u64>,
+	/// When the votes committed to a private proposal can no longer be revealed, null if this isn't a private proposal.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub reveal_expiry_timestamp_ms: Option<u64>,
 }
 
 impl TransactionProposalInfo {
@@ -245,60 +820,448 @@ impl TransactionProposalInfo {
 			..Zeroable::zeroed()
 		}
 	}
+	/// The proposer's votes are counted as abstaining until they choose an option.
+	pub fn new_multiple_choice(
+		proposer: SeiCanonicalAddr,
+		proposer_votes: u128,
+		expiry_timestamp_ms: u64,
+		option_count: u8,
+	) -> Self {
+		Self {
+			proposer,
+			votes_abstain: proposer_votes,
+			option_count,
+			expiry_timestamp_ms,
+			..Zeroable::zeroed()
+		}
+	}
+	pub fn new_signal(proposer: SeiCanonicalAddr, proposer_votes: u128, expiry_timestamp_ms: u64) -> Self {
+		Self {
+			signal: 1,
+			..Self::new(proposer, proposer_votes, expiry_timestamp_ms)
+		}
+	}
+	/// The proposer's votes are counted as approving, even though they don't matter unless someone else opposes it.
+	pub fn new_optimistic(proposer: SeiCanonicalAddr, proposer_votes: u128, expiry_timestamp_ms: u64) -> Self {
+		Self {
+			optimistic: 1,
+			..Self::new(proposer, proposer_votes, expiry_timestamp_ms)
+		}
+	}
+	/// Expedited proposals may pass at `expedited_expiry_timestamp_ms` if they meet the expedited requirements,
+	/// otherwise they're treated like any other proposal.
+	pub fn new_expedited(
+		proposer: SeiCanonicalAddr,
+		proposer_votes: u128,
+		expiry_timestamp_ms: u64,
+		expedited_expiry_timestamp_ms: u64,
+	) -> Self {
+		Self {
+			expedited_expiry_timestamp_ms: expedited_expiry_timestamp_ms.min(expiry_timestamp_ms),
+			..Self::new(proposer, proposer_votes, expiry_timestamp_ms)
+		}
+	}
+	/// Votes on private proposals are committed until `expiry_timestamp_ms`, and then revealed until
+	/// `reveal_expiry_timestamp_ms`. Only the revealed votes are counted, including the proposer's.
+	pub fn new_private(proposer: SeiCanonicalAddr, expiry_timestamp_ms: u64, reveal_expiry_timestamp_ms: u64) -> Self {
+		Self {
+			reveal_expiry_timestamp_ms: reveal_expiry_timestamp_ms.max(expiry_timestamp_ms),
+			..Self::new(proposer, 0, expiry_timestamp_ms)
+		}
+	}
 	pub fn execution_status(&self) -> TransactionProposalExecutionStatus {
 		self.execution_status.into()
 	}
 	pub fn set_execution_status(&mut self, value: TransactionProposalExecutionStatus) {
 		self.execution_status = value.into()
 	}
-	pub fn status(
-		&self,
+	pub fn is_signal(&self) -> bool {
+		self.signal != 0
+	}
+	pub fn is_optimistic(&self) -> bool {
+		self.optimistic != 0
+	}
+	pub fn is_expedited(&self) -> bool {
+		self.expedited_expiry_timestamp_ms != 0
+	}
+	/// When this proposal may pass early if it meets the expedited requirements, `None` if it isn't expedited.
+	pub fn expedited_expiry_timestamp_ms(&self) -> Option<u64> {
+		self.is_expedited().then_some(self.expedited_expiry_timestamp_ms)
+	}
+	pub fn is_private(&self) -> bool {
+		self.reveal_expiry_timestamp_ms != 0
+	}
+	/// When committed votes can no longer be revealed, `None` if this isn't a private proposal.
+	pub fn reveal_expiry_timestamp_ms(&self) -> Option<u64> {
+		self.is_private().then_some(self.reveal_expiry_timestamp_ms)
+	}
+	/// When the votes stop changing, which is once they can no longer be revealed if this is a private proposal.
+	pub fn voting_end_timestamp_ms(&self) -> u64 {
+		self.expiry_timestamp_ms.max(self.reveal_expiry_timestamp_ms)
+	}
+	/// The index of the proposal track this proposal belongs to, see [CourtProposalTracks::app_config_for]
+	pub fn track(&self) -> Option<u8> {
+		self.track.checked_sub(1)
+	}
+	pub fn set_track(&mut self, track: Option<u8>) {
+		self.track = track.map(|track| track + 1).unwrap_or_default();
+	}
+	/// How many times voting on this proposal has been extended, see [TransactionProposalInfo::extend_if_outcome_changed]
+	pub fn extensions(&self) -> u8 {
+		self.extensions
+	}
+	/// If a vote cast at `current_timestamp_ms` changed the outcome this proposal would have if voting ended now from
+	/// what `old_proposal` would have had, and it was cast within the configured quiet ending window, the expiry is
+	/// extended so that others have the chance to respond. Returns whether the expiry was extended.
+	pub fn extend_if_outcome_changed(
+		&mut self,
+		old_proposal: &TransactionProposalInfo,
 		current_timestamp_ms: u64,
-		token_supply: u128,
 		app_config: &CourtAppConfig,
-	) -> TransactionProposalStatus {
+	) -> bool {
+		if self.extensions >= app_config.max_quiet_ending_extensions
+			|| current_timestamp_ms
+				< self
+					.expiry_timestamp_ms
+					.saturating_sub(app_config.quiet_ending_window_seconds.saturating_mul(1000).into())
+		{
+			return false;
+		}
+		let outcome = |proposal: &TransactionProposalInfo| {
+			(
+				proposal.status(proposal.expiry_timestamp_ms, app_config),
+				proposal.leading_option(),
+			)
+		};
+		if outcome(old_proposal) == outcome(self) {
+			return false;
+		}
+		self.extensions += 1;
+		self.expiry_timestamp_ms = self
+			.expiry_timestamp_ms
+			.saturating_add(app_config.quiet_ending_extension_seconds.saturating_mul(1000).into());
+		true
+	}
+	/// Like [TransactionProposalStatus::is_finalized], except that signalling proposals are also finalized once they've
+	/// passed, as there's nothing left to execute.
+	pub fn is_finalized(&self, status: TransactionProposalStatus) -> bool {
+		status.is_finalized() || (self.is_signal() && status == TransactionProposalStatus::Passed)
+	}
+	/// 0 if this isn't a multiple-choice proposal
+	pub fn option_count(&self) -> u8 {
+		self.option_count
+	}
+	pub fn is_multiple_choice(&self) -> bool {
+		self.option_count != 0
+	}
+	/// The option of a multiple-choice proposal which has the most votes, `None` if there's a tie.
+	pub fn leading_option(&self) -> Option<u8> {
+		self.leading_option.checked_sub(1)
+	}
+	/// Updates `votes_for`, `votes_against`, and the leading option of a multiple-choice proposal from the votes each
+	/// option has.
+	pub fn set_option_votes(&mut self, option_votes: &[u128]) {
+		let mut leading_option = None;
+		let mut leading_option_votes = 0;
+		let mut total_option_votes = 0;
+		for (option, votes) in option_votes.iter().copied().enumerate() {
+			total_option_votes += votes;
+			if votes > leading_option_votes {
+				leading_option = Some(option as u8);
+				leading_option_votes = votes;
+			} else if votes == leading_option_votes {
+				leading_option = None;
+			}
+		}
+		self.votes_for = leading_option_votes;
+		self.votes_against = total_option_votes - leading_option_votes;
+		self.leading_option = leading_option.map(|option| option + 1).unwrap_or_default();
+	}
+	pub fn total_votes(&self) -> u128 {
+		self.votes_for + self.votes_against + self.votes_abstain + self.votes_veto
+	}
+	/// Checks if more than `veto_threshold_percent` of the votes cast are vetoes
+	pub fn is_vetoed(&self, app_config: &CourtAppConfig) -> bool {
+		app_config.veto_threshold_percent != 0
+			&& self.votes_veto * 100 > app_config.veto_threshold_percent as u128 * self.total_votes()
+	}
+	/// The most votes this proposal could have once everyone has voted. Locked votes count for up to
+	/// `max_stake_lock_vote_multiplier_percent` of their amount, so this may be more than `quorum_supply`. The multiplier
+	/// is derived from the config when voting, which can't change while proposals are being voted on, so no votes can
+	/// have counted for more.
+	///
+	/// `None` if quadratic voting is enabled, as the square roots of votes split between many users add up to more than
+	/// the square root of the supply.
+	fn max_total_votes(&self, app_config: &CourtAppConfig) -> Option<u128> {
+		if app_config.quadratic_voting() {
+			return None;
+		}
+		let max_vote_multiplier_percent = app_config
+			.max_stake_lock_vote_multiplier_percent
+			.clamp(100, MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT);
+		Some((self.quorum_supply * max_vote_multiplier_percent as u128 / 100).max(self.total_votes()))
+	}
+	/// The most votes which could still be cast on this proposal, see [Self::max_total_votes]
+	fn max_uncast_votes(&self, app_config: &CourtAppConfig) -> Option<u128> {
+		self.max_total_votes(app_config)
+			.map(|max_total_votes| max_total_votes - self.total_votes())
+	}
+	/// Checks if this proposal would be vetoed if all the votes which haven't been cast yet were vetoes
+	fn may_be_vetoed(&self, app_config: &CourtAppConfig) -> bool {
+		// Multiple-choice proposals cannot be vetoed
+		!self.is_multiple_choice()
+			&& app_config.veto_threshold_percent != 0
+			&& self.max_uncast_votes(app_config).is_none_or(|uncast_votes| {
+				(self.votes_veto + uncast_votes) * 100
+					> app_config.veto_threshold_percent as u128 * (self.total_votes() + uncast_votes)
+			})
+	}
+	/// Checks if `votes_opposing` would be enough to reject this proposal if it were an optimistic one
+	fn is_optimistically_opposed(&self, votes_opposing: u128, app_config: &CourtAppConfig) -> bool {
+		votes_opposing * 100 > app_config.optimistic_opposition_threshold_percent as u128 * self.quorum_supply
+	}
+	/// Checks if this expedited proposal meets the higher expedited requirements, which only matter once
+	/// `expedited_expiry_timestamp_ms` has passed
+	fn meets_expedited_requirements(&self, app_config: &CourtAppConfig) -> bool {
+		let votes_cast = self.votes_for + self.votes_against + self.votes_veto;
+		self.total_votes() * 100 >= app_config.expedited_minimum_vote_turnout_percent as u128 * self.quorum_supply
+			&& votes_cast != 0
+			&& self.votes_for * 100 >= app_config.expedited_minimum_vote_pass_percent as u128 * votes_cast
+			&& !self.is_vetoed(app_config)
+	}
+	/// Checks if another option of a multiple-choice proposal could at least tie with the leading option if it received
+	/// all the votes which haven't been cast yet
+	fn may_be_overtaken(&self, app_config: &CourtAppConfig) -> bool {
+		self.is_multiple_choice()
+			&& self
+				.max_uncast_votes(app_config)
+				.is_none_or(|uncast_votes| self.votes_for <= self.votes_against + uncast_votes)
+	}
+	pub fn status(&self, current_timestamp_ms: u64, app_config: &CourtAppConfig) -> TransactionProposalStatus {
 		// OVERFLOW SAFETY:
 		// The Mint function doesn't allow a total supply greater than 34028236692093846346337460743176821.
-		// Therefore multiplying by up to 10000 will not overflow. (If we want to use bps some day)
-		// Proposals cannot be created by the contract unless the token supply is non-zero.
-		// By definition, the total votes cannot exceed the total number existing votes.
-		let total_turnout_percent =
-			u8::try_from((self.votes_for + self.votes_against + self.votes_abstain) * 100 / token_supply).unwrap();
+		// Locked votes have at most MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT / 100 times the voting power, so the total
+		// votes cannot exceed 10 times the total number of existing votes. Therefore multiplying by up to 1000 will
+		// not overflow.
+		// The quorum supply recorded when proposals are created is never 0.
+		// Thanks to locked votes, the turnout may be more than 100%.
+		let total_turnout_percent = self.total_votes() * 100 / self.quorum_supply;
 		if let Some(status) = self.execution_status().as_proposal_status() {
-			status
+			if self.is_signal() {
+				TransactionProposalStatus::Passed
+			} else {
+				status
+			}
 		} else if self.expiry_timestamp_ms < app_config.last_config_change_timestamp_ms {
 			// Note: last_config_change_timestamp_ms cannot be incremented while there are any non-finalized proposals
 			TransactionProposalStatus::RejectedOrExpired
+		} else if current_timestamp_ms < self.expiry_timestamp_ms && self.is_private() {
+			// The votes cast so far are hidden, so this can't pass early
+			TransactionProposalStatus::Pending
+		} else if current_timestamp_ms < self.reveal_expiry_timestamp_ms {
+			// Only private proposals have a reveal expiry
+			TransactionProposalStatus::Revealing
+		} else if current_timestamp_ms < self.expiry_timestamp_ms && self.is_optimistic() {
+			if self.max_uncast_votes(app_config).is_none_or(|uncast_votes| {
+				self.is_optimistically_opposed(self.votes_against + self.votes_veto + uncast_votes, app_config)
+			}) {
+				TransactionProposalStatus::Pending
+			} else {
+				// Even if everyone else opposed it, this proposal would still pass.
+				TransactionProposalStatus::Passed
+			}
 		} else if current_timestamp_ms < self.expiry_timestamp_ms {
-			let total_vote_for_percent_of_supply = u8::try_from(self.votes_for * 100 / token_supply).unwrap();
-			if total_vote_for_percent_of_supply >= app_config.minimum_vote_pass_percent
-				&& total_turnout_percent >= app_config.minimum_vote_turnout_percent
+			if self.max_total_votes(app_config).is_some_and(|max_total_votes| {
+				self.votes_for * 100 / max_total_votes >= app_config.minimum_vote_pass_percent as u128
+			}) && total_turnout_percent >= app_config.minimum_vote_turnout_percent as u128
+				&& !self.may_be_vetoed(app_config)
+				&& !self.may_be_overtaken(app_config)
 			{
 				// At this point, this proposal can't be rejected, (unless new votes are minted or people change their
 				// votes) so we might as well allow the transaction to be executed early to save everyone time.
 				TransactionProposalStatus::Passed
+			} else if self
+				.expedited_expiry_timestamp_ms()
+				.is_some_and(|expedited_expiry_timestamp_ms| current_timestamp_ms >= expedited_expiry_timestamp_ms)
+				&& self.meets_expedited_requirements(app_config)
+			{
+				// If it doesn't meet the expedited requirements, it's treated like any other proposal once voting ends.
+				TransactionProposalStatus::Passed
 			} else {
 				TransactionProposalStatus::Pending
 			}
-		} else if total_turnout_percent < app_config.minimum_vote_turnout_percent
-			|| u8::try_from(
-				(self.votes_for * 100)
-					.checked_div(self.votes_for + self.votes_against)
-					.unwrap_or_default(),
-			)
-			.unwrap() < app_config.minimum_vote_pass_percent
-		{
+		} else if self.is_optimistic() {
+			if self.is_optimistically_opposed(self.votes_against + self.votes_veto, app_config) {
+				TransactionProposalStatus::Rejected
+			} else if self.is_execution_expired(current_timestamp_ms, app_config) {
+				TransactionProposalStatus::ExecutionExpired
+			} else {
+				TransactionProposalStatus::Passed
+			}
+		} else if total_turnout_percent < app_config.minimum_vote_turnout_percent as u128 {
 			TransactionProposalStatus::Rejected
-		} else if current_timestamp_ms
-			> self
-				.expiry_timestamp_ms
-				.saturating_add(app_config.execution_expiry_time_seconds.saturating_mul(1000).into())
+		} else if self.is_multiple_choice() && self.leading_option().is_none() {
+			TransactionProposalStatus::Rejected
+		} else if self.is_vetoed(app_config) {
+			TransactionProposalStatus::Vetoed
+		} else if u8::try_from(
+			(self.votes_for * 100)
+				.checked_div(self.votes_for + self.votes_against + self.votes_veto)
+				.unwrap_or_default(),
+		)
+		.unwrap() < app_config.minimum_vote_pass_percent
 		{
+			TransactionProposalStatus::Rejected
+		} else if self.is_execution_expired(current_timestamp_ms, app_config) {
 			TransactionProposalStatus::ExecutionExpired
 		} else {
 			TransactionProposalStatus::Passed
 		}
 	}
+	/// Checks if the time to execute this proposal has passed, signalling proposals have nothing to execute.
+	fn is_execution_expired(&self, current_timestamp_ms: u64, app_config: &CourtAppConfig) -> bool {
+		!self.is_signal()
+			&& current_timestamp_ms
+				> self
+					.voting_end_timestamp_ms()
+					.saturating_add(app_config.execution_expiry_time_seconds.saturating_mul(1000).into())
+	}
+	pub fn add_user_votes(&mut self, vote_info: &CourtUserVoteInfo) {
+		let (approve_votes, oppose_votes, abstain_votes, veto_votes) = vote_info.vote_amounts();
+		self.votes_for = self.votes_for.checked_add(approve_votes).unwrap();
+		self.votes_against = self.votes_against.checked_add(oppose_votes).unwrap();
+		self.votes_abstain = self.votes_abstain.checked_add(abstain_votes).unwrap();
+		self.votes_veto = self.votes_veto.checked_add(veto_votes).unwrap();
+	}
+	pub fn remove_user_votes(&mut self, vote_info: &CourtUserVoteInfo) {
+		let (approve_votes, oppose_votes, abstain_votes, veto_votes) = vote_info.vote_amounts();
+		self.votes_for = self.votes_for.checked_sub(approve_votes).unwrap();
+		self.votes_against = self.votes_against.checked_sub(oppose_votes).unwrap();
+		self.votes_abstain = self.votes_abstain.checked_sub(abstain_votes).unwrap();
+		self.votes_veto = self.votes_veto.checked_sub(veto_votes).unwrap();
+	}
+	/// The additional approving votes required for this proposal to pass once voting ends, assuming no one else votes
+	/// or changes their vote. `None` if there aren't enough uncast votes for this, or if this is a multiple-choice
+	/// proposal.
+	pub fn votes_needed_to_pass(&self, app_config: &CourtAppConfig) -> Option<u128> {
+		if self.is_multiple_choice() {
+			return None;
+		}
+		if self.is_optimistic() {
+			// Approving votes don't help optimistic proposals, they pass unless they're opposed
+			return (!self.is_optimistically_opposed(self.votes_against + self.votes_veto, app_config)).then_some(0);
+		}
+		let total_votes = self.total_votes();
+		let votes_opposing = self.votes_against + self.votes_veto;
+		let pass_percent = app_config.minimum_vote_pass_percent as u128;
+		let veto_threshold_percent = app_config.veto_threshold_percent as u128;
+		// turnout: (total_votes + x) * 100 >= minimum_vote_turnout_percent * quorum_supply
+		let turnout_votes_needed = (app_config.minimum_vote_turnout_percent as u128 * self.quorum_supply)
+			.div_ceil(100)
+			.saturating_sub(total_votes);
+		// approval: (votes_for + x) * (100 - pass_percent) >= pass_percent * (votes_against + votes_veto)
+		let approval_votes_needed = if pass_percent == 0 {
+			0
+		} else if pass_percent >= 100 {
+			if votes_opposing > 0 {
+				return None;
+			}
+			1u128.saturating_sub(self.votes_for)
+		} else {
+			(pass_percent * votes_opposing)
+				.div_ceil(100 - pass_percent)
+				.max(1)
+				.saturating_sub(self.votes_for)
+		};
+		// veto: votes_veto * 100 <= veto_threshold_percent * (total_votes + x)
+		let veto_votes_needed = if veto_threshold_percent == 0 {
+			0
+		} else {
+			(self.votes_veto * 100)
+				.div_ceil(veto_threshold_percent)
+				.saturating_sub(total_votes)
+		};
+		let votes_needed = turnout_votes_needed.max(approval_votes_needed).max(veto_votes_needed);
+		self.max_uncast_votes(app_config)
+			.is_none_or(|uncast_votes| votes_needed <= uncast_votes)
+			.then_some(votes_needed)
+	}
+	/// The additional opposing or veto votes required to prevent this proposal from passing once voting ends, whichever
+	/// is fewer, assuming no one else votes or changes their vote. `None` if there aren't enough uncast votes for this,
+	/// or if this is a multiple-choice proposal.
+	pub fn votes_needed_to_block(&self, app_config: &CourtAppConfig) -> Option<u128> {
+		if self.is_multiple_choice() {
+			return None;
+		}
+		let total_votes = self.total_votes();
+		if self.votes_needed_to_pass(app_config) != Some(0) {
+			return Some(0);
+		}
+		if self.is_optimistic() {
+			// optimistic: (votes_against + votes_veto + y) * 100 > optimistic_opposition_threshold_percent * quorum_supply
+			let votes_needed = (app_config.optimistic_opposition_threshold_percent as u128 * self.quorum_supply / 100
+				+ 1)
+			.saturating_sub(self.votes_against + self.votes_veto);
+			return self
+				.max_uncast_votes(app_config)
+				.is_none_or(|uncast_votes| votes_needed <= uncast_votes)
+				.then_some(votes_needed);
+		}
+		let pass_percent = app_config.minimum_vote_pass_percent as u128;
+		let veto_threshold_percent = app_config.veto_threshold_percent as u128;
+		// approval: (votes_against + votes_veto + y) * pass_percent > votes_for * (100 - pass_percent)
+		// Opposing votes only increase turnout if pass_percent is 0
+		let opposing_votes_needed = (pass_percent != 0).then(|| {
+			(self.votes_for * (100 - pass_percent.min(100)) / pass_percent + 1)
+				.saturating_sub(self.votes_against + self.votes_veto)
+		});
+		// veto: (votes_veto + y) * 100 > veto_threshold_percent * (total_votes + y)
+		let veto_votes_needed = (veto_threshold_percent != 0 && veto_threshold_percent < 100).then(|| {
+			(veto_threshold_percent * total_votes).saturating_sub(self.votes_veto * 100)
+				/ (100 - veto_threshold_percent)
+				+ 1
+		});
+		let votes_needed = match (opposing_votes_needed, veto_votes_needed) {
+			(Some(opposing_votes_needed), Some(veto_votes_needed)) => opposing_votes_needed.min(veto_votes_needed),
+			(Some(votes_needed), None) | (None, Some(votes_needed)) => votes_needed,
+			(None, None) => return None,
+		};
+		self.max_uncast_votes(app_config)
+			.is_none_or(|uncast_votes| votes_needed <= uncast_votes)
+			.then_some(votes_needed)
+	}
+	/// Splits a chain governance vote proportionally to this proposal's tally. The weights always add up to 1, if
+	/// nobody has voted, the vote is an abstention.
+	pub fn tally_as_weighted_vote(&self) -> Vec<WeightedVoteOption> {
+		let total_votes = self.total_votes();
+		if total_votes == 0 {
+			return vec![WeightedVoteOption {
+				option: VoteOption::Abstain,
+				weight: Decimal::one(),
+			}];
+		}
+		let mut options = [
+			(VoteOption::Yes, self.votes_for),
+			(VoteOption::No, self.votes_against),
+			(VoteOption::Abstain, self.votes_abstain),
+			(VoteOption::NoWithVeto, self.votes_veto),
+		]
+		.into_iter()
+		.filter(|(_, votes)| *votes > 0)
+		.map(|(option, votes)| WeightedVoteOption {
+			option,
+			weight: Decimal::from_ratio(votes, total_votes),
+		})
+		.collect::<Vec<_>>();
+		// Rounding down may leave some weight unaccounted for, the chain requires the weights to add up to exactly 1
+		let rounded_total_weight = options
+			.iter()
+			.fold(Decimal::zero(), |total_weight, option| total_weight + option.weight);
+		if let Some(last_option) = options.last_mut() {
+			last_option.weight += Decimal::one() - rounded_total_weight;
+		}
+		options
+	}
 }
 impl_serializable_as_ref!(TransactionProposalInfo);
 impl TryFrom<&TransactionProposalInfoJsonable> for TransactionProposalInfo {
@@ -309,9 +1272,19 @@ impl TryFrom<&TransactionProposalInfoJsonable> for TransactionProposalInfo {
 			votes_for: value.votes_for.u128(),
 			votes_against: value.votes_against.u128(),
 			votes_abstain: value.votes_abstain.u128(),
+			votes_veto: value.votes_veto.u128(),
+			quorum_supply: value.quorum_supply.u128(),
 			execution_status: value.execution_status as u8,
+			option_count: value.option_count,
+			leading_option: value.leading_option.map(|option| option + 1).unwrap_or_default(),
+			signal: value.is_signal as u8,
+			optimistic: value.is_optimistic as u8,
+			track: value.track.map(|track| track + 1).unwrap_or_default(),
+			extensions: value.extensions,
 			_unused: Zeroable::zeroed(),
 			expiry_timestamp_ms: value.expiry_timestamp_ms,
+			expedited_expiry_timestamp_ms: value.expedited_expiry_timestamp_ms.unwrap_or_default(),
+			reveal_expiry_timestamp_ms: value.reveal_expiry_timestamp_ms.unwrap_or_default(),
 		})
 	}
 }
@@ -323,14 +1296,152 @@ impl TryFrom<&TransactionProposalInfo> for TransactionProposalInfoJsonable {
 			votes_for: value.votes_for.into(),
 			votes_against: value.votes_against.into(),
 			votes_abstain: value.votes_abstain.into(),
+			votes_veto: value.votes_veto.into(),
+			quorum_supply: value.quorum_supply.into(),
 			execution_status: value.execution_status(),
+			option_count: value.option_count,
+			leading_option: value.leading_option(),
+			is_signal: value.is_signal(),
+			is_optimistic: value.is_optimistic(),
+			track: value.track(),
+			extensions: value.extensions,
 			expiry_timestamp_ms: value.expiry_timestamp_ms,
+			expedited_expiry_timestamp_ms: value.expedited_expiry_timestamp_ms(),
+			reveal_expiry_timestamp_ms: value.reveal_expiry_timestamp_ms(),
 		})
 	}
 }
 pub fn get_transaction_proposal_info_vec() -> StoredVec<TransactionProposalInfo> {
 	StoredVec::new(PROPOSAL_INFO_NAMESPACE.as_ref())
 }
+/// For multiple-choice proposals, this is empty until the winning option is executed.
 pub fn get_transaction_proposal_messages_vec() -> StoredVec<Vec<ProposedCourtMsg>> {
 	StoredVec::new(PROPOSAL_MSG_NAMESPACE.as_ref())
 }
+
+/// One of the alternatives of a multiple-choice proposal
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct TransactionProposalOption {
+	pub label: String,
+	/// Once the winning option has been executed, its messages are moved to the proposal's messages and the messages
+	/// of every option are discarded.
+	pub messages: Vec<ProposedCourtMsg>,
+}
+pub fn get_transaction_proposal_options_store() -> StoredMap<u32, Vec<TransactionProposalOption>> {
+	StoredMap::new(PROPOSAL_OPTIONS_NAMESPACE.as_ref())
+}
+/// The votes each option of a multiple-choice proposal has received
+pub fn get_transaction_proposal_option_votes_store() -> StoredMap<u32, Vec<u128>> {
+	StoredMap::new(PROPOSAL_OPTION_VOTES_NAMESPACE.as_ref())
+}
+
+/// Describes what a proposal is about, this is required for signalling proposals as they have nothing else.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct TransactionProposalMetadata {
+	pub title: String,
+	pub description: String,
+	pub content_uri: Option<String>,
+	pub content_sha256: Option<[u8; 32]>,
+}
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct TransactionProposalMetadataJsonable {
+	pub title: String,
+	#[serde(default)]
+	pub description: String,
+	/// Where the full text of the proposal can be found, e.g. an ipfs:// or https:// URI
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub content_uri: Option<String>,
+	/// The SHA-256 hash of the content found at `content_uri`, so that it can be verified
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub content_sha256: Option<HexBinary>,
+}
+impl TryFrom<TransactionProposalMetadataJsonable> for TransactionProposalMetadata {
+	type Error = StdError;
+	fn try_from(value: TransactionProposalMetadataJsonable) -> Result<Self, Self::Error> {
+		Ok(Self {
+			title: value.title,
+			description: value.description,
+			content_uri: value.content_uri,
+			content_sha256: value
+				.content_sha256
+				.map(|content_sha256| content_sha256.to_array())
+				.transpose()?,
+		})
+	}
+}
+impl From<TransactionProposalMetadata> for TransactionProposalMetadataJsonable {
+	fn from(value: TransactionProposalMetadata) -> Self {
+		Self {
+			title: value.title,
+			description: value.description,
+			content_uri: value.content_uri,
+			content_sha256: value.content_sha256.map(HexBinary::from),
+		}
+	}
+}
+pub fn get_transaction_proposal_metadata_store() -> StoredMap<u32, TransactionProposalMetadata> {
+	StoredMap::new(PROPOSAL_METADATA_NAMESPACE.as_ref())
+}
+
+pub fn get_proposer_proposal_id_set() -> StoredSet<(SeiCanonicalAddr, u32)> {
+	StoredSet::new(PROPOSAL_BY_PROPOSER_NAMESPACE.as_ref())
+}
+/// Gets the IDs of all the proposals made by the specified user
+///
+/// `start` is inclusive while `end` is exclusive
+pub fn get_proposer_proposal_ids(
+	proposer: SeiCanonicalAddr,
+	start: Option<u32>,
+	end: Option<u32>,
+) -> Result<StoredMapKeyIter<u32>, StdError> {
+	StoredMapKeyIter::new(PROPOSAL_BY_PROPOSER_NAMESPACE.as_ref(), proposer, start, end)
+}
+
+pub fn get_target_proposal_id_set() -> StoredSet<(SeiCanonicalAddr, u32)> {
+	StoredSet::new(PROPOSAL_BY_TARGET_NAMESPACE.as_ref())
+}
+/// Gets the IDs of all the proposals which have messages targeting the specified contract, see
+/// [ProposedCourtMsg::target_contract]
+///
+/// `start` is inclusive while `end` is exclusive
+pub fn get_target_proposal_ids(
+	target: SeiCanonicalAddr,
+	start: Option<u32>,
+	end: Option<u32>,
+) -> Result<StoredMapKeyIter<u32>, StdError> {
+	StoredMapKeyIter::new(PROPOSAL_BY_TARGET_NAMESPACE.as_ref(), target, start, end)
+}
+
+/// Proposals which may still be `Pending` or `Passed`, signalling proposals which have passed are considered
+/// finalized.
+///
+/// Proposals become finalized as time passes, so this may contain finalized proposals which haven't been pruned yet.
+pub fn get_unfinalized_proposal_id_set() -> StoredSet<u32> {
+	StoredSet::new(PROPOSAL_UNFINALIZED_NAMESPACE.as_ref())
+}
+/// How many of the oldest unfinalized proposals are checked for removal from the index whenever proposals are
+/// created, executed, or have their votes deactivated
+pub const UNFINALIZED_PROPOSAL_PRUNE_LIMIT: usize = 8;
+/// Removes finalized proposals from the unfinalized proposal index, only the oldest `limit` entries are checked.
+pub fn prune_unfinalized_proposal_ids(
+	current_timestamp_ms: u64,
+	app_config: &CourtAppConfig,
+	limit: usize,
+) -> Result<(), StdError> {
+	let unfinalized_proposal_ids = get_unfinalized_proposal_id_set();
+	let proposal_infos = get_transaction_proposal_info_vec();
+	let proposal_tracks = CourtProposalTracks::load_or_default()?;
+	for proposal_id in unfinalized_proposal_ids.iter()?.take(limit).collect::<Vec<u32>>() {
+		let is_finalized = proposal_infos
+			.get(proposal_id)?
+			.map(|info| {
+				let app_config = proposal_tracks.app_config_for(info.track(), app_config);
+				info.is_finalized(info.status(current_timestamp_ms, &app_config))
+			})
+			.unwrap_or(true);
+		if is_finalized {
+			unfinalized_proposal_ids.remove(&proposal_id);
+		}
+	}
+	Ok(())
+}
@@ -1,5 +1,6 @@
 use core::fmt;
 
+use crate::state::app::{CourtAppConfig, MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT};
 use bytemuck::{Pod, Zeroable};
 use cosmwasm_schema::schemars::{self, JsonSchema};
 use cosmwasm_std::{StdError, Uint128};
@@ -19,11 +20,108 @@ const USER_VOTES_NAMESPACE: &str = "user_stats";
 #[derive(Debug, Clone, Copy, Default, Zeroable, Pod, PartialEq, Eq)]
 #[repr(C)]
 pub struct CourtUserStats {
+	/// The votes which may be used
 	pub staked_votes: u128,
+	/// Votes which were staked too recently to be used, see [CourtUserStats::activated]
+	pub pending_votes: u128,
+	pub pending_votes_timestamp_ms: u64,
+	/// The staked and pending votes can't be unstaked until this time
+	pub lock_expiry_timestamp_ms: u64,
+	_unused: [u8; 16],
 }
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq)]
 pub struct CourtUserStatsJsonable {
 	pub staked_votes: Uint128,
+	/// Votes which were staked too recently to vote or propose with.
+	#[serde(default)]
+	pub pending_votes: Uint128,
+	/// When votes were last staked while some were pending, the pending votes become usable once they've been staked
+	/// for the configured `min_stake_age_seconds` since then. 0 if there are no pending votes.
+	#[serde(default)]
+	pub pending_votes_timestamp_ms: u64,
+	/// The staked votes can't be unstaked until this time, 0 if they were never locked.
+	#[serde(default)]
+	pub lock_expiry_timestamp_ms: u64,
+	/// The voting power the staked votes currently have due to being locked, in percent. This decreases as
+	/// `lock_expiry_timestamp_ms` approaches, and is 0 if they aren't locked. Only present in query responses.
+	#[serde(default)]
+	pub vote_multiplier_percent: u16,
+	/// What the staked votes currently count for when voting or proposing, after applying the lock multiplier,
+	/// quadratic voting, and the per-user cap. Only present in query responses.
+	#[serde(default)]
+	pub effective_votes: Uint128,
+}
+impl CourtUserStats {
+	/// The staked and pending votes
+	pub fn total_votes(&self) -> u128 {
+		self.staked_votes + self.pending_votes
+	}
+	/// Returns these stats with the pending votes counted as staked if they've been staked for at least
+	/// `min_stake_age_seconds` as of `current_timestamp_ms`.
+	pub fn activated(mut self, current_timestamp_ms: u64, min_stake_age_seconds: u32) -> Self {
+		if self.pending_votes != 0
+			&& current_timestamp_ms
+				>= self
+					.pending_votes_timestamp_ms
+					.saturating_add(min_stake_age_seconds.saturating_mul(1000).into())
+		{
+			self.staked_votes = self.staked_votes.checked_add(self.pending_votes).unwrap();
+			self.pending_votes = 0;
+			self.pending_votes_timestamp_ms = 0;
+		}
+		self
+	}
+	/// Locks all the user's votes until `lock_seconds` from now, or until they were already locked until if that's
+	/// later.
+	pub fn lock(&mut self, lock_seconds: u32, current_timestamp_ms: u64) {
+		self.lock_expiry_timestamp_ms = self
+			.lock_expiry_timestamp_ms
+			.max(current_timestamp_ms.saturating_add(lock_seconds.saturating_mul(1000).into()));
+	}
+	/// The voting power the staked votes have as of `current_timestamp_ms`, in percent. This is derived from how long
+	/// they remain locked for, so it decreases as the lock expiry approaches and can't exceed what the config allows.
+	pub fn vote_multiplier_percent(&self, current_timestamp_ms: u64, app_config: &CourtAppConfig) -> u16 {
+		let max_lock_ms = u64::from(app_config.max_stake_lock_seconds) * 1000;
+		let lock_remaining_ms = self
+			.lock_expiry_timestamp_ms
+			.saturating_sub(current_timestamp_ms)
+			.min(max_lock_ms);
+		let max_multiplier_bonus_percent = u64::from(
+			app_config
+				.max_stake_lock_vote_multiplier_percent
+				.min(MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT)
+				.saturating_sub(100),
+		);
+		100 + (max_multiplier_bonus_percent * lock_remaining_ms)
+			.checked_div(max_lock_ms)
+			.unwrap_or_default() as u16
+	}
+	pub fn is_locked(&self, current_timestamp_ms: u64) -> bool {
+		current_timestamp_ms < self.lock_expiry_timestamp_ms
+	}
+	/// The staked votes, multiplied according to how long they remain locked for
+	pub fn voting_power(&self, current_timestamp_ms: u64, app_config: &CourtAppConfig) -> u128 {
+		self.staked_votes * self.vote_multiplier_percent(current_timestamp_ms, app_config) as u128 / 100
+	}
+	/// What the user's votes count for when voting or proposing, see [CourtAppConfig::vote_weight] and
+	/// [CourtAppConfig::capped_user_votes]
+	pub fn vote_weight(&self, current_timestamp_ms: u64, quorum_supply: u128, app_config: &CourtAppConfig) -> u128 {
+		app_config.capped_user_votes(
+			app_config.vote_weight(self.voting_power(current_timestamp_ms, app_config)),
+			quorum_supply,
+		)
+	}
+	/// Adds newly staked votes, which are pending until they've been staked for `min_stake_age_seconds`. Any votes
+	/// which were already pending have to wait for the newly staked ones.
+	pub fn add_votes(&mut self, amount: u128, current_timestamp_ms: u64, min_stake_age_seconds: u32) {
+		*self = self.activated(current_timestamp_ms, min_stake_age_seconds);
+		if min_stake_age_seconds == 0 {
+			self.staked_votes = self.staked_votes.checked_add(amount).unwrap();
+		} else {
+			self.pending_votes = self.pending_votes.checked_add(amount).unwrap();
+			self.pending_votes_timestamp_ms = current_timestamp_ms;
+		}
+	}
 }
 impl_serializable_as_ref!(CourtUserStats);
 impl StoredItem for CourtUserStats {
@@ -36,6 +134,10 @@ impl TryFrom<&CourtUserStatsJsonable> for CourtUserStats {
 	fn try_from(value: &CourtUserStatsJsonable) -> Result<Self, Self::Error> {
 		Ok(CourtUserStats {
 			staked_votes: value.staked_votes.into(),
+			pending_votes: value.pending_votes.into(),
+			pending_votes_timestamp_ms: value.pending_votes_timestamp_ms,
+			lock_expiry_timestamp_ms: value.lock_expiry_timestamp_ms,
+			_unused: Zeroable::zeroed(),
 		})
 	}
 }
@@ -44,6 +146,11 @@ impl TryFrom<&CourtUserStats> for CourtUserStatsJsonable {
 	fn try_from(value: &CourtUserStats) -> Result<Self, Self::Error> {
 		Ok(CourtUserStatsJsonable {
 			staked_votes: value.staked_votes.into(),
+			pending_votes: value.pending_votes.into(),
+			pending_votes_timestamp_ms: value.pending_votes_timestamp_ms,
+			lock_expiry_timestamp_ms: value.lock_expiry_timestamp_ms,
+			vote_multiplier_percent: 0,
+			effective_votes: Uint128::zero(),
 		})
 	}
 }
@@ -51,17 +158,58 @@ pub fn get_user_stats_store() -> StoredMap<SeiCanonicalAddr, CourtUserStats> {
 	StoredMap::new(USER_VOTES_NAMESPACE.as_ref())
 }
 
+const TOTAL_STAKED_VOTES_NAMESPACE: &str = "total_staked";
+/// The votes staked by all users, including pending ones
+#[derive(Debug, Clone, Copy, Default, Zeroable, Pod, PartialEq, Eq)]
+#[repr(C)]
+pub struct CourtTotalStakedVotes {
+	pub votes: u128,
+}
+impl_serializable_as_ref!(CourtTotalStakedVotes);
+impl StoredItem for CourtTotalStakedVotes {
+	fn namespace() -> &'static [u8] {
+		TOTAL_STAKED_VOTES_NAMESPACE.as_bytes()
+	}
+}
+impl CourtTotalStakedVotes {
+	pub fn load_or_default() -> Result<Self, StdError> {
+		Ok(Self::load()?.unwrap_or_default().into_inner())
+	}
+}
+
 #[derive(Debug, Clone, Copy, Default, Zeroable, Pod, PartialEq, Eq)]
 #[repr(C)]
 pub struct CourtUserVoteInfo {
 	pub active_votes: u128,
 	vote: u8,
-	_unused: [u8; 15],
+	choice: u8,
+	split_approve_bps: u16,
+	split_oppose_bps: u16,
+	_unused_2: [u8; 10],
 }
 #[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema, PartialEq, Eq)]
 pub struct CourtUserVoteInfoJsonable {
 	pub active_votes: Uint128,
 	pub vote: CourtUserVoteStatus,
+	/// Only present when `vote` is "split"
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub split: Option<CourtUserVoteSplit>,
+	/// Only present when `vote` is "choice"
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub choice: Option<u8>,
+}
+
+/// How a user's votes are split between the options, in basis points. These must add up to 10000.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, JsonSchema, PartialEq, Eq)]
+pub struct CourtUserVoteSplit {
+	pub approve_bps: u16,
+	pub oppose_bps: u16,
+	pub abstain_bps: u16,
+}
+impl CourtUserVoteSplit {
+	pub fn is_valid(&self) -> bool {
+		self.approve_bps as u32 + self.oppose_bps as u32 + self.abstain_bps as u32 == 10000
+	}
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Default, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -72,6 +220,12 @@ pub enum CourtUserVoteStatus {
 	Abstain = 0,
 	Approve = 1,
 	Oppose = 2,
+	/// Votes are split between the options, see [CourtUserVoteSplit]
+	Split = 3,
+	/// Counts as opposing, and if enough of the votes cast are vetoes, the proposal is vetoed regardless of approval
+	Veto = 4,
+	/// Votes for one of the options of a multiple-choice proposal
+	Choice = 5,
 }
 // SAFTY: is a u8 and has a varient set to "0"
 unsafe impl Zeroable for CourtUserVoteStatus {}
@@ -81,6 +235,9 @@ impl From<u8> for CourtUserVoteStatus {
 			0 => CourtUserVoteStatus::Abstain,
 			1 => CourtUserVoteStatus::Approve,
 			2 => CourtUserVoteStatus::Oppose,
+			3 => CourtUserVoteStatus::Split,
+			4 => CourtUserVoteStatus::Veto,
+			5 => CourtUserVoteStatus::Choice,
 			_ => CourtUserVoteStatus::Abstain,
 		}
 	}
@@ -96,6 +253,9 @@ impl fmt::Display for CourtUserVoteStatus {
 			CourtUserVoteStatus::Oppose => f.write_str("oppose"),
 			CourtUserVoteStatus::Approve => f.write_str("approve"),
 			CourtUserVoteStatus::Abstain => f.write_str("abstain"),
+			CourtUserVoteStatus::Split => f.write_str("split"),
+			CourtUserVoteStatus::Veto => f.write_str("veto"),
+			CourtUserVoteStatus::Choice => f.write_str("choice"),
 		}
 	}
 }
@@ -105,7 +265,64 @@ impl CourtUserVoteInfo {
 		self.vote.into()
 	}
 	pub fn set_vote(&mut self, value: CourtUserVoteStatus) {
-		self.vote = value.into()
+		self.vote = value.into();
+		self.choice = 0;
+		self.split_approve_bps = 0;
+		self.split_oppose_bps = 0;
+	}
+	/// Returns `None` if the vote isn't split
+	pub fn split(&self) -> Option<CourtUserVoteSplit> {
+		if self.vote() != CourtUserVoteStatus::Split {
+			return None;
+		}
+		Some(CourtUserVoteSplit {
+			approve_bps: self.split_approve_bps,
+			oppose_bps: self.split_oppose_bps,
+			abstain_bps: 10000 - self.split_approve_bps - self.split_oppose_bps,
+		})
+	}
+	/// Sets the vote to "split", the split is assumed to be valid.
+	pub fn set_split(&mut self, value: &CourtUserVoteSplit) {
+		self.vote = CourtUserVoteStatus::Split.into();
+		self.choice = 0;
+		self.split_approve_bps = value.approve_bps;
+		self.split_oppose_bps = value.oppose_bps;
+	}
+	/// Returns `None` if the vote isn't for an option of a multiple-choice proposal
+	pub fn choice(&self) -> Option<u8> {
+		if self.vote() != CourtUserVoteStatus::Choice {
+			return None;
+		}
+		Some(self.choice)
+	}
+	/// Sets the vote to "choice", the option is assumed to exist.
+	pub fn set_choice(&mut self, option: u8) {
+		self.set_vote(CourtUserVoteStatus::Choice);
+		self.choice = option;
+	}
+	/// How many of the active votes are for approving, opposing, abstaining, and vetoing respectively.
+	///
+	/// Any votes lost to rounding a split are counted as abstaining. Votes for an option of a multiple-choice proposal
+	/// aren't included, see [crate::state::app::TransactionProposalInfo::set_option_votes].
+	pub fn vote_amounts(&self) -> (u128, u128, u128, u128) {
+		match self.vote() {
+			CourtUserVoteStatus::Approve => (self.active_votes, 0, 0, 0),
+			CourtUserVoteStatus::Oppose => (0, self.active_votes, 0, 0),
+			CourtUserVoteStatus::Abstain => (0, 0, self.active_votes, 0),
+			CourtUserVoteStatus::Veto => (0, 0, 0, self.active_votes),
+			CourtUserVoteStatus::Choice => (0, 0, 0, 0),
+			CourtUserVoteStatus::Split => {
+				// OVERFLOW SAFETY: See TransactionProposalInfo::status
+				let approve_votes = self.active_votes * self.split_approve_bps as u128 / 10000;
+				let oppose_votes = self.active_votes * self.split_oppose_bps as u128 / 10000;
+				(
+					approve_votes,
+					oppose_votes,
+					self.active_votes - approve_votes - oppose_votes,
+					0,
+				)
+			}
+		}
 	}
 }
 impl_serializable_as_ref!(CourtUserVoteInfo);
@@ -113,11 +330,24 @@ impl TryFrom<&CourtUserVoteInfoJsonable> for CourtUserVoteInfo {
 	type Error = StdError;
 
 	fn try_from(value: &CourtUserVoteInfoJsonable) -> Result<Self, Self::Error> {
-		Ok(CourtUserVoteInfo {
+		let mut result = CourtUserVoteInfo {
 			active_votes: value.active_votes.into(),
-			vote: value.vote.into(),
 			..Zeroable::zeroed()
-		})
+		};
+		match (value.vote, value.split, value.choice) {
+			(CourtUserVoteStatus::Split, Some(split), _) if split.is_valid() => result.set_split(&split),
+			(CourtUserVoteStatus::Split, ..) => {
+				return Err(StdError::generic_err(
+					"A split vote must have a split which adds up to 10000 basis points",
+				))
+			}
+			(CourtUserVoteStatus::Choice, _, Some(choice)) => result.set_choice(choice),
+			(CourtUserVoteStatus::Choice, ..) => {
+				return Err(StdError::generic_err("A choice vote must specify the option chosen"))
+			}
+			(vote, ..) => result.set_vote(vote),
+		}
+		Ok(result)
 	}
 }
 impl TryFrom<&CourtUserVoteInfo> for CourtUserVoteInfoJsonable {
@@ -127,6 +357,8 @@ impl TryFrom<&CourtUserVoteInfo> for CourtUserVoteInfoJsonable {
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: value.active_votes.into(),
 			vote: value.vote.into(),
+			split: value.split(),
+			choice: value.choice(),
 		})
 	}
 }
@@ -144,6 +376,33 @@ pub fn get_proposal_user_vote_store() -> StoredMap<(u32, SeiCanonicalAddr), Cour
 	StoredMap::new(USER_PROPOSAL_VOTES_NAMESPACE.as_ref())
 }
 
+const USER_PROPOSAL_COMMITMENTS_NAMESPACE: &str = "user_prop_c";
+/// A hidden vote on a private proposal, see [CourtVoteCommitmentPayload](crate::msg::CourtVoteCommitmentPayload)
+#[derive(Debug, Clone, Copy, Default, Zeroable, Pod, PartialEq, Eq)]
+#[repr(C)]
+pub struct CourtUserVoteCommitment {
+	pub hash: [u8; 32],
+	/// The votes which will be counted once the vote is revealed
+	pub votes: u128,
+}
+impl_serializable_as_ref!(CourtUserVoteCommitment);
+pub fn get_proposal_user_commitment_store() -> StoredMap<(u32, SeiCanonicalAddr), CourtUserVoteCommitment> {
+	StoredMap::new(USER_PROPOSAL_COMMITMENTS_NAMESPACE.as_ref())
+}
+
+const USER_PROPOSAL_SIGNED_VOTE_NONCES_NAMESPACE: &str = "user_prop_n";
+/// The nonce the next vote a user signs for a proposal must have, see
+/// [CourtSignedVotePayload](crate::msg::CourtSignedVotePayload)
+#[derive(Debug, Clone, Copy, Default, Zeroable, Pod, PartialEq, Eq)]
+#[repr(C)]
+pub struct CourtUserSignedVoteNonce {
+	pub nonce: u64,
+}
+impl_serializable_as_ref!(CourtUserSignedVoteNonce);
+pub fn get_proposal_user_signed_vote_nonce_store() -> StoredMap<(u32, SeiCanonicalAddr), CourtUserSignedVoteNonce> {
+	StoredMap::new(USER_PROPOSAL_SIGNED_VOTE_NONCES_NAMESPACE.as_ref())
+}
+
 /// Gets all the users who voted for a proposal
 ///
 /// `start` is inclusive while `end` is exclusive
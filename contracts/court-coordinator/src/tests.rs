@@ -7,15 +7,16 @@ use crate::error::CourtContractError;
 use cosmwasm_std::{
 	from_json,
 	testing::{BankQuerier, MockApi},
-	Addr, Api, BlockInfo, CanonicalAddr, Coin, ContractInfo, Env, MemoryStorage, OwnedDeps, Querier, QuerierResult,
-	QueryRequest, Response, StdError, StdResult, SystemError, SystemResult, Timestamp, TransactionInfo, WasmQuery,
+	to_json_binary, Addr, Api, BlockInfo, CanonicalAddr, Coin, ContractInfo, ContractResult, Env, MemoryStorage,
+	OwnedDeps, Querier, QuerierResult, QueryRequest, Response, StdError, StdResult, SystemError, SystemResult,
+	Timestamp, TransactionInfo, WasmQuery,
 };
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr,
 	extentions::timestamp::TimestampExtentions,
 	storage::base::{set_global_storage, GlobalStorage},
 };
-use sei_cosmwasm::SeiQueryWrapper;
+use sei_cosmwasm::{GetSeiAddressResponse, SeiQuery, SeiQueryWrapper};
 
 #[derive(Default)]
 struct SeiMockApi {
@@ -42,7 +43,7 @@ impl Api for SeiMockApi {
 		signature: &[u8],
 		public_key: &[u8],
 	) -> Result<bool, cosmwasm_std::VerificationError> {
-		self.inner_api.ed25519_verify(message_hash, signature, public_key)
+		self.inner_api.secp256k1_verify(message_hash, signature, public_key)
 	}
 	fn secp256k1_recover_pubkey(
 		&self,
@@ -76,12 +77,15 @@ impl Api for SeiMockApi {
 
 struct ThreadSafeMockSeiQuerier {
 	bank: BankQuerier,
+	/// Pairs of associated EVM and Sei addresses
+	evm_addresses: Vec<(String, String)>,
 }
 
 impl ThreadSafeMockSeiQuerier {
 	pub fn new(balances: &[(&str, &[Coin])]) -> Self {
 		ThreadSafeMockSeiQuerier {
 			bank: BankQuerier::new(balances),
+			evm_addresses: Vec::new(),
 		}
 	}
 	// set a new balance for the given address and return the old balance
@@ -102,6 +106,23 @@ impl ThreadSafeMockSeiQuerier {
 		// Bare-minimum for now
 		match request {
 			QueryRequest::Bank(bank_query) => self.bank.query(bank_query),
+			QueryRequest::Custom(SeiQueryWrapper {
+				query_data: SeiQuery::GetSeiAddress { evm_address },
+				..
+			}) => {
+				let sei_address = self
+					.evm_addresses
+					.iter()
+					.find(|(evm_addr, _)| evm_addr.eq_ignore_ascii_case(evm_address))
+					.map(|(_, sei_addr)| sei_addr.clone());
+				SystemResult::Ok(ContractResult::Ok(
+					to_json_binary(&GetSeiAddressResponse {
+						associated: sei_address.is_some(),
+						sei_address: sei_address.unwrap_or_default(),
+					})
+					.unwrap(),
+				))
+			}
 			QueryRequest::Custom(_) => SystemResult::Err(SystemError::UnsupportedRequest {
 				kind: "SeiQuery".to_string(),
 			}),
@@ -1,29 +1,41 @@
 use super::*;
 use crate::{
 	msg::*,
-	proposed_msg::ProposedCourtMsgJsonable,
+	proposed_msg::{ProposedCourtMsgDecoded, ProposedCourtMsgJsonable},
 	state::{
 		app::{
-			CourtAppConfigJsonable, TransactionProposalExecutionStatus, TransactionProposalInfoJsonable,
+			CourtAppConfigJsonable, CourtProposalTrackAmount, CourtProposalTrackJsonable, CourtQuorumSupply,
+			TransactionProposalExecutionStatus, TransactionProposalInfoJsonable, TransactionProposalMetadataJsonable,
 			TransactionProposalStatus,
 		},
-		user::{CourtUserStatsJsonable, CourtUserVoteInfoJsonable, CourtUserVoteStatus},
+		user::{CourtUserStatsJsonable, CourtUserVoteInfoJsonable, CourtUserVoteSplit, CourtUserVoteStatus},
 	},
 };
-use cosmwasm_std::{coin, MessageInfo};
+use cosmwasm_std::{coin, Binary, MessageInfo};
 use cw2::{get_contract_version, ContractVersion};
 use helpers::{get_known_vote_supply, new_env_and_instantiate};
+mod admin_allow_optimistic_proposals;
+mod admin_allow_stargate_messages;
 mod admin_change_admin;
 mod admin_change_config;
 mod admin_disallow_new_proposals;
 mod admin_mint_shares;
+mod admin_remove_proposal_track;
+mod admin_set_proposal_track;
 mod deactivate_votes;
 mod execute_proposal;
 mod helpers;
+mod user_commit_vote;
+mod user_propose_multiple_choice;
+mod user_propose_signal;
 mod user_propose_transaction;
 mod user_stake_votes;
+mod user_submit_signed_votes;
 mod user_unstake_votes;
 mod user_vote;
+mod user_vote_choice;
+mod user_vote_many;
+mod user_vote_weighted;
 
 #[test]
 pub fn instantiate() {
@@ -58,8 +70,25 @@ pub fn instantiate() {
 			minimum_vote_proposal_percent: 27,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 28,
+			veto_threshold_percent: 33,
+			optimistic_opposition_threshold_percent: 5,
+			expedited_minimum_vote_turnout_percent: 61,
+			expedited_minimum_vote_pass_percent: 75,
+			expedited_voting_period_seconds: 1044,
+			min_proposal_expiry_time_seconds: 123,
 			max_proposal_expiry_time_seconds: 3428,
 			execution_expiry_time_seconds: 2506,
+			quiet_ending_window_seconds: 321,
+			quiet_ending_extension_seconds: 654,
+			max_quiet_ending_extensions: 3,
+			min_stake_age_seconds: 86,
+			max_stake_lock_seconds: 7776000,
+			max_stake_lock_vote_multiplier_percent: 250,
+			quadratic_voting: true,
+			quorum_supply: CourtQuorumSupply::Staked,
+			max_user_votes: 500000u128.into(),
+			max_user_votes_bps: 2500,
+			reveal_period_seconds: 600,
 			vote_share_name: "Test vote tokens".into(),
 			vote_share_symbol: "TVT".into(),
 			vote_share_description: "Will everyone named Mike please stand".into(),
@@ -82,8 +111,25 @@ pub fn instantiate() {
 			minimum_vote_proposal_percent: 27,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 28,
+			veto_threshold_percent: 33,
+			optimistic_opposition_threshold_percent: 5,
+			expedited_minimum_vote_turnout_percent: 61,
+			expedited_minimum_vote_pass_percent: 75,
+			expedited_voting_period_seconds: 1044,
+			min_proposal_expiry_time_seconds: 123,
 			max_proposal_expiry_time_seconds: 3428,
 			execution_expiry_time_seconds: 2506,
+			quiet_ending_window_seconds: 321,
+			quiet_ending_extension_seconds: 654,
+			max_quiet_ending_extensions: 3,
+			min_stake_age_seconds: 86,
+			max_stake_lock_seconds: 7776000,
+			max_stake_lock_vote_multiplier_percent: 250,
+			quadratic_voting: true,
+			quorum_supply: CourtQuorumSupply::Staked,
+			max_user_votes: 500000u128.into(),
+			max_user_votes_bps: 2500,
+			reveal_period_seconds: 600,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(RANDOM_ACCOUNT_2),
 		})
@@ -167,3 +213,20 @@ pub fn instantiate() {
 		}
 	}));
 }
+
+#[test]
+pub fn migrate() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let env = env_deps.0.clone();
+	crate::contract::migrate(env_deps.1.as_mut(), env.clone(), CourtMigrateMsg {}).unwrap();
+
+	// Earlier versions stored things with layouts which can't be read anymore
+	cw2::set_contract_version(&mut env_deps.1.storage, "court-coordinator-contract", "0.1.0").unwrap();
+	assert_eq!(
+		crate::contract::migrate(env_deps.1.as_mut(), env, CourtMigrateMsg {}).unwrap_err(),
+		CourtContractError::UnsupportedMigration {
+			contract: "court-coordinator-contract".into(),
+			version: "0.1.0".into(),
+		}
+	);
+}
@@ -0,0 +1,161 @@
+use super::*;
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowOptimisticProposals {
+			proposers: vec![Addr::unchecked(SHARES_HOLDER_ACCOUNT_1)],
+			msg_kinds: vec!["send_coin".into()],
+			allowed: true,
+		}),
+	);
+}
+
+#[test]
+pub fn authorized_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+
+	helpers::assert_only_authorized_instruction(
+		&mut env_deps,
+		&[],
+		&[
+			RANDOM_ACCOUNT_1,
+			RANDOM_ACCOUNT_2,
+			RANDOM_ACCOUNT_3,
+			RANDOM_ACCOUNT_4,
+			RANDOM_ACCOUNT_5,
+			SHARES_HOLDER_ACCOUNT_1,
+			SHARES_HOLDER_ACCOUNT_2,
+			SHARES_HOLDER_ACCOUNT_3,
+			SHARES_HOLDER_ACCOUNT_4,
+			SHARES_HOLDER_ACCOUNT_5,
+		],
+		&[ADMIN_ACCOUNT],
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowOptimisticProposals {
+			proposers: vec![Addr::unchecked(SHARES_HOLDER_ACCOUNT_1)],
+			msg_kinds: vec!["send_coin".into()],
+			allowed: true,
+		}),
+	);
+}
+
+#[test]
+pub fn correct() {
+	let mut env_deps = new_env_and_instantiate(None);
+	// Nothing is allowed by default
+	assert_eq!(
+		helpers::query_optimistic_allowlist(&env_deps),
+		Ok(CourtQueryResponseOptimisticAllowlist {
+			proposers: vec![],
+			msg_kinds: vec![],
+		})
+	);
+
+	helpers::execute_allow_optimistic_proposals(
+		&mut env_deps,
+		None,
+		&[SHARES_HOLDER_ACCOUNT_1, SHARES_HOLDER_ACCOUNT_2],
+		&["send_coin", "gov_vote"],
+		true,
+	);
+	assert_eq!(
+		helpers::query_optimistic_allowlist(&env_deps),
+		Ok(CourtQueryResponseOptimisticAllowlist {
+			proposers: vec![
+				Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				Addr::unchecked(SHARES_HOLDER_ACCOUNT_2)
+			],
+			msg_kinds: vec!["send_coin".into(), "gov_vote".into()],
+		})
+	);
+
+	// No duplicates
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[SHARES_HOLDER_ACCOUNT_1], &["send_coin"], true);
+	assert_eq!(
+		helpers::query_optimistic_allowlist(&env_deps),
+		Ok(CourtQueryResponseOptimisticAllowlist {
+			proposers: vec![
+				Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				Addr::unchecked(SHARES_HOLDER_ACCOUNT_2)
+			],
+			msg_kinds: vec!["send_coin".into(), "gov_vote".into()],
+		})
+	);
+
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[SHARES_HOLDER_ACCOUNT_1], &["send_coin"], false);
+	assert_eq!(
+		helpers::query_optimistic_allowlist(&env_deps),
+		Ok(CourtQueryResponseOptimisticAllowlist {
+			proposers: vec![Addr::unchecked(SHARES_HOLDER_ACCOUNT_2)],
+			msg_kinds: vec!["gov_vote".into()],
+		})
+	);
+}
+
+#[test]
+pub fn gates_proposals() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 100000);
+	let send_coin = ProposedCourtMsgJsonable::SendCoin {
+		to: RANDOM_ACCOUNT_1.into(),
+		denom: "usei".into(),
+		amount: 1337u128.into(),
+	};
+	let gov_vote = ProposedCourtMsgJsonable::GovVote {
+		proposal_id: 1,
+		option: cosmwasm_std::VoteOption::Yes,
+	};
+	let propose_optimistic = |msgs: Vec<ProposedCourtMsgJsonable>| CourtExecuteMsg::ProposeTransaction {
+		msgs,
+		expiry_time_seconds: 1200,
+		metadata: None,
+		optimistic: true,
+		expedited: false,
+		private: false,
+	};
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			propose_optimistic(vec![send_coin.clone()]),
+		)
+		.unwrap_err(),
+		CourtContractError::OptimisticProposalNotAllowed
+	);
+
+	// Designated proposers may propose anything
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[SHARES_HOLDER_ACCOUNT_1], &[], true);
+	helpers::execute_propose_optimistic_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![send_coin.clone(), gov_vote.clone()],
+		1200,
+	);
+
+	// Anyone else may only propose allowlisted kinds of messages
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[], &["send_coin"], true);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+				funds: vec![],
+			}),
+			propose_optimistic(vec![send_coin.clone(), gov_vote]),
+		)
+		.unwrap_err(),
+		CourtContractError::OptimisticProposalNotAllowed
+	);
+	helpers::execute_propose_optimistic_transaction(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, vec![send_coin], 1200);
+}
@@ -0,0 +1,170 @@
+use sei_cosmwasm::SeiMsg;
+
+use super::*;
+
+const STARGATE_TYPE_URL_1: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+const STARGATE_TYPE_URL_2: &str = "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward";
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowStargateMessages {
+			type_urls: vec![STARGATE_TYPE_URL_1.into()],
+			allowed: true,
+		}),
+	);
+}
+
+#[test]
+pub fn authorized_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let contract_addr = env_deps.0.contract.address.to_string();
+	helpers::execute_change_admin(&mut env_deps, None, &contract_addr);
+
+	helpers::assert_only_authorized_instruction(
+		&mut env_deps,
+		&[],
+		&[
+			ADMIN_ACCOUNT,
+			RANDOM_ACCOUNT_1,
+			RANDOM_ACCOUNT_2,
+			RANDOM_ACCOUNT_3,
+			RANDOM_ACCOUNT_4,
+			RANDOM_ACCOUNT_5,
+			SHARES_HOLDER_ACCOUNT_1,
+			SHARES_HOLDER_ACCOUNT_2,
+			SHARES_HOLDER_ACCOUNT_3,
+			SHARES_HOLDER_ACCOUNT_4,
+			SHARES_HOLDER_ACCOUNT_5,
+		],
+		&[&contract_addr],
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowStargateMessages {
+			type_urls: vec![STARGATE_TYPE_URL_1.into()],
+			allowed: true,
+		}),
+	);
+}
+
+#[test]
+pub fn correct() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let contract_addr = env_deps.0.contract.address.to_string();
+	// Nothing is allowed by default
+	assert_eq!(helpers::query_stargate_allowlist(&env_deps), Ok(vec![]));
+
+	// Only proposals can allow stargate messages
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowStargateMessages {
+			type_urls: vec![STARGATE_TYPE_URL_1.into()],
+			allowed: true,
+		}),
+	);
+	assert!(execute_response.is_err_and(|err| matches!(err, CourtContractError::Unauthorized(_))));
+	assert_eq!(helpers::query_stargate_allowlist(&env_deps), Ok(vec![]));
+
+	helpers::execute_change_admin(&mut env_deps, None, &contract_addr);
+	helpers::execute_allow_stargate_messages(
+		&mut env_deps,
+		Some(&contract_addr),
+		&[STARGATE_TYPE_URL_1, STARGATE_TYPE_URL_2],
+		true,
+	);
+	assert_eq!(
+		helpers::query_stargate_allowlist(&env_deps),
+		Ok(vec![STARGATE_TYPE_URL_1.into(), STARGATE_TYPE_URL_2.into()])
+	);
+
+	// No duplicates
+	helpers::execute_allow_stargate_messages(&mut env_deps, Some(&contract_addr), &[STARGATE_TYPE_URL_1], true);
+	assert_eq!(
+		helpers::query_stargate_allowlist(&env_deps),
+		Ok(vec![STARGATE_TYPE_URL_1.into(), STARGATE_TYPE_URL_2.into()])
+	);
+
+	helpers::execute_allow_stargate_messages(&mut env_deps, Some(&contract_addr), &[STARGATE_TYPE_URL_1], false);
+	assert_eq!(
+		helpers::query_stargate_allowlist(&env_deps),
+		Ok(vec![STARGATE_TYPE_URL_2.into()])
+	);
+}
+
+#[test]
+pub fn gates_proposals() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let proposal_msgs = vec![ProposedCourtMsgJsonable::Stargate {
+		type_url: STARGATE_TYPE_URL_1.into(),
+		value: Binary(vec![0x0a, 0x04, 0x74, 0x65, 0x73, 0x74]),
+	}];
+	let contract_addr = env_deps.0.contract.address.to_string();
+	helpers::execute_change_admin(&mut env_deps, None, &contract_addr);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs: proposal_msgs.clone(),
+			expiry_time_seconds: 420,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
+		},
+	);
+	assert_eq!(
+		execute_response.unwrap_err(),
+		CourtContractError::StargateMessageNotAllowed(STARGATE_TYPE_URL_1.into())
+	);
+
+	helpers::execute_allow_stargate_messages(&mut env_deps, Some(&contract_addr), &[STARGATE_TYPE_URL_1], true);
+	let proposal_id = helpers::execute_create_guaranteed_passing_proposal(
+		&mut env_deps,
+		proposal_msgs.clone(),
+		420,
+		SHARES_HOLDER_ACCOUNT_1,
+	);
+	let second_proposal_id =
+		helpers::execute_create_guaranteed_passing_proposal(&mut env_deps, proposal_msgs, 420, SHARES_HOLDER_ACCOUNT_1);
+	helpers::advance_time_to_vote_end(&mut env_deps, second_proposal_id);
+
+	let execute_result = helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::ExecuteProposal { id: proposal_id },
+	)
+	.unwrap();
+	assert_eq!(
+		execute_result.messages,
+		vec![cosmwasm_std::SubMsg::new(cosmwasm_std::CosmosMsg::<SeiMsg>::Stargate {
+			type_url: STARGATE_TYPE_URL_1.into(),
+			value: Binary(vec![0x0a, 0x04, 0x74, 0x65, 0x73, 0x74]),
+		})]
+	);
+
+	// Proposals can't be executed if their message types were removed from the allowlist after they were created
+	helpers::execute_allow_stargate_messages(&mut env_deps, Some(&contract_addr), &[STARGATE_TYPE_URL_1], false);
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::ExecuteProposal { id: second_proposal_id },
+	);
+	assert_eq!(
+		execute_response.unwrap_err(),
+		CourtContractError::StargateMessageNotAllowed(STARGATE_TYPE_URL_1.into())
+	);
+}
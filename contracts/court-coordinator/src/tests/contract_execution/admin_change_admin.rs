@@ -68,12 +68,29 @@ pub fn new_guy_can_do_things() {
 			SHARES_HOLDER_ACCOUNT_5,
 		],
 		&[RANDOM_ACCOUNT_1],
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: Some(69),
 			minimum_vote_turnout_percent: Some(69),
 			minimum_vote_pass_percent: Some(69),
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	);
 }
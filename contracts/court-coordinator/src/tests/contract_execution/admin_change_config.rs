@@ -10,13 +10,30 @@ pub fn unfunded_check() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 }
 
@@ -40,13 +57,30 @@ pub fn authorized_check() {
 			SHARES_HOLDER_ACCOUNT_5,
 		],
 		&[ADMIN_ACCOUNT],
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	);
 }
 
@@ -60,13 +94,30 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -77,8 +128,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 10,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 7200,
 			execution_expiry_time_seconds: 3600,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -92,13 +160,30 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: Some(69),
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -109,8 +194,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 69,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 7200,
 			execution_expiry_time_seconds: 3600,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -124,13 +226,30 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: Some(69),
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -141,8 +260,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 10,
 			minimum_vote_turnout_percent: 69,
 			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 7200,
 			execution_expiry_time_seconds: 3600,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -156,13 +292,30 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: Some(69),
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -173,8 +326,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 10,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 69,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 7200,
 			execution_expiry_time_seconds: 3600,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -188,13 +358,30 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: Some(69),
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -205,8 +392,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 10,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 69,
 			execution_expiry_time_seconds: 3600,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -220,13 +424,30 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: Some(69),
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -237,8 +458,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 10,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 7200,
 			execution_expiry_time_seconds: 69,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -251,13 +489,145 @@ pub fn correct() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: Some(69),
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
+			max_proposal_expiry_time_seconds: None,
+			execution_expiry_time_seconds: None,
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_config(&env_deps).unwrap(),
+		// Config is what's applied
+		CourtAppConfigJsonable {
+			allow_new_proposals: true,
+			minimum_vote_proposal_percent: 10,
+			minimum_vote_turnout_percent: 20,
+			minimum_vote_pass_percent: 69,
+			veto_threshold_percent: 0,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
+			max_proposal_expiry_time_seconds: 7200,
+			execution_expiry_time_seconds: 69,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
+			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
+			admin: Addr::unchecked(ADMIN_ACCOUNT)
+		}
+	);
+	env_deps.0.block.time = env_deps.0.block.time.plus_minutes(1);
+
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			minimum_vote_proposal_percent: None,
+			minimum_vote_turnout_percent: None,
+			minimum_vote_pass_percent: None,
+			veto_threshold_percent: Some(34),
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_config(&env_deps).unwrap(),
+		// Config is what's applied
+		CourtAppConfigJsonable {
+			allow_new_proposals: true,
+			minimum_vote_proposal_percent: 10,
+			minimum_vote_turnout_percent: 20,
+			minimum_vote_pass_percent: 69,
+			veto_threshold_percent: 34,
+			optimistic_opposition_threshold_percent: 10,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
+			max_proposal_expiry_time_seconds: 7200,
+			execution_expiry_time_seconds: 69,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
+			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
+			admin: Addr::unchecked(ADMIN_ACCOUNT)
+		}
+	);
+	env_deps.0.block.time = env_deps.0.block.time.plus_minutes(1);
+
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
 		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			minimum_vote_proposal_percent: None,
+			minimum_vote_turnout_percent: None,
+			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: Some(25),
+			max_proposal_expiry_time_seconds: None,
+			execution_expiry_time_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -268,8 +638,25 @@ pub fn correct() {
 			minimum_vote_proposal_percent: 10,
 			minimum_vote_turnout_percent: 20,
 			minimum_vote_pass_percent: 69,
+			veto_threshold_percent: 34,
+			optimistic_opposition_threshold_percent: 25,
+			expedited_minimum_vote_turnout_percent: 50,
+			expedited_minimum_vote_pass_percent: 66,
+			expedited_voting_period_seconds: 600,
+			min_proposal_expiry_time_seconds: 60,
 			max_proposal_expiry_time_seconds: 7200,
 			execution_expiry_time_seconds: 69,
+			quiet_ending_window_seconds: 0,
+			quiet_ending_extension_seconds: 0,
+			max_quiet_ending_extensions: 0,
+			min_stake_age_seconds: 0,
+			max_stake_lock_seconds: 0,
+			max_stake_lock_vote_multiplier_percent: 0,
+			quadratic_voting: false,
+			quorum_supply: CourtQuorumSupply::Total,
+			max_user_votes: 0u128.into(),
+			max_user_votes_bps: 0,
+			reveal_period_seconds: 0,
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -310,13 +697,30 @@ pub fn only_when_no_pending_proposals() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	);
 	assert!(execute_response.is_err_and(|err| { err.to_string().contains("votes must not be tied to any proposals") }));
 	env_deps.0.block.time = env_deps.0.block.time.plus_days(69);
@@ -336,13 +740,147 @@ pub fn only_when_no_pending_proposals() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
+	)
+	.unwrap();
+}
+
+#[test]
+pub fn invalid_values_check() {
+	let mut env_deps = helpers::new_env_and_instantiate(None);
+	let sender = Some(MessageInfo {
+		sender: Addr::unchecked(ADMIN_ACCOUNT),
+		funds: vec![],
+	});
+	let config_before = helpers::query_config(&env_deps).unwrap();
+	for (changes, expected_error) in [
+		(
+			CourtConfigChanges {
+				veto_threshold_percent: Some(101),
+				..Default::default()
+			},
+			CourtContractError::ConfigValueTooHigh {
+				proprety_name: "veto_threshold_percent".into(),
+				max: 100,
+			},
+		),
+		(
+			CourtConfigChanges {
+				optimistic_opposition_threshold_percent: Some(101),
+				..Default::default()
+			},
+			CourtContractError::ConfigValueTooHigh {
+				proprety_name: "optimistic_opposition_threshold_percent".into(),
+				max: 100,
+			},
+		),
+		(
+			CourtConfigChanges {
+				expedited_minimum_vote_pass_percent: Some(255),
+				..Default::default()
+			},
+			CourtContractError::ConfigValueTooHigh {
+				proprety_name: "expedited_minimum_vote_pass_percent".into(),
+				max: 100,
+			},
+		),
+		(
+			CourtConfigChanges {
+				max_user_votes_bps: Some(10001),
+				..Default::default()
+			},
+			CourtContractError::ConfigValueTooHigh {
+				proprety_name: "max_user_votes_bps".into(),
+				max: 10000,
+			},
+		),
+		(
+			CourtConfigChanges {
+				max_stake_lock_vote_multiplier_percent: Some(1001),
+				..Default::default()
+			},
+			CourtContractError::ConfigValueTooHigh {
+				proprety_name: "max_stake_lock_vote_multiplier_percent".into(),
+				max: 1000,
+			},
+		),
+		(
+			CourtConfigChanges {
+				min_proposal_expiry_time_seconds: Some(7201),
+				..Default::default()
+			},
+			CourtContractError::InvalidProposalExpiryTimeRange,
+		),
+		(
+			CourtConfigChanges {
+				min_proposal_expiry_time_seconds: Some(120),
+				max_proposal_expiry_time_seconds: Some(60),
+				..Default::default()
+			},
+			CourtContractError::InvalidProposalExpiryTimeRange,
+		),
+		(
+			CourtConfigChanges {
+				quadratic_voting: Some(true),
+				..Default::default()
+			},
+			CourtContractError::QuadraticVotingRequiresStakedQuorum,
+		),
+		(
+			CourtConfigChanges {
+				quadratic_voting: Some(true),
+				quorum_supply: Some(CourtQuorumSupply::Circulating),
+				..Default::default()
+			},
+			CourtContractError::QuadraticVotingRequiresStakedQuorum,
+		),
+	] {
+		assert_eq!(
+			helpers::execute(
+				&mut env_deps,
+				sender.clone(),
+				CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(changes)),
+			)
+			.unwrap_err(),
+			expected_error
+		);
+	}
+	// Nothing was saved
+	assert_eq!(helpers::query_config(&env_deps).unwrap(), config_before);
+
+	helpers::execute(
+		&mut env_deps,
+		sender,
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			veto_threshold_percent: Some(100),
+			max_user_votes_bps: Some(10000),
+			max_stake_lock_vote_multiplier_percent: Some(1000),
+			min_proposal_expiry_time_seconds: Some(7200),
+			..Default::default()
+		})),
 	)
 	.unwrap();
 }
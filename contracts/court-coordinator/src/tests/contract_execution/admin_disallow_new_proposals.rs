@@ -68,8 +68,25 @@ pub fn blocks_proposals() {
 		minimum_vote_proposal_percent: 10,
 		minimum_vote_turnout_percent: 10,
 		minimum_vote_pass_percent: 50,
+		veto_threshold_percent: 0,
+		optimistic_opposition_threshold_percent: 10,
+		expedited_minimum_vote_turnout_percent: 50,
+		expedited_minimum_vote_pass_percent: 66,
+		expedited_voting_period_seconds: 600,
+		min_proposal_expiry_time_seconds: 60,
 		max_proposal_expiry_time_seconds: 86400,
 		execution_expiry_time_seconds: 86400,
+		quiet_ending_window_seconds: 0,
+		quiet_ending_extension_seconds: 0,
+		max_quiet_ending_extensions: 0,
+		min_stake_age_seconds: 0,
+		max_stake_lock_seconds: 0,
+		max_stake_lock_vote_multiplier_percent: 0,
+		quadratic_voting: false,
+		quorum_supply: CourtQuorumSupply::Total,
+		max_user_votes: 0u128.into(),
+		max_user_votes_bps: 0,
+		reveal_period_seconds: 0,
 		vote_share_name: "Test vote token".into(),
 		vote_share_symbol: "TVT".into(),
 		vote_share_description: "Test vote token".into(),
@@ -90,6 +107,10 @@ pub fn blocks_proposals() {
 				amount: 31337u128.into(),
 			}],
 			expiry_time_seconds: 3600,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 	assert!(
@@ -109,6 +130,10 @@ pub fn blocks_proposals() {
 				amount: 31337u128.into(),
 			}],
 			expiry_time_seconds: 3600,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	)
 	.unwrap();
@@ -0,0 +1,113 @@
+use super::*;
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("upgrades"));
+
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::RemoveProposalTrack {
+			name: "upgrades".into(),
+		}),
+	);
+}
+
+#[test]
+pub fn authorized_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("upgrades"));
+
+	helpers::assert_only_authorized_instruction(
+		&mut env_deps,
+		&[],
+		&[
+			RANDOM_ACCOUNT_1,
+			RANDOM_ACCOUNT_2,
+			RANDOM_ACCOUNT_3,
+			RANDOM_ACCOUNT_4,
+			RANDOM_ACCOUNT_5,
+			SHARES_HOLDER_ACCOUNT_1,
+			SHARES_HOLDER_ACCOUNT_2,
+			SHARES_HOLDER_ACCOUNT_3,
+			SHARES_HOLDER_ACCOUNT_4,
+			SHARES_HOLDER_ACCOUNT_5,
+		],
+		&[ADMIN_ACCOUNT],
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::RemoveProposalTrack {
+			name: "upgrades".into(),
+		}),
+	);
+}
+
+#[test]
+pub fn correct() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("large-payments"));
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("upgrades"));
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("meta"));
+
+	env_deps.0.block.time = env_deps.0.block.time.plus_minutes(1);
+	helpers::execute_remove_proposal_track(&mut env_deps, "upgrades");
+	assert_eq!(
+		helpers::query_proposal_tracks(&env_deps),
+		Ok(vec![
+			helpers::new_proposal_track("large-payments"),
+			helpers::new_proposal_track("meta")
+		])
+	);
+	// The indices of the tracks after it have changed
+	assert_eq!(
+		helpers::query_config(&env_deps)
+			.unwrap()
+			.last_config_change_timestamp_ms,
+		env_deps.0.block.time.millis()
+	);
+
+	assert!(helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::RemoveProposalTrack {
+			name: "upgrades".into(),
+		}),
+	)
+	.is_err_and(|err| err.to_string().contains("Proposal track \"upgrades\" does not exist")));
+}
+
+#[test]
+pub fn votes_active_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("upgrades"));
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(ADMIN_ACCOUNT),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Admin(CourtAdminExecuteMsg::RemoveProposalTrack {
+				name: "upgrades".into(),
+			}),
+		)
+		.unwrap_err(),
+		CourtContractError::VotesActive
+	);
+}
@@ -0,0 +1,167 @@
+use super::*;
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::SetProposalTrack {
+			track: helpers::new_proposal_track("upgrades"),
+		}),
+	);
+}
+
+#[test]
+pub fn authorized_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+
+	helpers::assert_only_authorized_instruction(
+		&mut env_deps,
+		&[],
+		&[
+			RANDOM_ACCOUNT_1,
+			RANDOM_ACCOUNT_2,
+			RANDOM_ACCOUNT_3,
+			RANDOM_ACCOUNT_4,
+			RANDOM_ACCOUNT_5,
+			SHARES_HOLDER_ACCOUNT_1,
+			SHARES_HOLDER_ACCOUNT_2,
+			SHARES_HOLDER_ACCOUNT_3,
+			SHARES_HOLDER_ACCOUNT_4,
+			SHARES_HOLDER_ACCOUNT_5,
+		],
+		&[ADMIN_ACCOUNT],
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::SetProposalTrack {
+			track: helpers::new_proposal_track("upgrades"),
+		}),
+	);
+}
+
+#[test]
+pub fn correct() {
+	let mut env_deps = new_env_and_instantiate(None);
+	assert_eq!(helpers::query_proposal_tracks(&env_deps), Ok(vec![]));
+
+	let large_payments_track = CourtProposalTrackJsonable {
+		minimum_vote_pass_percent: 75,
+		timelock_seconds: 600,
+		msg_kinds: vec!["send_coin".into()],
+		min_amount: Some(CourtProposalTrackAmount {
+			denom: "usei".into(),
+			amount: 1000000u128.into(),
+		}),
+		..helpers::new_proposal_track("large-payments")
+	};
+	let upgrades_track = CourtProposalTrackJsonable {
+		minimum_vote_proposal_percent: 20,
+		minimum_vote_pass_percent: 90,
+		msg_kinds: vec!["upgrade_wasm_contract".into()],
+		targets: vec![RANDOM_ACCOUNT_2.into()],
+		..helpers::new_proposal_track("upgrades")
+	};
+	env_deps.0.block.time = env_deps.0.block.time.plus_minutes(1);
+	helpers::execute_set_proposal_track(&mut env_deps, large_payments_track.clone());
+	helpers::execute_set_proposal_track(&mut env_deps, upgrades_track.clone());
+	assert_eq!(
+		helpers::query_proposal_tracks(&env_deps),
+		Ok(vec![large_payments_track.clone(), upgrades_track.clone()])
+	);
+	// Changing the voting rules is a config change
+	assert_eq!(
+		helpers::query_config(&env_deps)
+			.unwrap()
+			.last_config_change_timestamp_ms,
+		env_deps.0.block.time.millis()
+	);
+
+	// Tracks with the same name are replaced in-place
+	let large_payments_track = CourtProposalTrackJsonable {
+		minimum_vote_pass_percent: 66,
+		..large_payments_track
+	};
+	helpers::execute_set_proposal_track(&mut env_deps, large_payments_track.clone());
+	assert_eq!(
+		helpers::query_proposal_tracks(&env_deps),
+		Ok(vec![large_payments_track, upgrades_track])
+	);
+}
+
+#[test]
+pub fn invalid_track_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	for track in [
+		helpers::new_proposal_track(""),
+		CourtProposalTrackJsonable {
+			execution_expiry_time_seconds: 0,
+			..helpers::new_proposal_track("upgrades")
+		},
+	] {
+		assert_eq!(
+			helpers::execute(
+				&mut env_deps,
+				Some(MessageInfo {
+					sender: Addr::unchecked(ADMIN_ACCOUNT),
+					funds: vec![],
+				}),
+				CourtExecuteMsg::Admin(CourtAdminExecuteMsg::SetProposalTrack { track }),
+			)
+			.unwrap_err(),
+			CourtContractError::InvalidProposalTrack
+		);
+	}
+
+	for i in 0..16 {
+		helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track(&format!("track-{i}")));
+	}
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(ADMIN_ACCOUNT),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Admin(CourtAdminExecuteMsg::SetProposalTrack {
+				track: helpers::new_proposal_track("track-16"),
+			}),
+		)
+		.unwrap_err(),
+		CourtContractError::TooManyProposalTracks(16)
+	);
+	// Existing tracks can still be replaced
+	helpers::execute_set_proposal_track(&mut env_deps, helpers::new_proposal_track("track-15"));
+}
+
+#[test]
+pub fn votes_active_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(ADMIN_ACCOUNT),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Admin(CourtAdminExecuteMsg::SetProposalTrack {
+				track: helpers::new_proposal_track("upgrades"),
+			}),
+		)
+		.unwrap_err(),
+		CourtContractError::VotesActive
+	);
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Decimal, GovMsg, VoteOption, WeightedVoteOption};
 use sei_cosmwasm::SeiMsg;
 
 use super::*;
@@ -308,13 +308,30 @@ pub fn only_execute_on_passed_state() {
 			sender: Addr::unchecked(ADMIN_ACCOUNT),
 			funds: vec![],
 		}),
-		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig {
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
 			minimum_vote_proposal_percent: None,
 			minimum_vote_turnout_percent: None,
 			minimum_vote_pass_percent: None,
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
 			max_proposal_expiry_time_seconds: None,
 			execution_expiry_time_seconds: None,
-		}),
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
 	)
 	.unwrap();
 	assert_eq!(
@@ -714,3 +731,190 @@ pub fn only_absatain_votes() {
 			.contains("status should be \"passed\" for this operation")
 	}));
 }
+
+#[test]
+pub fn gov_vote_proposals() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let proposal_id = helpers::execute_create_passing_proposal(
+		&mut env_deps,
+		vec![
+			ProposedCourtMsgJsonable::GovVote {
+				proposal_id: 69,
+				option: VoteOption::NoWithVeto,
+			},
+			ProposedCourtMsgJsonable::GovVoteWeighted {
+				proposal_id: 420,
+				options: vec![
+					WeightedVoteOption {
+						option: VoteOption::Yes,
+						weight: Decimal::percent(25),
+					},
+					WeightedVoteOption {
+						option: VoteOption::Abstain,
+						weight: Decimal::percent(75),
+					},
+				],
+			},
+			ProposedCourtMsgJsonable::GovVoteByTally { proposal_id: 1337 },
+		],
+		420,
+		SHARES_HOLDER_ACCOUNT_1,
+		SHARES_HOLDER_ACCOUNT_2,
+	);
+	helpers::advance_time_to_vote_end(&mut env_deps, proposal_id);
+	// Sanity check
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, proposal_id)
+			.unwrap()
+			.unwrap()
+			.status,
+		TransactionProposalStatus::Passed
+	);
+
+	let execute_result = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(RANDOM_ACCOUNT_5),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ExecuteProposal { id: proposal_id },
+	)
+	.unwrap();
+	assert_eq!(
+		execute_result.messages,
+		vec![
+			cosmwasm_std::SubMsg::new(cosmwasm_std::CosmosMsg::<SeiMsg>::Gov(GovMsg::Vote {
+				proposal_id: 69,
+				vote: VoteOption::NoWithVeto
+			})),
+			cosmwasm_std::SubMsg::new(cosmwasm_std::CosmosMsg::<SeiMsg>::Gov(GovMsg::VoteWeighted {
+				proposal_id: 420,
+				options: vec![
+					WeightedVoteOption {
+						option: VoteOption::Yes,
+						weight: Decimal::percent(25),
+					},
+					WeightedVoteOption {
+						option: VoteOption::Abstain,
+						weight: Decimal::percent(75),
+					},
+				]
+			})),
+			// The passing proposal has an equal amount of votes for and against
+			cosmwasm_std::SubMsg::new(cosmwasm_std::CosmosMsg::<SeiMsg>::Gov(GovMsg::VoteWeighted {
+				proposal_id: 1337,
+				options: vec![
+					WeightedVoteOption {
+						option: VoteOption::Yes,
+						weight: Decimal::percent(50),
+					},
+					WeightedVoteOption {
+						option: VoteOption::No,
+						weight: Decimal::percent(50),
+					},
+				]
+			})),
+		]
+	);
+}
+
+#[test]
+pub fn timelock_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_set_proposal_track(
+		&mut env_deps,
+		CourtProposalTrackJsonable {
+			timelock_seconds: 600,
+			msg_kinds: vec!["send_coin".into()],
+			..helpers::new_proposal_track("payments")
+		},
+	);
+	let proposal_id = helpers::execute_create_guaranteed_passing_proposal(
+		&mut env_deps,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		420,
+		SHARES_HOLDER_ACCOUNT_1,
+	);
+	// Passes early, but can't be executed until after the timelock
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, proposal_id)
+			.unwrap()
+			.unwrap()
+			.status,
+		TransactionProposalStatus::Passed
+	);
+	let timelock_expiry_timestamp_ms = env_deps.0.block.time.plus_seconds(420 + 600).millis();
+	assert_eq!(
+		helpers::query_proposal_tally(&env_deps, proposal_id, None)
+			.unwrap()
+			.unwrap()
+			.timelock_remaining_ms,
+		(420 + 600) * 1000
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(RANDOM_ACCOUNT_5),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ExecuteProposal { id: proposal_id },
+		)
+		.unwrap_err(),
+		CourtContractError::ProposalTimelocked(timelock_expiry_timestamp_ms)
+	);
+
+	// Still locked once voting ends
+	helpers::advance_time_to_vote_end(&mut env_deps, proposal_id);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(RANDOM_ACCOUNT_5),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ExecuteProposal { id: proposal_id },
+		)
+		.unwrap_err(),
+		CourtContractError::ProposalTimelocked(timelock_expiry_timestamp_ms)
+	);
+
+	// The execution window starts once the timelock ends
+	assert_eq!(
+		helpers::query_get_proposal_at_time(&env_deps, proposal_id, timelock_expiry_timestamp_ms + 3600 * 1000)
+			.unwrap()
+			.unwrap()
+			.status,
+		TransactionProposalStatus::Passed
+	);
+	assert_eq!(
+		helpers::query_get_proposal_at_time(&env_deps, proposal_id, timelock_expiry_timestamp_ms + 3600 * 1000 + 1)
+			.unwrap()
+			.unwrap()
+			.status,
+		TransactionProposalStatus::ExecutionExpired
+	);
+	assert_eq!(
+		helpers::query_proposal_tally(&env_deps, proposal_id, None)
+			.unwrap()
+			.unwrap()
+			.execution_time_remaining_ms,
+		(600 + 3600) * 1000
+	);
+
+	env_deps.0.block.time = Timestamp::from_millis(timelock_expiry_timestamp_ms);
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(RANDOM_ACCOUNT_5),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ExecuteProposal { id: proposal_id },
+	)
+	.unwrap();
+	assert_eq!(execute_response.messages.len(), 1);
+}
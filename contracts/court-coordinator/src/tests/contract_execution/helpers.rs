@@ -16,6 +16,27 @@ pub fn new_env_and_instantiate(msg: Option<CourtInstantiateMsg>) -> MutexGuard<'
 				.map(|msg| { msg.minimum_vote_turnout_percent })
 				.unwrap_or(20),
 			minimum_vote_pass_percent: msg.as_ref().map(|msg| { msg.minimum_vote_pass_percent }).unwrap_or(50),
+			veto_threshold_percent: msg.as_ref().map(|msg| msg.veto_threshold_percent).unwrap_or(0),
+			optimistic_opposition_threshold_percent: msg
+				.as_ref()
+				.map(|msg| msg.optimistic_opposition_threshold_percent)
+				.unwrap_or(10),
+			expedited_minimum_vote_turnout_percent: msg
+				.as_ref()
+				.map(|msg| msg.expedited_minimum_vote_turnout_percent)
+				.unwrap_or(50),
+			expedited_minimum_vote_pass_percent: msg
+				.as_ref()
+				.map(|msg| msg.expedited_minimum_vote_pass_percent)
+				.unwrap_or(66),
+			expedited_voting_period_seconds: msg
+				.as_ref()
+				.map(|msg| msg.expedited_voting_period_seconds)
+				.unwrap_or(600),
+			min_proposal_expiry_time_seconds: msg
+				.as_ref()
+				.map(|msg| msg.min_proposal_expiry_time_seconds)
+				.unwrap_or(60),
 			max_proposal_expiry_time_seconds: msg
 				.as_ref()
 				.map(|msg| { msg.max_proposal_expiry_time_seconds })
@@ -24,6 +45,20 @@ pub fn new_env_and_instantiate(msg: Option<CourtInstantiateMsg>) -> MutexGuard<'
 				.as_ref()
 				.map(|msg| { msg.execution_expiry_time_seconds })
 				.unwrap_or(3600),
+			quiet_ending_window_seconds: msg.as_ref().map(|msg| msg.quiet_ending_window_seconds).unwrap_or(0),
+			quiet_ending_extension_seconds: msg.as_ref().map(|msg| msg.quiet_ending_extension_seconds).unwrap_or(0),
+			max_quiet_ending_extensions: msg.as_ref().map(|msg| msg.max_quiet_ending_extensions).unwrap_or(0),
+			min_stake_age_seconds: msg.as_ref().map(|msg| msg.min_stake_age_seconds).unwrap_or(0),
+			max_stake_lock_seconds: msg.as_ref().map(|msg| msg.max_stake_lock_seconds).unwrap_or(0),
+			max_stake_lock_vote_multiplier_percent: msg
+				.as_ref()
+				.map(|msg| msg.max_stake_lock_vote_multiplier_percent)
+				.unwrap_or(0),
+			quadratic_voting: msg.as_ref().map(|msg| msg.quadratic_voting).unwrap_or(false),
+			quorum_supply: msg.as_ref().map(|msg| msg.quorum_supply).unwrap_or_default(),
+			max_user_votes: msg.as_ref().map(|msg| msg.max_user_votes).unwrap_or_default(),
+			max_user_votes_bps: msg.as_ref().map(|msg| msg.max_user_votes_bps).unwrap_or(0),
+			reveal_period_seconds: msg.as_ref().map(|msg| msg.reveal_period_seconds).unwrap_or(0),
 			last_config_change_timestamp_ms: env_deps.0.block.time.millis(),
 			admin: Addr::unchecked(ADMIN_ACCOUNT)
 		}
@@ -47,8 +82,25 @@ pub fn instantiate(
 		minimum_vote_proposal_percent: 10,
 		minimum_vote_turnout_percent: 20,
 		minimum_vote_pass_percent: 50,
+		veto_threshold_percent: 0,
+		optimistic_opposition_threshold_percent: 10,
+		expedited_minimum_vote_turnout_percent: 50,
+		expedited_minimum_vote_pass_percent: 66,
+		expedited_voting_period_seconds: 600,
+		min_proposal_expiry_time_seconds: 60,
 		max_proposal_expiry_time_seconds: 7200,
 		execution_expiry_time_seconds: 3600,
+		quiet_ending_window_seconds: 0,
+		quiet_ending_extension_seconds: 0,
+		max_quiet_ending_extensions: 0,
+		min_stake_age_seconds: 0,
+		max_stake_lock_seconds: 0,
+		max_stake_lock_vote_multiplier_percent: 0,
+		quadratic_voting: false,
+		quorum_supply: CourtQuorumSupply::Total,
+		max_user_votes: 0u128.into(),
+		max_user_votes_bps: 0,
+		reveal_period_seconds: 0,
 		vote_share_name: "Test Votes".into(),
 		vote_share_symbol: "TST".into(),
 		vote_share_description: "Test vortessadbjhk,sdfgvgjhlksdfjhgbksdv".into(),
@@ -100,6 +152,34 @@ pub fn query_proposal_amount(env_deps: &(Env, SeiMockEnvDeps)) -> Result<u32, Co
 		CourtQueryMsg::ProposalAmount,
 	)?)?)
 }
+pub fn query_stargate_allowlist(env_deps: &(Env, SeiMockEnvDeps)) -> Result<Vec<String>, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::StargateAllowlist,
+	)?)?)
+}
+pub fn query_optimistic_allowlist(
+	env_deps: &(Env, SeiMockEnvDeps),
+) -> Result<CourtQueryResponseOptimisticAllowlist, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::OptimisticAllowlist,
+	)?)?)
+}
+pub fn query_proposal_tracks(
+	env_deps: &(Env, SeiMockEnvDeps),
+) -> Result<Vec<CourtProposalTrackJsonable>, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::ProposalTracks,
+	)?)?)
+}
 pub fn query_get_proposal(
 	env_deps: &(Env, SeiMockEnvDeps),
 	id: u32,
@@ -108,7 +188,26 @@ pub fn query_get_proposal(
 	Ok(from_json(crate::contract::query(
 		env_deps.1.as_ref().into_empty(),
 		env,
-		CourtQueryMsg::GetProposal { id },
+		CourtQueryMsg::GetProposal {
+			id,
+			decode: None,
+			at_time_ms: None,
+		},
+	)?)?)
+}
+pub fn query_get_proposal_decoded(
+	env_deps: &(Env, SeiMockEnvDeps),
+	id: u32,
+) -> Result<Option<CourtQueryResponseTransactionProposal>, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::GetProposal {
+			id,
+			decode: Some(true),
+			at_time_ms: None,
+		},
 	)?)?)
 }
 pub fn query_get_proposals(
@@ -118,13 +217,86 @@ pub fn query_get_proposals(
 	descending: bool,
 ) -> Result<Vec<CourtQueryResponseTransactionProposal>, CourtContractError> {
 	let env = env_deps.0.clone();
-	Ok(from_json(crate::contract::query(
+	let page: CourtQueryResponseProposals = from_json(crate::contract::query(
 		env_deps.1.as_ref().into_empty(),
 		env,
 		CourtQueryMsg::GetProposals {
 			skip,
 			limit,
 			descending,
+			after: None,
+			filter: None,
+			omit_messages: None,
+			at_time_ms: None,
+		},
+	)?)?;
+	Ok(page.proposals)
+}
+pub fn query_get_proposals_filtered(
+	env_deps: &(Env, SeiMockEnvDeps),
+	after: Option<u32>,
+	limit: Option<u32>,
+	descending: bool,
+	filter: CourtQueryProposalFilter,
+	omit_messages: bool,
+) -> Result<CourtQueryResponseProposals, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::GetProposals {
+			skip: None,
+			limit,
+			descending,
+			after,
+			filter: Some(filter),
+			omit_messages: Some(omit_messages),
+			at_time_ms: None,
+		},
+	)?)?)
+}
+pub fn query_get_proposal_at_time(
+	env_deps: &(Env, SeiMockEnvDeps),
+	id: u32,
+	at_time_ms: u64,
+) -> Result<Option<CourtQueryResponseTransactionProposal>, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::GetProposal {
+			id,
+			decode: None,
+			at_time_ms: Some(at_time_ms),
+		},
+	)?)?)
+}
+pub fn query_proposal_tally(
+	env_deps: &(Env, SeiMockEnvDeps),
+	id: u32,
+	at_time_ms: Option<u64>,
+) -> Result<Option<CourtQueryResponseProposalTally>, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::ProposalTally { id, at_time_ms },
+	)?)?)
+}
+pub fn query_simulate_proposal(
+	env_deps: &(Env, SeiMockEnvDeps),
+	msgs: Vec<ProposedCourtMsgJsonable>,
+	expiry_time_seconds: u32,
+	proposer: &str,
+) -> Result<CourtQueryResponseSimulatedProposal, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::SimulateProposal {
+			msgs,
+			expiry_time_seconds,
+			proposer: Addr::unchecked(proposer),
 		},
 	)?)?)
 }
@@ -156,6 +328,21 @@ pub fn query_user_vote_info(
 		},
 	)?)?)
 }
+pub fn query_signed_vote_nonce(
+	env_deps: &(Env, SeiMockEnvDeps),
+	user: &str,
+	proposal_id: u32,
+) -> Result<u64, CourtContractError> {
+	let env = env_deps.0.clone();
+	Ok(from_json(crate::contract::query(
+		env_deps.1.as_ref().into_empty(),
+		env,
+		CourtQueryMsg::SignedVoteNonce {
+			user: Addr::unchecked(user),
+			proposal_id,
+		},
+	)?)?)
+}
 pub fn query_get_users_with_active_proposals(
 	env_deps: &(Env, SeiMockEnvDeps),
 	after: Option<CourtQueryUserWithActiveProposal>,
@@ -375,6 +562,88 @@ pub fn execute_allow_new_proposals(env_deps: &mut (Env, SeiMockEnvDeps), sender:
 	.unwrap();
 }
 
+pub fn execute_allow_stargate_messages(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: Option<&str>,
+	type_urls: &[&str],
+	allowed: bool,
+) {
+	execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender.unwrap_or(ADMIN_ACCOUNT)),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowStargateMessages {
+			type_urls: type_urls.iter().map(|type_url| type_url.to_string()).collect(),
+			allowed,
+		}),
+	)
+	.unwrap();
+}
+
+pub fn execute_allow_optimistic_proposals(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: Option<&str>,
+	proposers: &[&str],
+	msg_kinds: &[&str],
+	allowed: bool,
+) {
+	execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender.unwrap_or(ADMIN_ACCOUNT)),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::AllowOptimisticProposals {
+			proposers: proposers.iter().map(|proposer| Addr::unchecked(*proposer)).collect(),
+			msg_kinds: msg_kinds.iter().map(|msg_kind| msg_kind.to_string()).collect(),
+			allowed,
+		}),
+	)
+	.unwrap();
+}
+
+/// A track with the same voting config as the default instantiation, which matches any message
+pub fn new_proposal_track(name: &str) -> CourtProposalTrackJsonable {
+	CourtProposalTrackJsonable {
+		name: name.into(),
+		minimum_vote_proposal_percent: 10,
+		minimum_vote_turnout_percent: 20,
+		minimum_vote_pass_percent: 50,
+		max_proposal_expiry_time_seconds: 7200,
+		execution_expiry_time_seconds: 3600,
+		timelock_seconds: 0,
+		msg_kinds: vec![],
+		targets: vec![],
+		min_amount: None,
+	}
+}
+
+pub fn execute_set_proposal_track(env_deps: &mut (Env, SeiMockEnvDeps), track: CourtProposalTrackJsonable) {
+	execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::SetProposalTrack { track }),
+	)
+	.unwrap();
+}
+
+pub fn execute_remove_proposal_track(env_deps: &mut (Env, SeiMockEnvDeps), name: &str) {
+	execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::RemoveProposalTrack { name: name.into() }),
+	)
+	.unwrap();
+}
+
 pub fn execute_stake_votes(env_deps: &mut (Env, SeiMockEnvDeps), sender: &str, amount: u128) {
 	let vote_shares_denom = query_denom(&env_deps).unwrap().votes;
 	let previous_stake_amount = query_user_stats(&env_deps, sender).unwrap().staked_votes.u128();
@@ -456,6 +725,10 @@ pub fn execute_propose_transaction(
 		CourtExecuteMsg::ProposeTransaction {
 			msgs,
 			expiry_time_seconds,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	)
 	.unwrap();
@@ -476,6 +749,178 @@ pub fn execute_propose_transaction(
 	);
 }
 
+pub fn execute_propose_optimistic_transaction(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: &str,
+	msgs: Vec<ProposedCourtMsgJsonable>,
+	expiry_time_seconds: u32,
+) {
+	let user_staked_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
+	let new_proposal_id = query_proposal_amount(&env_deps).unwrap();
+	let execute_result = execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs,
+			expiry_time_seconds,
+			metadata: None,
+			optimistic: true,
+			expedited: false,
+			private: false,
+		},
+	)
+	.unwrap();
+
+	assert_eq!(execute_result.messages.len(), 0);
+	assert_eq!(
+		execute_result.events,
+		vec![
+			cosmwasm_std::Event::new("proposal")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("proposer", sender)
+				.add_attribute("optimistic", "true"),
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("voter", sender)
+				.add_attribute("votes", user_staked_votes)
+				.add_attribute("vote", "approve")
+		]
+	);
+}
+
+pub fn execute_propose_expedited_transaction(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: &str,
+	msgs: Vec<ProposedCourtMsgJsonable>,
+	expiry_time_seconds: u32,
+) {
+	let user_staked_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
+	let new_proposal_id = query_proposal_amount(&env_deps).unwrap();
+	let execute_result = execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs,
+			expiry_time_seconds,
+			metadata: None,
+			optimistic: false,
+			expedited: true,
+			private: false,
+		},
+	)
+	.unwrap();
+
+	assert_eq!(execute_result.messages.len(), 0);
+	assert_eq!(
+		execute_result.events,
+		vec![
+			cosmwasm_std::Event::new("proposal")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("proposer", sender)
+				.add_attribute("expedited", "true"),
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("voter", sender)
+				.add_attribute("votes", user_staked_votes)
+				.add_attribute("vote", "approve")
+		]
+	);
+}
+
+pub fn execute_propose_multiple_choice(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: &str,
+	options: Vec<CourtProposalOption>,
+	expiry_time_seconds: u32,
+) {
+	let user_staked_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
+	let new_proposal_id = query_proposal_amount(&env_deps).unwrap();
+	let option_count = options.len();
+	let execute_result = execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeMultipleChoice {
+			options,
+			expiry_time_seconds,
+			metadata: None,
+		},
+	)
+	.unwrap();
+
+	assert_eq!(execute_result.messages.len(), 0);
+	assert_eq!(
+		execute_result.events,
+		vec![
+			cosmwasm_std::Event::new("proposal")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("proposer", sender)
+				.add_attribute("option_count", option_count.to_string()),
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("voter", sender)
+				.add_attribute("votes", user_staked_votes)
+				.add_attribute("vote", "abstain")
+		]
+	);
+}
+
+pub fn execute_propose_signal(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: &str,
+	metadata: TransactionProposalMetadataJsonable,
+	expiry_time_seconds: u32,
+) {
+	let user_staked_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
+	let new_proposal_id = query_proposal_amount(&env_deps).unwrap();
+	let execute_result = execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeSignal {
+			metadata: metadata.clone(),
+			expiry_time_seconds,
+		},
+	)
+	.unwrap();
+	let mut proposal_event = cosmwasm_std::Event::new("proposal")
+		.add_attribute("proposal_id", new_proposal_id.to_string())
+		.add_attribute("proposer", sender)
+		.add_attribute("title", metadata.title);
+	if !metadata.description.is_empty() {
+		proposal_event = proposal_event.add_attribute("description", metadata.description);
+	}
+	if let Some(content_uri) = metadata.content_uri {
+		proposal_event = proposal_event.add_attribute("content_uri", content_uri);
+	}
+	if let Some(content_sha256) = metadata.content_sha256 {
+		proposal_event = proposal_event.add_attribute("content_sha256", content_sha256.to_hex());
+	}
+
+	assert_eq!(execute_result.messages.len(), 0);
+	assert_eq!(
+		execute_result.events,
+		vec![
+			proposal_event,
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", new_proposal_id.to_string())
+				.add_attribute("voter", sender)
+				.add_attribute("votes", user_staked_votes)
+				.add_attribute("vote", "approve")
+		]
+	);
+}
+
 pub fn execute_vote(env_deps: &mut (Env, SeiMockEnvDeps), sender: &str, id: u32, vote: CourtUserVoteStatus) {
 	let user_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
 	execute(
@@ -491,7 +936,67 @@ pub fn execute_vote(env_deps: &mut (Env, SeiMockEnvDeps), sender: &str, id: u32,
 		query_user_vote_info(&env_deps, sender, id),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: user_votes,
-			vote
+			vote,
+			split: None,
+			choice: None,
+		})
+	);
+}
+pub fn execute_vote_weighted(
+	env_deps: &mut (Env, SeiMockEnvDeps),
+	sender: &str,
+	id: u32,
+	approve: u16,
+	oppose: u16,
+	abstain: u16,
+) {
+	let user_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
+	execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteWeighted {
+			id,
+			approve,
+			oppose,
+			abstain,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		query_user_vote_info(&env_deps, sender, id),
+		Ok(CourtUserVoteInfoJsonable {
+			active_votes: user_votes,
+			vote: CourtUserVoteStatus::Split,
+			split: Some(CourtUserVoteSplit {
+				approve_bps: approve,
+				oppose_bps: oppose,
+				abstain_bps: abstain,
+			}),
+			choice: None,
+		})
+	);
+}
+pub fn execute_vote_choice(env_deps: &mut (Env, SeiMockEnvDeps), sender: &str, id: u32, option: u8) {
+	let user_votes = query_user_stats(&env_deps, sender).unwrap().staked_votes;
+	execute(
+		env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(sender),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteChoice { id, option },
+	)
+	.unwrap();
+	assert_eq!(
+		query_user_vote_info(&env_deps, sender, id),
+		Ok(CourtUserVoteInfoJsonable {
+			active_votes: user_votes,
+			vote: CourtUserVoteStatus::Choice,
+			split: None,
+			choice: Some(option),
 		})
 	);
 }
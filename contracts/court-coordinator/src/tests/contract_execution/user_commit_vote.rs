@@ -0,0 +1,397 @@
+use cosmwasm_std::to_json_vec;
+use sha2::{Digest, Sha256};
+
+use super::*;
+
+fn commitment_hash(proposal_id: u32, voter: &str, vote: CourtUserVoteStatus, salt: &[u8]) -> Binary {
+	Sha256::digest(
+		to_json_vec(&CourtVoteCommitmentPayload {
+			proposal_id,
+			voter: Addr::unchecked(voter),
+			vote,
+			salt: Binary::from(salt),
+		})
+		.unwrap(),
+	)
+	.to_vec()
+	.into()
+}
+
+fn new_env_with_private_proposal() -> MutexGuard<'static, (Env, SeiMockEnvDeps)> {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			reveal_period_seconds: Some(600),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 300000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 200000);
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: true,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events[0],
+		cosmwasm_std::Event::new("proposal")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("proposer", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("private", "true")
+	);
+	// The proposer's vote is hidden too
+	assert_eq!(
+		execute_response.events[1],
+		cosmwasm_std::Event::new("vote_committed")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("votes", "140000")
+	);
+	env_deps
+}
+
+#[test]
+pub fn private_not_allowed_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	let propose_private = |expedited: bool| CourtExecuteMsg::ProposeTransaction {
+		msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		expiry_time_seconds: 1200,
+		metadata: None,
+		optimistic: false,
+		expedited,
+		private: true,
+	};
+	// The reveal period is 0 by default
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			propose_private(false),
+		)
+		.unwrap_err(),
+		CourtContractError::PrivateProposalNotAllowed
+	);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			reveal_period_seconds: Some(600),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			propose_private(true),
+		)
+		.unwrap_err(),
+		CourtContractError::PrivateProposalNotAllowed
+	);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		propose_private(false),
+	)
+	.unwrap();
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(
+		proposal.info.reveal_expiry_timestamp_ms,
+		Some(env_deps.0.block.time.plus_seconds(1800).millis())
+	);
+}
+
+#[test]
+pub fn commit_and_reveal() {
+	let mut env_deps = new_env_with_private_proposal();
+	let sender = |account: &str| {
+		Some(MessageInfo {
+			sender: Addr::unchecked(account),
+			funds: vec![],
+		})
+	};
+
+	// Votes can't be cast openly
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_2),
+			CourtExecuteMsg::Vote {
+				id: 0,
+				vote: CourtUserVoteStatus::Approve,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::VoteMustBeCommitted(0)
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_2),
+			CourtExecuteMsg::CommitVote {
+				id: 0,
+				hash: Binary::from(b"not a hash"),
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteCommitment
+	);
+	// The proposer may replace their commitment like anyone else
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_1),
+		CourtExecuteMsg::CommitVote {
+			id: 0,
+			hash: commitment_hash(0, SHARES_HOLDER_ACCOUNT_1, CourtUserVoteStatus::Approve, b"salt1"),
+		},
+	)
+	.unwrap();
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_2),
+		CourtExecuteMsg::CommitVote {
+			id: 0,
+			hash: commitment_hash(0, SHARES_HOLDER_ACCOUNT_2, CourtUserVoteStatus::Approve, b"salt2"),
+		},
+	)
+	.unwrap();
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_3),
+		CourtExecuteMsg::CommitVote {
+			id: 0,
+			hash: commitment_hash(0, SHARES_HOLDER_ACCOUNT_3, CourtUserVoteStatus::Oppose, b"salt3"),
+		},
+	)
+	.unwrap();
+
+	// The committed votes are hidden, so the proposal can't pass early
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.status, TransactionProposalStatus::Pending);
+	assert_eq!(proposal.info.votes_for.u128(), 0);
+	assert_eq!(proposal.info.votes_against.u128(), 0);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_2),
+			CourtExecuteMsg::RevealVote {
+				id: 0,
+				vote: CourtUserVoteStatus::Approve,
+				salt: Binary::from(b"salt2"),
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::UnexpectedProposalStatus {
+			expected: TransactionProposalStatus::Revealing,
+			actual: TransactionProposalStatus::Pending
+		}
+	);
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Revealing
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_2),
+			CourtExecuteMsg::RevealVote {
+				id: 0,
+				vote: CourtUserVoteStatus::Oppose,
+				salt: Binary::from(b"salt2"),
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::VoteRevealMismatch
+	);
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_2),
+		CourtExecuteMsg::RevealVote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+			salt: Binary::from(b"salt2"),
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 0),
+		Ok(CourtUserVoteInfoJsonable {
+			active_votes: 300000u128.into(),
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
+		})
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_2),
+			CourtExecuteMsg::RevealVote {
+				id: 0,
+				vote: CourtUserVoteStatus::Approve,
+				salt: Binary::from(b"salt2"),
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::NoVoteCommitment(0)
+	);
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_1),
+		CourtExecuteMsg::RevealVote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+			salt: Binary::from(b"salt1"),
+		},
+	)
+	.unwrap();
+	// Votes can't be unstaked until they could no longer be revealed
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_3),
+			CourtExecuteMsg::DeactivateVotes {
+				user: None,
+				limit: None
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::ProposalNotFinalized(0)
+	);
+
+	// Only the revealed votes count
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(600);
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.status, TransactionProposalStatus::Passed);
+	assert_eq!(proposal.info.votes_for.u128(), 440000);
+	assert_eq!(proposal.info.votes_against.u128(), 0);
+	helpers::execute(&mut env_deps, None, CourtExecuteMsg::ExecuteProposal { id: 0 }).unwrap();
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_3),
+		CourtExecuteMsg::DeactivateVotes {
+			user: None,
+			limit: None,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("unrevealed_vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_3)
+			.add_attribute("votes", "200000")]
+	);
+	helpers::execute(&mut env_deps, sender(SHARES_HOLDER_ACCOUNT_3), CourtExecuteMsg::Unstake).unwrap();
+}
+
+#[test]
+pub fn proposer_vote_is_revealed_with_empty_salt() {
+	let mut env_deps = new_env_with_private_proposal();
+	let sender = |account: &str| {
+		Some(MessageInfo {
+			sender: Addr::unchecked(account),
+			funds: vec![],
+		})
+	};
+	let execution_expiry_ms = helpers::query_config(&env_deps).unwrap().execution_expiry_time_seconds as u64 * 1000;
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.votes_for.u128(), 0);
+	// No votes have been revealed yet, so it would be rejected
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Rejected);
+	// The execution window starts once the reveal period ends
+	assert_eq!(tally.voting_time_remaining_ms, 1200000);
+	assert_eq!(tally.execution_time_remaining_ms, 1800000 + execution_expiry_ms);
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_2),
+		CourtExecuteMsg::CommitVote {
+			id: 0,
+			hash: commitment_hash(0, SHARES_HOLDER_ACCOUNT_2, CourtUserVoteStatus::Approve, b"salt2"),
+		},
+	)
+	.unwrap();
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender(SHARES_HOLDER_ACCOUNT_1),
+			CourtExecuteMsg::RevealVote {
+				id: 0,
+				vote: CourtUserVoteStatus::Oppose,
+				salt: Binary::default(),
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::VoteRevealMismatch
+	);
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_1),
+		CourtExecuteMsg::RevealVote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+			salt: Binary::default(),
+		},
+	)
+	.unwrap();
+	helpers::execute(
+		&mut env_deps,
+		sender(SHARES_HOLDER_ACCOUNT_2),
+		CourtExecuteMsg::RevealVote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+			salt: Binary::from(b"salt2"),
+		},
+	)
+	.unwrap();
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.status, TransactionProposalStatus::Revealing);
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Passed);
+	assert_eq!(tally.votes_for.u128(), 440000);
+	assert_eq!(tally.execution_time_remaining_ms, 600000 + execution_expiry_ms);
+}
@@ -0,0 +1,249 @@
+use super::*;
+
+fn send_coin_options(option_count: u128) -> Vec<CourtProposalOption> {
+	(0..option_count)
+		.map(|i| CourtProposalOption {
+			label: format!("Option {i}"),
+			msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: (1337u128 + i).into(),
+			}],
+		})
+		.collect()
+}
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeMultipleChoice {
+			options: send_coin_options(2),
+			expiry_time_seconds: 7200,
+			metadata: None,
+		},
+	);
+}
+
+#[test]
+pub fn option_count_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	for option_count in [0, 1, 17] {
+		assert_eq!(
+			helpers::execute(
+				&mut env_deps,
+				Some(MessageInfo {
+					sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+					funds: vec![],
+				}),
+				CourtExecuteMsg::ProposeMultipleChoice {
+					options: send_coin_options(option_count),
+					expiry_time_seconds: 7200,
+					metadata: None,
+				},
+			)
+			.unwrap_err(),
+			CourtContractError::InvalidProposalOptionCount(16)
+		);
+	}
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, send_coin_options(16), 7200);
+}
+
+#[test]
+pub fn non_empty_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	let mut options = send_coin_options(3);
+	options[1].msgs.clear();
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ProposeMultipleChoice {
+				options,
+				expiry_time_seconds: 7200,
+				metadata: None,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::EmptyProposal
+	);
+}
+
+#[test]
+pub fn gov_vote_by_tally_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	let mut options = send_coin_options(3);
+	options[2]
+		.msgs
+		.push(ProposedCourtMsgJsonable::GovVoteByTally { proposal_id: 69 });
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ProposeMultipleChoice {
+				options,
+				expiry_time_seconds: 7200,
+				metadata: None,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::GovVoteByTallyNotAllowed {
+			proprety_name: "propose_multiple_choice.options[2].msgs[1]".into()
+		}
+	);
+}
+
+#[test]
+pub fn proposal_has_options() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, send_coin_options(2), 7200);
+
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0),
+		Ok(Some(CourtQueryResponseTransactionProposal {
+			proposal_id: 0,
+			status: TransactionProposalStatus::Pending,
+			info: TransactionProposalInfoJsonable {
+				proposer: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				votes_for: 0u128.into(),
+				votes_against: 0u128.into(),
+				votes_abstain: 500000u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
+				execution_status: TransactionProposalExecutionStatus::NotExecuted,
+				option_count: 2,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(7200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None,
+			},
+			messages: vec![],
+			decoded_messages: None,
+			options: vec![
+				CourtQueryResponseProposalOption {
+					label: "Option 0".into(),
+					messages: vec![ProposedCourtMsgJsonable::SendCoin {
+						to: RANDOM_ACCOUNT_1.into(),
+						denom: "usei".into(),
+						amount: 1337u128.into(),
+					}],
+					votes: 0u128.into(),
+				},
+				CourtQueryResponseProposalOption {
+					label: "Option 1".into(),
+					messages: vec![ProposedCourtMsgJsonable::SendCoin {
+						to: RANDOM_ACCOUNT_1.into(),
+						denom: "usei".into(),
+						amount: 1338u128.into(),
+					}],
+					votes: 0u128.into(),
+				},
+			],
+			metadata: None,
+		}))
+	);
+	// The proposer has yet to choose
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 0),
+		Ok(CourtUserVoteInfoJsonable {
+			active_votes: 500000u128.into(),
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
+		})
+	);
+	// Options are omitted along with messages
+	let proposals = helpers::query_get_proposals_filtered(&env_deps, None, None, false, Default::default(), true)
+		.unwrap()
+		.proposals;
+	assert_eq!(
+		proposals[0].options,
+		vec![
+			CourtQueryResponseProposalOption {
+				label: "Option 0".into(),
+				messages: vec![],
+				votes: 0u128.into(),
+			},
+			CourtQueryResponseProposalOption {
+				label: "Option 1".into(),
+				messages: vec![],
+				votes: 0u128.into(),
+			},
+		]
+	);
+}
+
+#[test]
+pub fn metadata_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	let metadata = TransactionProposalMetadataJsonable {
+		title: "Send some coins".into(),
+		description: "Which amount should be sent?".into(),
+		content_uri: Some("https://example.com/proposals/0".into()),
+		content_sha256: None,
+	};
+	let propose = |env_deps: &mut (Env, SeiMockEnvDeps), metadata: TransactionProposalMetadataJsonable| {
+		helpers::execute(
+			env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ProposeMultipleChoice {
+				options: send_coin_options(2),
+				expiry_time_seconds: 7200,
+				metadata: Some(metadata),
+			},
+		)
+	};
+	assert_eq!(
+		propose(
+			&mut env_deps,
+			TransactionProposalMetadataJsonable {
+				title: "".into(),
+				..metadata.clone()
+			}
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidProposalMetadata {
+			max_title_len: 128,
+			max_description_len: 4096,
+		}
+	);
+	let execute_result = propose(&mut env_deps, metadata.clone()).unwrap();
+	assert_eq!(
+		execute_result.events[0],
+		cosmwasm_std::Event::new("proposal")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("proposer", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("option_count", "2")
+			.add_attribute("title", "Send some coins")
+			.add_attribute("description", "Which amount should be sent?")
+			.add_attribute("content_uri", "https://example.com/proposals/0")
+	);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().metadata,
+		Some(metadata)
+	);
+}
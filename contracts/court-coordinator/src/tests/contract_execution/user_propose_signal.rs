@@ -0,0 +1,231 @@
+use super::*;
+
+fn text_metadata(title: &str, description: &str) -> TransactionProposalMetadataJsonable {
+	TransactionProposalMetadataJsonable {
+		title: title.into(),
+		description: description.into(),
+		..Default::default()
+	}
+}
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeSignal {
+			metadata: text_metadata("Should we do the thing?", ""),
+			expiry_time_seconds: 7200,
+		},
+	);
+}
+
+#[test]
+pub fn metadata_length_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	for (title, description) in [
+		(String::new(), String::new()),
+		("a".repeat(129), String::new()),
+		("Should we do the thing?".into(), "a".repeat(4097)),
+	] {
+		assert_eq!(
+			helpers::execute(
+				&mut env_deps,
+				Some(MessageInfo {
+					sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+					funds: vec![],
+				}),
+				CourtExecuteMsg::ProposeSignal {
+					metadata: text_metadata(&title, &description),
+					expiry_time_seconds: 7200,
+				},
+			)
+			.unwrap_err(),
+			CourtContractError::InvalidProposalMetadata {
+				max_title_len: 128,
+				max_description_len: 4096
+			}
+		);
+	}
+	helpers::execute_propose_signal(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		text_metadata(&"a".repeat(128), &"a".repeat(4096)),
+		7200,
+	);
+}
+
+#[test]
+pub fn proposal_has_metadata() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	helpers::execute_propose_signal(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		text_metadata("Should we do the thing?", "I think we should do the thing."),
+		7200,
+	);
+
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0),
+		Ok(Some(CourtQueryResponseTransactionProposal {
+			proposal_id: 0,
+			status: TransactionProposalStatus::Pending,
+			info: TransactionProposalInfoJsonable {
+				proposer: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				votes_for: 500000u128.into(),
+				votes_against: 0u128.into(),
+				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
+				execution_status: TransactionProposalExecutionStatus::NotExecuted,
+				option_count: 0,
+				leading_option: None,
+				is_signal: true,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(7200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None,
+			},
+			messages: vec![],
+			decoded_messages: None,
+			options: vec![],
+			metadata: Some(text_metadata(
+				"Should we do the thing?",
+				"I think we should do the thing."
+			)),
+		}))
+	);
+}
+
+#[test]
+pub fn passes_without_execution() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 200000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 100000);
+	helpers::execute_propose_signal(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		text_metadata("Should we do the thing?", ""),
+		1200,
+	);
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Oppose);
+
+	// Still pending as the remaining votes could reject it
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Pending));
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::DeactivateVotes {
+				user: Some(Addr::unchecked(SHARES_HOLDER_ACCOUNT_1)),
+				limit: None,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::ProposalNotFinalized(0)
+	);
+
+	// Signalling proposals don't enter the execution window
+	helpers::advance_time_to_execution_expiry(&mut env_deps, 0);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Passed));
+	assert_eq!(
+		helpers::execute(&mut env_deps, None, CourtExecuteMsg::ExecuteProposal { id: 0 }).unwrap_err(),
+		CourtContractError::SignalProposalNotExecutable
+	);
+
+	// Passed signalling proposals are finalized
+	helpers::execute_deactivate_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1);
+	helpers::execute_deactivate_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2);
+	assert_eq!(
+		helpers::query_get_proposals_filtered(
+			&env_deps,
+			None,
+			None,
+			false,
+			CourtQueryProposalFilter {
+				status: Some(TransactionProposalStatus::Passed),
+				..Default::default()
+			},
+			true
+		)
+		.unwrap()
+		.proposals
+		.len(),
+		1
+	);
+
+	// The outcome is remembered after the voting config changes
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			minimum_vote_proposal_percent: None,
+			minimum_vote_turnout_percent: None,
+			minimum_vote_pass_percent: Some(90),
+			veto_threshold_percent: None,
+			optimistic_opposition_threshold_percent: None,
+			expedited_minimum_vote_turnout_percent: None,
+			expedited_minimum_vote_pass_percent: None,
+			expedited_voting_period_seconds: None,
+			min_proposal_expiry_time_seconds: None,
+			max_proposal_expiry_time_seconds: None,
+			execution_expiry_time_seconds: None,
+			quiet_ending_window_seconds: None,
+			quiet_ending_extension_seconds: None,
+			max_quiet_ending_extensions: None,
+			min_stake_age_seconds: None,
+			max_stake_lock_seconds: None,
+			max_stake_lock_vote_multiplier_percent: None,
+			quadratic_voting: None,
+			quorum_supply: None,
+			max_user_votes: None,
+			max_user_votes_bps: None,
+			reveal_period_seconds: None,
+		})),
+	)
+	.unwrap();
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.status, TransactionProposalStatus::Passed);
+	assert!(proposal.messages.is_empty());
+}
+
+#[test]
+pub fn rejected() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 200000);
+	helpers::execute_propose_signal(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		text_metadata("Should we do the thing?", ""),
+		1200,
+	);
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Oppose);
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Rejected));
+	assert_eq!(
+		helpers::execute(&mut env_deps, None, CourtExecuteMsg::ExecuteProposal { id: 0 }).unwrap_err(),
+		CourtContractError::SignalProposalNotExecutable
+	);
+	helpers::execute_deactivate_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1);
+	helpers::execute_deactivate_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2);
+}
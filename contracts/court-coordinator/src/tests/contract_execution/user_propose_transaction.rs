@@ -1,3 +1,5 @@
+use cosmwasm_std::{Decimal, HexBinary, VoteOption, WeightedVoteOption};
+
 use super::*;
 
 #[test]
@@ -17,6 +19,10 @@ pub fn unfunded_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 86400,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 }
@@ -34,6 +40,10 @@ pub fn non_empty_check() {
 		CourtExecuteMsg::ProposeTransaction {
 			msgs: vec![],
 			expiry_time_seconds: 86400,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 	assert!(execute_result.is_err_and(|err| { err.to_string().contains("Proposal must have at least one message") }));
@@ -53,6 +63,12 @@ pub fn expire_time_check() {
 			.max_proposal_expiry_time_seconds,
 		7200
 	);
+	assert_eq!(
+		helpers::query_config(&env_deps)
+			.unwrap()
+			.min_proposal_expiry_time_seconds,
+		60
+	);
 	assert_eq!(helpers::get_known_vote_supply(&env_deps), 1000000);
 
 	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
@@ -69,9 +85,33 @@ pub fn expire_time_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 7201,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 	assert!(execute_result.is_err_and(|err| { err.to_string().contains("Proposal takes too long to expire") }));
+	let execute_result = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+			expiry_time_seconds: 59,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
+		},
+	);
+	assert!(execute_result.is_err_and(|err| { err.to_string().contains("Proposal expires too soon") }));
 	helpers::execute(
 		&mut env_deps,
 		Some(MessageInfo {
@@ -85,6 +125,10 @@ pub fn expire_time_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 7200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	)
 	.unwrap();
@@ -120,6 +164,10 @@ pub fn minimum_vote_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 	assert!(execute_result.is_err_and(|err| {
@@ -149,14 +197,27 @@ pub fn minimum_vote_check() {
 				votes_for: 100000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	assert_eq!(
@@ -165,7 +226,9 @@ pub fn minimum_vote_check() {
 			user: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
 			info: CourtUserVoteInfoJsonable {
 				active_votes: 100000u128.into(),
-				vote: CourtUserVoteStatus::Approve
+				vote: CourtUserVoteStatus::Approve,
+				split: None,
+				choice: None,
 			}
 		}])
 	);
@@ -179,14 +242,27 @@ pub fn minimum_vote_check() {
 				votes_for: 100000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}])
 	);
 	assert_eq!(helpers::query_proposal_amount(&env_deps), Ok(1));
@@ -209,21 +285,27 @@ pub fn minimum_vote_check() {
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 0),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 100000u128.into(),
-			vote: CourtUserVoteStatus::Approve
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 0),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 0u128.into(),
-			vote: CourtUserVoteStatus::Abstain
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 1),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 0u128.into(),
-			vote: CourtUserVoteStatus::Abstain
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
 		})
 	);
 }
@@ -288,14 +370,27 @@ pub fn multiple() {
 				votes_for: 100000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	assert_eq!(
@@ -308,14 +403,27 @@ pub fn multiple() {
 				votes_for: 100000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_3.into(),
 				denom: "usei".into(),
 				amount: 1338u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	assert_eq!(
@@ -328,14 +436,27 @@ pub fn multiple() {
 				votes_for: 150000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1400).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1400).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_2.into(),
 				denom: "usei".into(),
 				amount: 1339u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	assert_eq!(
@@ -344,7 +465,9 @@ pub fn multiple() {
 			user: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
 			info: CourtUserVoteInfoJsonable {
 				active_votes: 100000u128.into(),
-				vote: CourtUserVoteStatus::Approve
+				vote: CourtUserVoteStatus::Approve,
+				split: None,
+				choice: None,
 			}
 		}])
 	);
@@ -354,7 +477,9 @@ pub fn multiple() {
 			user: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
 			info: CourtUserVoteInfoJsonable {
 				active_votes: 100000u128.into(),
-				vote: CourtUserVoteStatus::Approve
+				vote: CourtUserVoteStatus::Approve,
+				split: None,
+				choice: None,
 			}
 		}])
 	);
@@ -364,7 +489,9 @@ pub fn multiple() {
 			user: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
 			info: CourtUserVoteInfoJsonable {
 				active_votes: 150000u128.into(),
-				vote: CourtUserVoteStatus::Approve
+				vote: CourtUserVoteStatus::Approve,
+				split: None,
+				choice: None,
 			}
 		}])
 	);
@@ -380,14 +507,27 @@ pub fn multiple() {
 					votes_for: 100000u128.into(),
 					votes_against: 0u128.into(),
 					votes_abstain: 0u128.into(),
+					votes_veto: 0u128.into(),
+					quorum_supply: 1000000u128.into(),
 					execution_status: TransactionProposalExecutionStatus::NotExecuted,
-					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+					option_count: 0,
+					leading_option: None,
+					is_signal: false,
+					is_optimistic: false,
+					track: None,
+					extensions: 0,
+					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+					expedited_expiry_timestamp_ms: None,
+					reveal_expiry_timestamp_ms: None
 				},
 				messages: vec![ProposedCourtMsgJsonable::SendCoin {
 					to: RANDOM_ACCOUNT_1.into(),
 					denom: "usei".into(),
 					amount: 1337u128.into()
-				}]
+				}],
+				decoded_messages: None,
+				options: vec![],
+				metadata: None,
 			},
 			CourtQueryResponseTransactionProposal {
 				proposal_id: 1,
@@ -397,14 +537,27 @@ pub fn multiple() {
 					votes_for: 100000u128.into(),
 					votes_against: 0u128.into(),
 					votes_abstain: 0u128.into(),
+					votes_veto: 0u128.into(),
+					quorum_supply: 1000000u128.into(),
 					execution_status: TransactionProposalExecutionStatus::NotExecuted,
-					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis()
+					option_count: 0,
+					leading_option: None,
+					is_signal: false,
+					is_optimistic: false,
+					track: None,
+					extensions: 0,
+					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis(),
+					expedited_expiry_timestamp_ms: None,
+					reveal_expiry_timestamp_ms: None
 				},
 				messages: vec![ProposedCourtMsgJsonable::SendCoin {
 					to: RANDOM_ACCOUNT_3.into(),
 					denom: "usei".into(),
 					amount: 1338u128.into()
-				}]
+				}],
+				decoded_messages: None,
+				options: vec![],
+				metadata: None,
 			},
 			CourtQueryResponseTransactionProposal {
 				proposal_id: 2,
@@ -414,14 +567,27 @@ pub fn multiple() {
 					votes_for: 150000u128.into(),
 					votes_against: 0u128.into(),
 					votes_abstain: 0u128.into(),
+					votes_veto: 0u128.into(),
+					quorum_supply: 1000000u128.into(),
 					execution_status: TransactionProposalExecutionStatus::NotExecuted,
-					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1400).millis()
+					option_count: 0,
+					leading_option: None,
+					is_signal: false,
+					is_optimistic: false,
+					track: None,
+					extensions: 0,
+					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1400).millis(),
+					expedited_expiry_timestamp_ms: None,
+					reveal_expiry_timestamp_ms: None
 				},
 				messages: vec![ProposedCourtMsgJsonable::SendCoin {
 					to: RANDOM_ACCOUNT_2.into(),
 					denom: "usei".into(),
 					amount: 1339u128.into()
-				}]
+				}],
+				decoded_messages: None,
+				options: vec![],
+				metadata: None,
 			}
 		])
 	);
@@ -435,14 +601,27 @@ pub fn multiple() {
 				votes_for: 100000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_3.into(),
 				denom: "usei".into(),
 				amount: 1338u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}])
 	);
 	assert_eq!(
@@ -456,14 +635,27 @@ pub fn multiple() {
 					votes_for: 150000u128.into(),
 					votes_against: 0u128.into(),
 					votes_abstain: 0u128.into(),
+					votes_veto: 0u128.into(),
+					quorum_supply: 1000000u128.into(),
 					execution_status: TransactionProposalExecutionStatus::NotExecuted,
-					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1400).millis()
+					option_count: 0,
+					leading_option: None,
+					is_signal: false,
+					is_optimistic: false,
+					track: None,
+					extensions: 0,
+					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1400).millis(),
+					expedited_expiry_timestamp_ms: None,
+					reveal_expiry_timestamp_ms: None
 				},
 				messages: vec![ProposedCourtMsgJsonable::SendCoin {
 					to: RANDOM_ACCOUNT_2.into(),
 					denom: "usei".into(),
 					amount: 1339u128.into()
-				}]
+				}],
+				decoded_messages: None,
+				options: vec![],
+				metadata: None,
 			},
 			CourtQueryResponseTransactionProposal {
 				proposal_id: 1,
@@ -473,14 +665,27 @@ pub fn multiple() {
 					votes_for: 100000u128.into(),
 					votes_against: 0u128.into(),
 					votes_abstain: 0u128.into(),
+					votes_veto: 0u128.into(),
+					quorum_supply: 1000000u128.into(),
 					execution_status: TransactionProposalExecutionStatus::NotExecuted,
-					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis()
+					option_count: 0,
+					leading_option: None,
+					is_signal: false,
+					is_optimistic: false,
+					track: None,
+					extensions: 0,
+					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1300).millis(),
+					expedited_expiry_timestamp_ms: None,
+					reveal_expiry_timestamp_ms: None
 				},
 				messages: vec![ProposedCourtMsgJsonable::SendCoin {
 					to: RANDOM_ACCOUNT_3.into(),
 					denom: "usei".into(),
 					amount: 1338u128.into()
-				}]
+				}],
+				decoded_messages: None,
+				options: vec![],
+				metadata: None,
 			},
 			CourtQueryResponseTransactionProposal {
 				proposal_id: 0,
@@ -490,14 +695,27 @@ pub fn multiple() {
 					votes_for: 100000u128.into(),
 					votes_against: 0u128.into(),
 					votes_abstain: 0u128.into(),
+					votes_veto: 0u128.into(),
+					quorum_supply: 1000000u128.into(),
 					execution_status: TransactionProposalExecutionStatus::NotExecuted,
-					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+					option_count: 0,
+					leading_option: None,
+					is_signal: false,
+					is_optimistic: false,
+					track: None,
+					extensions: 0,
+					expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+					expedited_expiry_timestamp_ms: None,
+					reveal_expiry_timestamp_ms: None
 				},
 				messages: vec![ProposedCourtMsgJsonable::SendCoin {
 					to: RANDOM_ACCOUNT_1.into(),
 					denom: "usei".into(),
 					amount: 1337u128.into()
-				}]
+				}],
+				decoded_messages: None,
+				options: vec![],
+				metadata: None,
 			},
 		])
 	);
@@ -511,14 +729,27 @@ pub fn multiple() {
 				votes_for: 100000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		},])
 	);
 	assert_eq!(helpers::query_proposal_amount(&env_deps), Ok(3));
@@ -628,42 +859,54 @@ pub fn multiple() {
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 0),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 100000u128.into(),
-			vote: CourtUserVoteStatus::Approve
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 0),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 0u128.into(),
-			vote: CourtUserVoteStatus::Abstain
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 1),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 100000u128.into(),
-			vote: CourtUserVoteStatus::Approve
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 1),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 0u128.into(),
-			vote: CourtUserVoteStatus::Abstain
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 2),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 0u128.into(),
-			vote: CourtUserVoteStatus::Abstain
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 2),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 150000u128.into(),
-			vote: CourtUserVoteStatus::Approve
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
 		})
 	);
 }
@@ -698,6 +941,10 @@ pub fn token_recipiant_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 	assert!(execute_result.is_err_and(|err| {
@@ -718,6 +965,10 @@ pub fn token_recipiant_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
 		},
 	);
 	assert!(
@@ -736,10 +987,917 @@ pub fn token_recipiant_check() {
 				amount: 1337u128.into(),
 			}],
 			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
+		},
+	)
+	.unwrap();
+}
+
+#[test]
+pub fn gov_vote_weights_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	for options in [
+		vec![],
+		vec![WeightedVoteOption {
+			option: VoteOption::Yes,
+			weight: Decimal::percent(99),
+		}],
+		vec![
+			WeightedVoteOption {
+				option: VoteOption::Yes,
+				weight: Decimal::percent(50),
+			},
+			WeightedVoteOption {
+				option: VoteOption::Yes,
+				weight: Decimal::percent(50),
+			},
+		],
+	] {
+		let execute_result = helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ProposeTransaction {
+				msgs: vec![ProposedCourtMsgJsonable::GovVoteWeighted {
+					proposal_id: 1,
+					options,
+				}],
+				expiry_time_seconds: 1200,
+				metadata: None,
+				optimistic: false,
+				expedited: false,
+				private: false,
+			},
+		);
+		assert_eq!(
+			execute_result.unwrap_err(),
+			CourtContractError::InvalidGovVoteWeights {
+				proprety_name: "propose_transaction.msgs[0].options".into()
+			}
+		);
+	}
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::GovVoteWeighted {
+			proposal_id: 1,
+			options: vec![
+				WeightedVoteOption {
+					option: VoteOption::Yes,
+					weight: Decimal::percent(60),
+				},
+				WeightedVoteOption {
+					option: VoteOption::No,
+					weight: Decimal::percent(40),
+				},
+			],
+		}],
+		1200,
+	);
+}
+
+#[test]
+pub fn metadata_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	let msgs = vec![ProposedCourtMsgJsonable::SendCoin {
+		to: RANDOM_ACCOUNT_1.into(),
+		denom: "usei".into(),
+		amount: 1337u128.into(),
+	}];
+	let metadata = TransactionProposalMetadataJsonable {
+		title: "Send some coins".into(),
+		description: "Coins should be sent".into(),
+		content_uri: Some("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".into()),
+		content_sha256: Some(HexBinary::from([0x42; 32])),
+	};
+	for (metadata, expected_error) in [
+		(
+			TransactionProposalMetadataJsonable {
+				title: "".into(),
+				..metadata.clone()
+			},
+			CourtContractError::InvalidProposalMetadata {
+				max_title_len: 128,
+				max_description_len: 4096,
+			},
+		),
+		(
+			TransactionProposalMetadataJsonable {
+				description: "a".repeat(4097),
+				..metadata.clone()
+			},
+			CourtContractError::InvalidProposalMetadata {
+				max_title_len: 128,
+				max_description_len: 4096,
+			},
+		),
+		(
+			TransactionProposalMetadataJsonable {
+				content_uri: Some("".into()),
+				..metadata.clone()
+			},
+			CourtContractError::InvalidProposalContent {
+				max_content_uri_len: 512,
+			},
+		),
+		(
+			TransactionProposalMetadataJsonable {
+				content_sha256: Some(HexBinary::from([0x42; 31])),
+				..metadata.clone()
+			},
+			CourtContractError::InvalidProposalContent {
+				max_content_uri_len: 512,
+			},
+		),
+	] {
+		let execute_result = helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ProposeTransaction {
+				msgs: msgs.clone(),
+				expiry_time_seconds: 1200,
+				metadata: Some(metadata),
+				optimistic: false,
+				expedited: false,
+				private: false,
+			},
+		);
+		assert_eq!(execute_result.unwrap_err(), expected_error);
+	}
+
+	let execute_result = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs,
+			expiry_time_seconds: 1200,
+			metadata: Some(metadata.clone()),
+			optimistic: false,
+			expedited: false,
+			private: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_result.events[0],
+		cosmwasm_std::Event::new("proposal")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("proposer", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("title", "Send some coins")
+			.add_attribute("description", "Coins should be sent")
+			.add_attribute(
+				"content_uri",
+				"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+			)
+			.add_attribute("content_sha256", "42".repeat(32))
+	);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().metadata,
+		Some(metadata)
+	);
+}
+
+#[test]
+pub fn simulate_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	let msgs = vec![
+		ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
 		},
+		ProposedCourtMsgJsonable::TokenfactoryMint {
+			tokens: coin(420, &vote_denom),
+		},
+		ProposedCourtMsgJsonable::GovVoteByTally { proposal_id: 69 },
+	];
+
+	// Same checks as actually proposing
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 1200, SHARES_HOLDER_ACCOUNT_1).unwrap_err(),
+		CourtContractError::InsufficientVotesForProposal
+	);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 7201, SHARES_HOLDER_ACCOUNT_1).unwrap_err(),
+		CourtContractError::ProposalLivesTooLong
+	);
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, vec![], 1200, SHARES_HOLDER_ACCOUNT_1).unwrap_err(),
+		CourtContractError::EmptyProposal
+	);
+
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 1200, SHARES_HOLDER_ACCOUNT_1),
+		Ok(CourtQueryResponseSimulatedProposal {
+			messages: msgs,
+			cosmos_msgs: vec![
+				cosmwasm_std::CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+					to_address: RANDOM_ACCOUNT_1.into(),
+					amount: vec![coin(1337, "usei")]
+				}),
+				cosmwasm_std::CosmosMsg::Custom(sei_cosmwasm::SeiMsg::MintTokens {
+					amount: coin(420, &vote_denom)
+				}),
+				// Only the proposer has voted
+				cosmwasm_std::CosmosMsg::Gov(cosmwasm_std::GovMsg::VoteWeighted {
+					proposal_id: 69,
+					options: vec![WeightedVoteOption {
+						option: VoteOption::Yes,
+						weight: Decimal::one(),
+					}]
+				}),
+			],
+			track: None,
+		})
+	);
+	// Nothing was actually done
+	assert_eq!(helpers::query_proposal_amount(&env_deps), Ok(0));
+	assert_eq!(helpers::get_known_vote_supply(&env_deps), 1000000);
+}
+
+#[test]
+pub fn simulate_gov_vote_by_tally_without_votes() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			minimum_vote_proposal_percent: Some(0),
+			..Default::default()
+		})),
 	)
 	.unwrap();
+	let msgs = vec![ProposedCourtMsgJsonable::GovVoteByTally { proposal_id: 69 }];
+	// The chain doesn't accept weighted votes without any options
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 1200, RANDOM_ACCOUNT_1)
+			.unwrap()
+			.cosmos_msgs,
+		vec![cosmwasm_std::CosmosMsg::Gov(cosmwasm_std::GovMsg::VoteWeighted {
+			proposal_id: 69,
+			options: vec![WeightedVoteOption {
+				option: VoteOption::Abstain,
+				weight: Decimal::one(),
+			}]
+		})]
+	);
 }
 
 #[test]
 pub fn user_vote_only_shares_check() {}
+
+#[test]
+pub fn decoded_messages() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let contract_addr = env_deps.0.contract.address.to_string();
+	helpers::execute_change_admin(&mut env_deps, None, &contract_addr);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_allow_stargate_messages(
+		&mut env_deps,
+		Some(&contract_addr),
+		&["/cosmos.bank.v1beta1.MsgSend"],
+		true,
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![
+			ProposedCourtMsgJsonable::ExecuteWasmContract {
+				contract: Addr::unchecked(RANDOM_CONTRACT),
+				msg: Binary::from(br#"{"do_the_thing":{"amount":"1337"}}"#),
+				funds: vec![coin(1337, "usei")],
+			},
+			ProposedCourtMsgJsonable::UpgradeWasmContract {
+				contract: Addr::unchecked(RANDOM_CONTRACT),
+				new_code_id: 420,
+				msg: Binary::from(b"not json"),
+			},
+			ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			},
+			// MsgSend { from_address: "sei1a", to_address: "sei1b", amount: [{ denom: "usei", amount: "5" }] }
+			ProposedCourtMsgJsonable::Stargate {
+				type_url: "/cosmos.bank.v1beta1.MsgSend".into(),
+				value: HexBinary::from_hex("0a057365693161120573656931621a090a0475736569120135")
+					.unwrap()
+					.into(),
+			},
+			ProposedCourtMsgJsonable::GovVote {
+				proposal_id: 69,
+				option: VoteOption::NoWithVeto,
+			},
+		],
+		1200,
+	);
+	// Not included unless requested
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.decoded_messages,
+		None
+	);
+	assert_eq!(
+		helpers::query_get_proposal_decoded(&env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.decoded_messages,
+		Some(vec![
+			ProposedCourtMsgDecoded {
+				msg_json: Some(from_json(br#"{"do_the_thing":{"amount":"1337"}}"#).unwrap()),
+				amounts: vec!["0.001337 sei".into()],
+			},
+			ProposedCourtMsgDecoded {
+				msg_json: None,
+				amounts: vec![],
+			},
+			ProposedCourtMsgDecoded {
+				msg_json: None,
+				amounts: vec!["0.001337 sei".into()],
+			},
+			ProposedCourtMsgDecoded {
+				msg_json: Some(from_json(br#"{"1":"sei1a","2":"sei1b","3":{"1":"usei","2":"5"}}"#).unwrap()),
+				amounts: vec![],
+			},
+			ProposedCourtMsgDecoded {
+				msg_json: Some(from_json(br#"{"proposal_id":69,"option":"no_with_veto"}"#).unwrap()),
+				amounts: vec![],
+			},
+		])
+	);
+}
+
+#[test]
+pub fn decoded_stargate_messages_are_bounded() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let contract_addr = env_deps.0.contract.address.to_string();
+	helpers::execute_change_admin(&mut env_deps, None, &contract_addr);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_allow_stargate_messages(
+		&mut env_deps,
+		Some(&contract_addr),
+		&["/cosmos.bank.v1beta1.MsgSend"],
+		true,
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![
+			// Field 1 nested 6 levels deep, ending with the varint field 1 = 1
+			ProposedCourtMsgJsonable::Stargate {
+				type_url: "/cosmos.bank.v1beta1.MsgSend".into(),
+				value: HexBinary::from_hex("0a0c0a0a0a080a060a040a020801").unwrap().into(),
+			},
+			// The varint field 1 = 1 repeated past the size limit
+			ProposedCourtMsgJsonable::Stargate {
+				type_url: "/cosmos.bank.v1beta1.MsgSend".into(),
+				value: [8u8, 1].repeat(1025).into(),
+			},
+		],
+		1200,
+	);
+	assert_eq!(
+		helpers::query_get_proposal_decoded(&env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.decoded_messages,
+		Some(vec![
+			ProposedCourtMsgDecoded {
+				msg_json: Some(from_json(br#"{"1":{"1":{"1":{"1":{"1":"CgIIAQ=="}}}}}"#).unwrap()),
+				amounts: vec![],
+			},
+			ProposedCourtMsgDecoded {
+				msg_json: None,
+				amounts: vec![],
+			},
+		])
+	);
+}
+
+#[test]
+pub fn filtered_proposals() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 150000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::ExecuteWasmContract {
+			contract: Addr::unchecked(RANDOM_CONTRACT),
+			msg: Binary::from(br#"{"do_the_thing":{}}"#),
+			funds: vec![],
+		}],
+		1200,
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_2,
+		vec![ProposedCourtMsgJsonable::ClearWasmContractAdmin {
+			contract: Addr::unchecked(RANDOM_CONTRACT),
+		}],
+		1200,
+	);
+	let filtered_ids = |env_deps: &(Env, SeiMockEnvDeps),
+	                    after: Option<u32>,
+	                    limit: Option<u32>,
+	                    descending: bool,
+	                    filter: CourtQueryProposalFilter| {
+		helpers::query_get_proposals_filtered(env_deps, after, limit, descending, filter, true)
+			.unwrap()
+			.proposals
+			.into_iter()
+			.map(|proposal| proposal.proposal_id)
+			.collect::<Vec<u32>>()
+	};
+
+	// No filters
+	assert_eq!(
+		filtered_ids(&env_deps, None, None, false, Default::default()),
+		vec![0, 1, 2]
+	);
+	assert_eq!(
+		filtered_ids(&env_deps, Some(0), None, false, Default::default()),
+		vec![1, 2]
+	);
+	assert_eq!(
+		filtered_ids(&env_deps, Some(2), None, true, Default::default()),
+		vec![1, 0]
+	);
+	assert_eq!(
+		filtered_ids(&env_deps, Some(0), Some(1), false, Default::default()),
+		vec![1]
+	);
+
+	// By proposer
+	let by_proposer_1 = CourtQueryProposalFilter {
+		proposer: Some(Addr::unchecked(SHARES_HOLDER_ACCOUNT_1)),
+		..Default::default()
+	};
+	assert_eq!(
+		filtered_ids(&env_deps, None, None, false, by_proposer_1.clone()),
+		vec![0, 1]
+	);
+	assert_eq!(
+		filtered_ids(&env_deps, None, None, true, by_proposer_1.clone()),
+		vec![1, 0]
+	);
+	assert_eq!(
+		filtered_ids(&env_deps, Some(0), None, false, by_proposer_1.clone()),
+		vec![1]
+	);
+	assert_eq!(filtered_ids(&env_deps, Some(1), None, true, by_proposer_1), vec![0]);
+
+	// By target contract
+	let by_target = CourtQueryProposalFilter {
+		target: Some(RANDOM_CONTRACT.into()),
+		..Default::default()
+	};
+	assert_eq!(
+		filtered_ids(&env_deps, None, None, false, by_target.clone()),
+		vec![1, 2]
+	);
+	assert_eq!(
+		filtered_ids(
+			&env_deps,
+			None,
+			None,
+			false,
+			CourtQueryProposalFilter {
+				proposer: Some(Addr::unchecked(SHARES_HOLDER_ACCOUNT_2)),
+				..by_target.clone()
+			}
+		),
+		vec![2]
+	);
+	assert_eq!(
+		filtered_ids(
+			&env_deps,
+			None,
+			None,
+			false,
+			CourtQueryProposalFilter {
+				target: Some(RANDOM_ACCOUNT_1.into()),
+				..Default::default()
+			}
+		),
+		vec![]
+	);
+
+	// By status
+	let pending = CourtQueryProposalFilter {
+		status: Some(TransactionProposalStatus::Pending),
+		..Default::default()
+	};
+	let rejected = CourtQueryProposalFilter {
+		status: Some(TransactionProposalStatus::Rejected),
+		..Default::default()
+	};
+	assert_eq!(
+		filtered_ids(&env_deps, None, None, false, pending.clone()),
+		vec![0, 1, 2]
+	);
+	assert_eq!(filtered_ids(&env_deps, None, None, false, rejected.clone()), vec![]);
+	helpers::advance_time_to_vote_end(&mut env_deps, 2);
+	assert_eq!(filtered_ids(&env_deps, None, None, false, pending), vec![]);
+	assert_eq!(filtered_ids(&env_deps, None, None, true, rejected), vec![2, 1, 0]);
+
+	// Messages can be omitted
+	assert!(
+		helpers::query_get_proposals_filtered(&env_deps, None, None, false, by_target.clone(), true)
+			.unwrap()
+			.proposals
+			.iter()
+			.all(|proposal| proposal.messages.is_empty())
+	);
+	assert_eq!(
+		helpers::query_get_proposals_filtered(&env_deps, None, None, false, by_target, false)
+			.unwrap()
+			.proposals
+			.into_iter()
+			.map(|proposal| proposal.messages)
+			.collect::<Vec<_>>(),
+		vec![
+			vec![ProposedCourtMsgJsonable::ExecuteWasmContract {
+				contract: Addr::unchecked(RANDOM_CONTRACT),
+				msg: Binary::from(br#"{"do_the_thing":{}}"#),
+				funds: vec![],
+			}],
+			vec![ProposedCourtMsgJsonable::ClearWasmContractAdmin {
+				contract: Addr::unchecked(RANDOM_CONTRACT),
+			}]
+		]
+	);
+}
+
+#[test]
+pub fn filtered_proposals_status_scan_limit() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	for expiry_time_seconds in [600, 1200, 600] {
+		helpers::execute_propose_transaction(
+			&mut env_deps,
+			SHARES_HOLDER_ACCOUNT_1,
+			vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+			expiry_time_seconds,
+		);
+	}
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	let rejected_ids = |env_deps: &(Env, SeiMockEnvDeps), after: Option<u32>, limit: Option<u32>| {
+		let page = helpers::query_get_proposals_filtered(
+			env_deps,
+			after,
+			limit,
+			false,
+			CourtQueryProposalFilter {
+				status: Some(TransactionProposalStatus::Rejected),
+				..Default::default()
+			},
+			true,
+		)
+		.unwrap();
+		(
+			page.proposals
+				.into_iter()
+				.map(|proposal| proposal.proposal_id)
+				.collect::<Vec<u32>>(),
+			page.next_after,
+		)
+	};
+	assert_eq!(rejected_ids(&env_deps, None, None), (vec![0, 2], None));
+	// Proposal 1 is still pending, but it counts towards the limit as it had to be checked
+	assert_eq!(rejected_ids(&env_deps, None, Some(2)), (vec![0], Some(1)));
+	assert_eq!(rejected_ids(&env_deps, Some(1), Some(2)), (vec![2], None));
+	// Pages may be empty, but the cursor still moves forward
+	assert_eq!(rejected_ids(&env_deps, Some(0), Some(1)), (vec![], Some(1)));
+	assert_eq!(rejected_ids(&env_deps, Some(1), Some(1)), (vec![2], None));
+}
+
+#[test]
+pub fn unfinalized_proposals_are_pruned() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	let propose = |env_deps: &mut (Env, SeiMockEnvDeps)| {
+		helpers::execute_propose_transaction(
+			env_deps,
+			SHARES_HOLDER_ACCOUNT_1,
+			vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+			1200,
+		);
+	};
+	let unfinalized_count = || {
+		crate::state::app::get_unfinalized_proposal_id_set()
+			.iter()
+			.unwrap()
+			.count()
+	};
+	for _ in 0..20 {
+		propose(&mut env_deps);
+	}
+	assert_eq!(unfinalized_count(), 20);
+
+	// All of them have been rejected, the oldest ones are checked whenever a new proposal is made
+	helpers::advance_time_to_vote_end(&mut env_deps, 19);
+	propose(&mut env_deps);
+	assert_eq!(unfinalized_count(), 13);
+	propose(&mut env_deps);
+	assert_eq!(unfinalized_count(), 6);
+	propose(&mut env_deps);
+	assert_eq!(unfinalized_count(), 3);
+
+	// The new proposals are pruned once they're finalized, even if no more are made
+	helpers::advance_time_to_vote_end(&mut env_deps, 22);
+	helpers::execute_deactivate_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1);
+	assert_eq!(unfinalized_count(), 0);
+}
+
+#[test]
+pub fn optimistic_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 100001);
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[SHARES_HOLDER_ACCOUNT_1], &[], true);
+	let msgs = vec![ProposedCourtMsgJsonable::SendCoin {
+		to: RANDOM_ACCOUNT_1.into(),
+		denom: "usei".into(),
+		amount: 1337u128.into(),
+	}];
+
+	helpers::execute_propose_optimistic_transaction(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, msgs.clone(), 1200);
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert!(proposal.info.is_optimistic);
+	// Still pending as the remaining votes could oppose it
+	assert_eq!(proposal.status, TransactionProposalStatus::Pending);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Passed);
+	assert_eq!(tally.votes_needed_to_pass, Some(0u128.into()));
+	assert_eq!(tally.votes_needed_to_block, Some(100001u128.into()));
+
+	// Passes without reaching the minimum turnout
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Passed));
+	helpers::advance_time_to_execution_expiry(&mut env_deps, 0);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::ExecutionExpired));
+
+	// Rejected once opposition exceeds the threshold, regardless of how many votes approve
+	helpers::execute_propose_optimistic_transaction(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, msgs.clone(), 1200);
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 1, CourtUserVoteStatus::Oppose);
+	let tally = helpers::query_proposal_tally(&env_deps, 1, None).unwrap().unwrap();
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Rejected);
+	assert_eq!(tally.votes_needed_to_pass, None);
+	assert_eq!(tally.votes_needed_to_block, Some(0u128.into()));
+	helpers::advance_time_to_vote_end(&mut env_deps, 1);
+	assert!(helpers::query_get_proposal(&env_deps, 1)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Rejected));
+}
+
+#[test]
+pub fn optimistic_early_pass_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 900000);
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[], &["send_coin"], true);
+
+	helpers::execute_propose_optimistic_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	// The uncast votes aren't enough to oppose it
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Passed));
+	assert_eq!(
+		helpers::query_proposal_tally(&env_deps, 0, None)
+			.unwrap()
+			.unwrap()
+			.votes_needed_to_block,
+		None
+	);
+}
+
+#[test]
+pub fn track_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_set_proposal_track(
+		&mut env_deps,
+		CourtProposalTrackJsonable {
+			minimum_vote_proposal_percent: 30,
+			minimum_vote_pass_percent: 75,
+			msg_kinds: vec!["send_coin".into()],
+			min_amount: Some(CourtProposalTrackAmount {
+				denom: "usei".into(),
+				amount: 1000u128.into(),
+			}),
+			..helpers::new_proposal_track("large-payments")
+		},
+	);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 200000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 100001);
+	let send_coin = |amount: u128| ProposedCourtMsgJsonable::SendCoin {
+		to: RANDOM_ACCOUNT_1.into(),
+		denom: "usei".into(),
+		amount: amount.into(),
+	};
+
+	// Proposals which don't match any track use the voting config
+	helpers::execute_propose_transaction(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, vec![send_coin(999)], 1200);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().info.track,
+		None
+	);
+
+	// Any message matching the track is enough
+	let msgs = vec![send_coin(999), send_coin(1000)];
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 1200, SHARES_HOLDER_ACCOUNT_1).unwrap_err(),
+		CourtContractError::InsufficientVotesForProposal
+	);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 1200, SHARES_HOLDER_ACCOUNT_1)
+			.unwrap()
+			.track,
+		Some(0)
+	);
+	helpers::execute_propose_transaction(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, msgs, 1200);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 1).unwrap().unwrap().info.track,
+		Some(0)
+	);
+
+	// 300000 / 400001 approval would pass with the voting config, but not with the track's
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 1, CourtUserVoteStatus::Oppose);
+	let tally = helpers::query_proposal_tally(&env_deps, 1, None).unwrap().unwrap();
+	assert_eq!(tally.minimum_vote_pass_percent, 75);
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Rejected);
+	helpers::advance_time_to_vote_end(&mut env_deps, 1);
+	assert!(helpers::query_get_proposal(&env_deps, 1)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Rejected));
+}
+
+#[test]
+pub fn expedited_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 400000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 150000);
+	helpers::execute_propose_expedited_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.info
+			.expedited_expiry_timestamp_ms,
+		Some(env_deps.0.block.time.plus_seconds(600).millis())
+	);
+	// 55% turnout and 72% approval meets the expedited requirements
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Oppose);
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(599);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Pending));
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(1);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Passed));
+}
+
+#[test]
+pub fn expedited_fallback_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 400000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 250000);
+	helpers::execute_propose_expedited_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	// 61% approval doesn't meet the expedited requirements...
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Oppose);
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(600);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Pending));
+	// ...but it's enough for a regular proposal
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert!(helpers::query_get_proposal(&env_deps, 0)
+		.unwrap()
+		.is_some_and(|proposal| proposal.status == TransactionProposalStatus::Passed));
+}
+
+#[test]
+pub fn expedited_not_allowed_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	helpers::execute_allow_optimistic_proposals(&mut env_deps, None, &[SHARES_HOLDER_ACCOUNT_1], &[], true);
+	let propose_expedited = |optimistic: bool| CourtExecuteMsg::ProposeTransaction {
+		msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		expiry_time_seconds: 1200,
+		metadata: None,
+		optimistic,
+		expedited: true,
+		private: false,
+	};
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			propose_expedited(true),
+		)
+		.unwrap_err(),
+		CourtContractError::ExpeditedProposalNotAllowed
+	);
+
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			expedited_voting_period_seconds: Some(0),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			propose_expedited(false),
+		)
+		.unwrap_err(),
+		CourtContractError::ExpeditedProposalNotAllowed
+	);
+}
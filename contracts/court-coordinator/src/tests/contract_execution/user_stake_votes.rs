@@ -23,7 +23,12 @@ pub fn info_updated() {
 	assert_eq!(
 		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
 		CourtUserStatsJsonable {
-			staked_votes: 0u128.into()
+			staked_votes: 0u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 0u128.into()
 		}
 	);
 
@@ -40,7 +45,12 @@ pub fn info_updated() {
 	assert_eq!(
 		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
 		CourtUserStatsJsonable {
-			staked_votes: 31337u128.into()
+			staked_votes: 31337u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 31337u128.into()
 		}
 	);
 	assert_eq!(
@@ -64,7 +74,12 @@ pub fn info_updated() {
 	assert_eq!(
 		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
 		CourtUserStatsJsonable {
-			staked_votes: 40000u128.into()
+			staked_votes: 40000u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 40000u128.into()
 		}
 	);
 	assert_eq!(
@@ -75,3 +90,421 @@ pub fn info_updated() {
 			.add_attribute("user_total_votes", 40000.to_string())]
 	);
 }
+
+#[test]
+pub fn min_stake_age_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			min_stake_age_seconds: Some(3600),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	let staked_timestamp_ms = env_deps.0.block.time.millis();
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![coin(500000, &vote_shares_denom)],
+		}),
+		CourtExecuteMsg::Stake,
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("stake")
+			.add_attribute("user", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("user_new_votes", 500000.to_string())
+			.add_attribute("user_total_votes", 500000.to_string())
+			.add_attribute("user_pending_votes", 500000.to_string())]
+	);
+	assert_eq!(
+		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
+		CourtUserStatsJsonable {
+			staked_votes: 0u128.into(),
+			pending_votes: 500000u128.into(),
+			pending_votes_timestamp_ms: staked_timestamp_ms,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 0u128.into()
+		}
+	);
+
+	// Newly staked votes can't be used to propose or vote yet
+	let msgs = vec![ProposedCourtMsgJsonable::SendCoin {
+		to: RANDOM_ACCOUNT_1.into(),
+		denom: "usei".into(),
+		amount: 1337u128.into(),
+	}];
+	assert_eq!(
+		helpers::query_simulate_proposal(&env_deps, msgs.clone(), 1200, SHARES_HOLDER_ACCOUNT_1).unwrap_err(),
+		CourtContractError::InsufficientVotesForProposal
+	);
+
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(3600);
+	assert_eq!(
+		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
+		CourtUserStatsJsonable {
+			staked_votes: 500000u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 500000u128.into()
+		}
+	);
+	helpers::execute_propose_transaction(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, msgs, 1200);
+
+	// Votes which have already aged stay usable while more are staked
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![coin(100000, &vote_shares_denom)],
+		}),
+		CourtExecuteMsg::Stake,
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
+		CourtUserStatsJsonable {
+			staked_votes: 500000u128.into(),
+			pending_votes: 100000u128.into(),
+			pending_votes_timestamp_ms: env_deps.0.block.time.millis(),
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 500000u128.into()
+		}
+	);
+}
+
+#[test]
+pub fn locked_stake_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			max_stake_lock_seconds: Some(1000),
+			max_stake_lock_vote_multiplier_percent: Some(400),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	let stake_locked = |env_deps: &mut (Env, SeiMockEnvDeps), lock_seconds: u32| {
+		helpers::execute(
+			env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![coin(100000, &vote_shares_denom)],
+			}),
+			CourtExecuteMsg::StakeLocked { lock_seconds },
+		)
+	};
+	assert_eq!(
+		stake_locked(&mut env_deps, 1001).unwrap_err(),
+		CourtContractError::StakeLockTooLong(1000)
+	);
+
+	// Half of the maximum lock gets half of the maximum bonus
+	let lock_expiry_timestamp_ms = env_deps.0.block.time.plus_seconds(500).millis();
+	let execute_response = stake_locked(&mut env_deps, 500).unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("stake")
+			.add_attribute("user", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("user_new_votes", 100000.to_string())
+			.add_attribute("user_total_votes", 100000.to_string())
+			.add_attribute("user_lock_expiry_timestamp_ms", lock_expiry_timestamp_ms.to_string())
+			.add_attribute("user_vote_multiplier_percent", 250.to_string())]
+	);
+	assert_eq!(
+		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
+		CourtUserStatsJsonable {
+			staked_votes: 100000u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms,
+			vote_multiplier_percent: 250,
+			effective_votes: 250000u128.into()
+		}
+	);
+
+	// The locked votes count for more when voting
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 300000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_2,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Vote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_1, 0)
+			.unwrap()
+			.active_votes
+			.u128(),
+		250000
+	);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.info
+			.votes_for
+			.u128(),
+		550000
+	);
+}
+
+#[test]
+pub fn locked_vote_multiplier_decays() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			max_stake_lock_seconds: Some(1000),
+			max_stake_lock_vote_multiplier_percent: Some(400),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![coin(100000, &vote_shares_denom)],
+		}),
+		CourtExecuteMsg::StakeLocked { lock_seconds: 1000 },
+	)
+	.unwrap();
+	let lock_expiry_timestamp_ms = env_deps.0.block.time.plus_seconds(1000).millis();
+	let multiplier_and_effective_votes = |env_deps: &mut (Env, SeiMockEnvDeps)| {
+		let user_stats = helpers::query_user_stats(env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap();
+		assert_eq!(user_stats.lock_expiry_timestamp_ms, lock_expiry_timestamp_ms);
+		(user_stats.vote_multiplier_percent, user_stats.effective_votes.u128())
+	};
+	assert_eq!(multiplier_and_effective_votes(&mut env_deps), (400, 400000));
+
+	// The bonus decreases as the lock expiry approaches
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(750);
+	assert_eq!(multiplier_and_effective_votes(&mut env_deps), (175, 175000));
+
+	// Staking more without extending the lock doesn't bring the bonus back
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	assert_eq!(multiplier_and_effective_votes(&mut env_deps), (175, 350000));
+
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(250);
+	assert_eq!(multiplier_and_effective_votes(&mut env_deps), (0, 200000));
+}
+
+#[test]
+pub fn locked_vote_multiplier_follows_config() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	let change_max_multiplier = |env_deps: &mut (Env, SeiMockEnvDeps), max_stake_lock_vote_multiplier_percent: u16| {
+		helpers::execute(
+			env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(ADMIN_ACCOUNT),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+				max_stake_lock_seconds: Some(1000),
+				max_stake_lock_vote_multiplier_percent: Some(max_stake_lock_vote_multiplier_percent),
+				..Default::default()
+			})),
+		)
+		.unwrap();
+	};
+	change_max_multiplier(&mut env_deps, 1000);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![coin(100000, &vote_shares_denom)],
+		}),
+		CourtExecuteMsg::StakeLocked { lock_seconds: 1000 },
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_stats(&env_deps, SHARES_HOLDER_ACCOUNT_1)
+			.unwrap()
+			.effective_votes
+			.u128(),
+		1000000
+	);
+
+	// Votes which are already locked can't count for more than the config allows, which keeps early passes sound
+	change_max_multiplier(&mut env_deps, 200);
+	assert_eq!(
+		helpers::query_user_stats(&env_deps, SHARES_HOLDER_ACCOUNT_1)
+			.unwrap()
+			.effective_votes
+			.u128(),
+		200000
+	);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 150000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_2,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Vote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+		},
+	)
+	.unwrap();
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.info.votes_for.u128(), 350000);
+	// The remaining votes could still oppose it with twice the voting power
+	assert_eq!(proposal.status, TransactionProposalStatus::Pending);
+}
+
+#[test]
+pub fn staked_votes_total() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 300000);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Unstake,
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_total_supply(&env_deps).unwrap().staked_votes.u128(),
+		300000
+	);
+}
+
+#[test]
+pub fn locked_minority_cant_pass_early() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			max_stake_lock_seconds: Some(1000),
+			max_stake_lock_vote_multiplier_percent: Some(1000),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 150000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_2,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	// 10% of the supply locked for 10x the voting power
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![coin(100000, &vote_shares_denom)],
+		}),
+		CourtExecuteMsg::StakeLocked { lock_seconds: 1000 },
+	)
+	.unwrap();
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Vote {
+			id: 0,
+			vote: CourtUserVoteStatus::Approve,
+		},
+	)
+	.unwrap();
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	// More votes have been cast than there are in the supply...
+	assert_eq!(proposal.info.votes_for.u128(), 1150000);
+	assert_eq!(helpers::get_known_vote_supply(&env_deps), 1000000);
+	// ...but the remaining 90% could still oppose it with 10x the voting power
+	assert_eq!(proposal.status, TransactionProposalStatus::Pending);
+	assert_eq!(
+		helpers::query_proposal_tally(&env_deps, 0, None)
+			.unwrap()
+			.unwrap()
+			.votes_needed_to_block
+			.map(|votes| votes.u128()),
+		Some(1150001)
+	);
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Passed
+	);
+}
+
+#[test]
+pub fn stake_msg_json() {
+	// Staking without a lock keeps its original JSON representation
+	assert_eq!(
+		from_json::<CourtExecuteMsg>(br#""stake""#).unwrap(),
+		CourtExecuteMsg::Stake
+	);
+	assert_eq!(
+		from_json::<CourtExecuteMsg>(br#"{"stake_locked":{"lock_seconds":500}}"#).unwrap(),
+		CourtExecuteMsg::StakeLocked { lock_seconds: 500 }
+	);
+}
@@ -0,0 +1,473 @@
+use super::*;
+
+// Derived from the private key 0x1f2e3d4c5b6a79881f2e3d4c5b6a79881f2e3d4c5b6a79881f2e3d4c5b6a7988
+const SIGNER_PUBKEY: &str = "Aghf4sp6V1iVfqgRvY50PZzua8IAcvFHCoiMQ6EJGo6L";
+const SIGNER_ACCOUNT: &str = "sei12khwp8zl7js9ysh9rjvvd85884fueqq3uutafz";
+// Signature of {"chain_id":"sei-chain","court":"sei1235xjueqd9ejqargv5sxxmmww3exzcm5ypskgerjv4ehxgrvdakqu2lr7g","proposal_id":0,"vote":"approve","nonce":0}
+const SIGNED_APPROVE_NONCE_0: &str =
+	"TOPYy9FYlgny3o8SRj2hBK8J6h8u7XEiD4D2UBrmG5pqKunRemllDUf6qHZhQg/Un2A3/Ng1dkiH2CbU1NDwLQ==";
+// Signature of {"chain_id":"sei-chain","court":"sei1235xjueqd9ejqargv5sxxmmww3exzcm5ypskgerjv4ehxgrvdakqu2lr7g","proposal_id":0,"vote":"oppose","nonce":1}
+const SIGNED_OPPOSE_NONCE_1: &str =
+	"/gajAcRX8ypkpX3P8hzn8VHcLrwqc7hVb7ADY0TPI6IgpSztySTpbjf970gznu18XDrbJLCJIqBksDX59hMPdA==";
+// Signature of {"chain_id":"sei-chain","court":"sei1235xjueqd9ejqargv5sxxmmww3exzcm5ypskgerjv4ehxgrvdakqu2lr7g","proposal_id":1,"vote":"approve","nonce":0}
+const SIGNED_APPROVE_PROPOSAL_1_NONCE_0: &str =
+	"u2y3/cBmVAEA7WrQntamMmTgOihkTZ/abClgHcj4iwtafe+/w1D6dB4LmOXHfVMsZKUA/Q70ChzllTq5EYFTjQ==";
+// The EVM address of the same private key
+const SIGNER_EVM_ACCOUNT: &str = "0xaa06ca87ee128ec0010f6bf148bb040f3bc07f9b";
+// EIP-712 signatures of the same votes as above
+const EIP712_SIGNED_APPROVE_NONCE_0: &str =
+	"FhEGaownkmrE20uGo70HT4V1UtXBr9/1UjHfi6BSlxxSGUj8cDod0+2XL9dz/UZC3a3vLjvy7ZajAl6SOly00Rs=";
+const EIP712_SIGNED_OPPOSE_NONCE_1: &str =
+	"ufEm7BFTQw4jy3uuMy1ixJ42VQ+Pg5WsAL9pwTefHPoZnevZSbe1v4zT2fLFU5hsU5m8W0BOrwPUITgdO0GKbBw=";
+
+fn signed_vote(vote: CourtUserVoteStatus, nonce: u64, signature: &str) -> CourtSignedVote {
+	CourtSignedVote {
+		signature_type: CourtVoteSignatureType::Secp256k1,
+		pubkey: Binary::from_base64(SIGNER_PUBKEY).unwrap(),
+		proposal_id: 0,
+		vote,
+		option: None,
+		nonce,
+		signature: Binary::from_base64(signature).unwrap(),
+	}
+}
+
+fn eip712_signed_vote(vote: CourtUserVoteStatus, nonce: u64, signature: &str) -> CourtSignedVote {
+	CourtSignedVote {
+		signature_type: CourtVoteSignatureType::Eip712,
+		pubkey: Binary::default(),
+		proposal_id: 0,
+		vote,
+		option: None,
+		nonce,
+		signature: Binary::from_base64(signature).unwrap(),
+	}
+}
+
+fn new_env_with_proposal() -> MutexGuard<'static, (Env, SeiMockEnvDeps)> {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SIGNER_ACCOUNT, 110000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	env_deps
+}
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_with_proposal();
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0)],
+			skip_invalid: false,
+		},
+	);
+}
+
+#[test]
+pub fn votes_on_behalf_of_signer() {
+	let mut env_deps = new_env_with_proposal();
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0)],
+			skip_invalid: false,
+		},
+	)
+	.unwrap();
+	assert!(execute_response.events.iter().any(|event| event.ty == "signed_vote"));
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0),
+		Ok(CourtUserVoteInfoJsonable {
+			active_votes: 110000u128.into(),
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
+		})
+	);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(1));
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.info
+			.votes_for
+			.u128(),
+		250000
+	);
+
+	// The same signed vote can't be submitted twice
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0)],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteNonce(1)
+	);
+
+	helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![signed_vote(CourtUserVoteStatus::Oppose, 1, SIGNED_OPPOSE_NONCE_1)],
+			skip_invalid: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0)
+			.unwrap()
+			.vote,
+		CourtUserVoteStatus::Oppose
+	);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(2));
+}
+
+#[test]
+pub fn direct_votes_use_up_nonce() {
+	let mut env_deps = new_env_with_proposal();
+	helpers::execute_vote(&mut env_deps, SIGNER_ACCOUNT, 0, CourtUserVoteStatus::Oppose);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(1));
+
+	// A vote signed before voting directly can't be replayed to override it
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0)],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteNonce(1)
+	);
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0)
+			.unwrap()
+			.vote,
+		CourtUserVoteStatus::Oppose
+	);
+
+	// Same goes for voting on many proposals at once
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SIGNER_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteMany {
+			votes: vec![(0, CourtUserVoteStatus::Abstain)],
+			skip_inactive: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(2));
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![signed_vote(CourtUserVoteStatus::Oppose, 1, SIGNED_OPPOSE_NONCE_1)],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteNonce(2)
+	);
+}
+
+#[test]
+pub fn invalid_signatures() {
+	let mut env_deps = new_env_with_proposal();
+	// Signature is for an approving vote
+	let tampered_vote = signed_vote(CourtUserVoteStatus::Oppose, 0, SIGNED_APPROVE_NONCE_0);
+	// Signed with the wrong nonce
+	let early_vote = signed_vote(CourtUserVoteStatus::Oppose, 1, SIGNED_OPPOSE_NONCE_1);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![tampered_vote.clone()],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteSignature
+	);
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![early_vote.clone()],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteNonce(0)
+	);
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![
+				tampered_vote,
+				early_vote,
+				signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0),
+			],
+			skip_invalid: true,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response
+			.events
+			.iter()
+			.filter(|event| event.ty == "signed_vote_skipped")
+			.count(),
+		2
+	);
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0)
+			.unwrap()
+			.vote,
+		CourtUserVoteStatus::Approve
+	);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(1));
+}
+
+#[test]
+pub fn eip712_votes_on_behalf_of_associated_address() {
+	let mut env_deps = new_env_with_proposal();
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![eip712_signed_vote(
+					CourtUserVoteStatus::Approve,
+					0,
+					EIP712_SIGNED_APPROVE_NONCE_0
+				)],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::UnassociatedVoteSigner(SIGNER_EVM_ACCOUNT.into())
+	);
+
+	env_deps
+		.1
+		.querier
+		.evm_addresses
+		.push((SIGNER_EVM_ACCOUNT.into(), SIGNER_ACCOUNT.into()));
+	helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![eip712_signed_vote(
+				CourtUserVoteStatus::Approve,
+				0,
+				EIP712_SIGNED_APPROVE_NONCE_0,
+			)],
+			skip_invalid: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0),
+		Ok(CourtUserVoteInfoJsonable {
+			active_votes: 110000u128.into(),
+			vote: CourtUserVoteStatus::Approve,
+			split: None,
+			choice: None,
+		})
+	);
+
+	// Nonces are shared with votes signed by the Sei address
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: vec![signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0)],
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteNonce(1)
+	);
+	// The signature doesn't match the vote, so a different address is recovered from it
+	assert!(helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![eip712_signed_vote(
+				CourtUserVoteStatus::Veto,
+				1,
+				EIP712_SIGNED_OPPOSE_NONCE_1,
+			)],
+			skip_invalid: false,
+		},
+	)
+	.is_err_and(|err| matches!(err, CourtContractError::UnassociatedVoteSigner(_))));
+	helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![eip712_signed_vote(
+				CourtUserVoteStatus::Oppose,
+				1,
+				EIP712_SIGNED_OPPOSE_NONCE_1,
+			)],
+			skip_invalid: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0)
+			.unwrap()
+			.vote,
+		CourtUserVoteStatus::Oppose
+	);
+}
+
+fn new_env_with_second_proposal() -> MutexGuard<'static, (Env, SeiMockEnvDeps)> {
+	let mut env_deps = new_env_with_proposal();
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		2400,
+	);
+	env_deps
+}
+
+#[test]
+pub fn nonces_are_per_proposal() {
+	let mut env_deps = new_env_with_second_proposal();
+	let proposal_1_vote = CourtSignedVote {
+		proposal_id: 1,
+		..signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_PROPOSAL_1_NONCE_0)
+	};
+	// A relayer withholding the signed vote on proposal 0 doesn't hold up the vote on proposal 1
+	helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![proposal_1_vote],
+			skip_invalid: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 1)
+			.unwrap()
+			.vote,
+		CourtUserVoteStatus::Approve
+	);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 1), Ok(1));
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(0));
+
+	helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes: vec![signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0)],
+			skip_invalid: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(1));
+}
+
+#[test]
+pub fn skips_votes_which_cant_be_cast() {
+	let mut env_deps = new_env_with_second_proposal();
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	let status = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status;
+	assert_ne!(status, TransactionProposalStatus::Pending);
+	let votes = vec![
+		signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_NONCE_0),
+		CourtSignedVote {
+			proposal_id: 1,
+			..signed_vote(CourtUserVoteStatus::Approve, 0, SIGNED_APPROVE_PROPOSAL_1_NONCE_0)
+		},
+	];
+	let status_err = CourtContractError::UnexpectedProposalStatus {
+		expected: TransactionProposalStatus::Pending,
+		actual: status,
+	};
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			None,
+			CourtExecuteMsg::SubmitSignedVotes {
+				votes: votes.clone(),
+				skip_invalid: false,
+			},
+		)
+		.unwrap_err(),
+		status_err
+	);
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		None,
+		CourtExecuteMsg::SubmitSignedVotes {
+			votes,
+			skip_invalid: true,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events[0],
+		cosmwasm_std::Event::new("signed_vote_skipped")
+			.add_attribute("index", "0")
+			.add_attribute("reason", status_err.to_string())
+	);
+	assert_eq!(
+		execute_response.events[1],
+		cosmwasm_std::Event::new("signed_vote")
+			.add_attribute("voter", SIGNER_ACCOUNT)
+			.add_attribute("nonce", "0")
+	);
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 0)
+			.unwrap()
+			.active_votes
+			.u128(),
+		0
+	);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 0), Ok(0));
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SIGNER_ACCOUNT, 1)
+			.unwrap()
+			.vote,
+		CourtUserVoteStatus::Approve
+	);
+	assert_eq!(helpers::query_signed_vote_nonce(&env_deps, SIGNER_ACCOUNT, 1), Ok(1));
+}
@@ -115,13 +115,23 @@ pub fn tokens_actually_returned() {
 	assert_eq!(
 		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_2).unwrap(),
 		CourtUserStatsJsonable {
-			staked_votes: 0u128.into()
+			staked_votes: 0u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 0u128.into()
 		}
 	);
 	assert_eq!(
 		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
 		CourtUserStatsJsonable {
-			staked_votes: user1_stake_amount_total.into()
+			staked_votes: user1_stake_amount_total.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: user1_stake_amount_total.into()
 		}
 	);
 
@@ -157,7 +167,57 @@ pub fn tokens_actually_returned() {
 	assert_eq!(
 		helpers::query_user_stats(&mut env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap(),
 		CourtUserStatsJsonable {
-			staked_votes: 0u128.into()
+			staked_votes: 0u128.into(),
+			pending_votes: 0u128.into(),
+			pending_votes_timestamp_ms: 0,
+			lock_expiry_timestamp_ms: 0,
+			vote_multiplier_percent: 0,
+			effective_votes: 0u128.into()
 		}
 	);
 }
+
+#[test]
+pub fn only_when_unlocked() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			max_stake_lock_seconds: Some(1000),
+			max_stake_lock_vote_multiplier_percent: Some(400),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![coin(100000, &vote_shares_denom)],
+		}),
+		CourtExecuteMsg::StakeLocked { lock_seconds: 500 },
+	)
+	.unwrap();
+	let unstake = |env_deps: &mut (Env, SeiMockEnvDeps)| {
+		helpers::execute(
+			env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Unstake,
+		)
+	};
+
+	assert_eq!(
+		unstake(&mut env_deps).unwrap_err(),
+		CourtContractError::StakeLocked(env_deps.0.block.time.plus_seconds(500).millis())
+	);
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(500);
+	unstake(&mut env_deps).unwrap();
+}
@@ -1,3 +1,5 @@
+use cosmwasm_std::Decimal;
+
 use super::*;
 
 #[test]
@@ -299,7 +301,9 @@ pub fn allow_vote_change() {
 		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 0),
 		Ok(CourtUserVoteInfoJsonable {
 			active_votes: 0u128.into(),
-			vote: CourtUserVoteStatus::Abstain
+			vote: CourtUserVoteStatus::Abstain,
+			split: None,
+			choice: None,
 		})
 	);
 	assert_eq!(
@@ -312,14 +316,27 @@ pub fn allow_vote_change() {
 				votes_for: 140000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 
@@ -335,14 +352,27 @@ pub fn allow_vote_change() {
 				votes_for: 250000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Abstain);
@@ -356,14 +386,27 @@ pub fn allow_vote_change() {
 				votes_for: 140000u128.into(),
 				votes_against: 0u128.into(),
 				votes_abstain: 110000u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Oppose);
@@ -377,14 +420,27 @@ pub fn allow_vote_change() {
 				votes_for: 140000u128.into(),
 				votes_against: 110000u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	// Cannot vote for the same position twice
@@ -426,14 +482,27 @@ pub fn allow_vote_change() {
 				votes_for: 0u128.into(),
 				votes_against: 110000u128.into(),
 				votes_abstain: 140000u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 0, CourtUserVoteStatus::Oppose);
@@ -447,14 +516,27 @@ pub fn allow_vote_change() {
 				votes_for: 0u128.into(),
 				votes_against: 250000u128.into(),
 				votes_abstain: 0u128.into(),
+				votes_veto: 0u128.into(),
+				quorum_supply: 1000000u128.into(),
 				execution_status: TransactionProposalExecutionStatus::NotExecuted,
-				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis()
+				option_count: 0,
+				leading_option: None,
+				is_signal: false,
+				is_optimistic: false,
+				track: None,
+				extensions: 0,
+				expiry_timestamp_ms: env_deps.0.block.time.plus_seconds(1200).millis(),
+				expedited_expiry_timestamp_ms: None,
+				reveal_expiry_timestamp_ms: None
 			},
 			messages: vec![ProposedCourtMsgJsonable::SendCoin {
 				to: RANDOM_ACCOUNT_1.into(),
 				denom: "usei".into(),
 				amount: 1337u128.into()
-			}]
+			}],
+			decoded_messages: None,
+			options: vec![],
+			metadata: None,
 		}))
 	);
 }
@@ -588,3 +670,681 @@ pub fn emits_vote_event() {
 			.add_attribute("vote", "approve")]
 	);
 }
+
+#[test]
+pub fn tally() {
+	let mut env_deps = new_env_and_instantiate(None);
+	// Sanity tests for current config we're testing against
+	assert_eq!(
+		helpers::query_config(&env_deps).unwrap().minimum_vote_turnout_percent,
+		20
+	);
+	assert_eq!(helpers::query_config(&env_deps).unwrap().minimum_vote_pass_percent, 50);
+	assert_eq!(
+		helpers::query_config(&env_deps).unwrap().execution_expiry_time_seconds,
+		3600
+	);
+	assert_eq!(helpers::get_known_vote_supply(&env_deps), 1000000);
+	assert_eq!(helpers::query_proposal_tally(&env_deps, 0, None), Ok(None));
+
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 150000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 250001);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	// Not enough turnout
+	assert_eq!(
+		helpers::query_proposal_tally(&env_deps, 0, None),
+		Ok(Some(CourtQueryResponseProposalTally {
+			proposal_id: 0,
+			status: TransactionProposalStatus::Pending,
+			projected_status: TransactionProposalStatus::Rejected,
+			votes_for: 100000u128.into(),
+			votes_against: 0u128.into(),
+			votes_abstain: 0u128.into(),
+			votes_veto: 0u128.into(),
+			option_votes: vec![],
+			total_supply: 1000000u128.into(),
+			turnout: Decimal::percent(10),
+			approval: Decimal::one(),
+			veto: Decimal::zero(),
+			minimum_vote_turnout_percent: 20,
+			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			votes_needed_to_pass: Some(100000u128.into()),
+			votes_needed_to_block: Some(0u128.into()),
+			voting_time_remaining_ms: 1200000,
+			timelock_remaining_ms: 0,
+			execution_time_remaining_ms: 4800000,
+		}))
+	);
+
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Approve);
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(200);
+	assert_eq!(
+		helpers::query_proposal_tally(&env_deps, 0, None),
+		Ok(Some(CourtQueryResponseProposalTally {
+			proposal_id: 0,
+			status: TransactionProposalStatus::Pending,
+			projected_status: TransactionProposalStatus::Passed,
+			votes_for: 250000u128.into(),
+			votes_against: 0u128.into(),
+			votes_abstain: 0u128.into(),
+			votes_veto: 0u128.into(),
+			option_votes: vec![],
+			total_supply: 1000000u128.into(),
+			turnout: Decimal::percent(25),
+			approval: Decimal::one(),
+			veto: Decimal::zero(),
+			minimum_vote_turnout_percent: 20,
+			minimum_vote_pass_percent: 50,
+			veto_threshold_percent: 0,
+			votes_needed_to_pass: Some(0u128.into()),
+			votes_needed_to_block: Some(250001u128.into()),
+			voting_time_remaining_ms: 1000000,
+			timelock_remaining_ms: 0,
+			execution_time_remaining_ms: 4600000,
+		}))
+	);
+
+	// Exactly what was needed to block it
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 0, CourtUserVoteStatus::Oppose);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Rejected);
+	assert_eq!(tally.approval, Decimal::from_ratio(250000u128, 500001u128));
+	assert_eq!(tally.votes_needed_to_pass, Some(1u128.into()));
+	assert_eq!(tally.votes_needed_to_block, Some(0u128.into()));
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.status, TransactionProposalStatus::Rejected);
+	assert_eq!(tally.voting_time_remaining_ms, 0);
+	assert_eq!(tally.execution_time_remaining_ms, 3600000);
+}
+
+#[test]
+pub fn status_at_time() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let proposal_id = helpers::execute_create_passing_proposal(
+		&mut env_deps,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+		SHARES_HOLDER_ACCOUNT_1,
+		SHARES_HOLDER_ACCOUNT_2,
+	);
+	let expiry_timestamp_ms = env_deps.0.block.time.plus_seconds(1200).millis();
+	let execution_expiry_timestamp_ms = expiry_timestamp_ms + 3600000;
+	let status_at = |env_deps: &(Env, SeiMockEnvDeps), at_time_ms: u64| {
+		helpers::query_get_proposal_at_time(env_deps, proposal_id, at_time_ms)
+			.unwrap()
+			.unwrap()
+			.status
+	};
+
+	assert_eq!(
+		status_at(&env_deps, expiry_timestamp_ms - 1),
+		TransactionProposalStatus::Pending
+	);
+	assert_eq!(
+		status_at(&env_deps, expiry_timestamp_ms),
+		TransactionProposalStatus::Passed
+	);
+	assert_eq!(
+		status_at(&env_deps, execution_expiry_timestamp_ms),
+		TransactionProposalStatus::Passed
+	);
+	assert_eq!(
+		status_at(&env_deps, execution_expiry_timestamp_ms + 1),
+		TransactionProposalStatus::ExecutionExpired
+	);
+	// The current status is unaffected
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, proposal_id)
+			.unwrap()
+			.unwrap()
+			.status,
+		TransactionProposalStatus::Pending
+	);
+
+	let tally = helpers::query_proposal_tally(&env_deps, proposal_id, Some(expiry_timestamp_ms))
+		.unwrap()
+		.unwrap();
+	assert_eq!(tally.status, TransactionProposalStatus::Passed);
+	assert_eq!(tally.voting_time_remaining_ms, 0);
+	assert_eq!(tally.execution_time_remaining_ms, 3600000);
+}
+
+#[test]
+pub fn veto() {
+	let mut env_deps = new_env_and_instantiate(Some(CourtInstantiateMsg {
+		admin: Addr::unchecked(ADMIN_ACCOUNT),
+		shares_mint_amount: 1000000u128.into(),
+		shares_mint_receiver: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+		minimum_vote_proposal_percent: 10,
+		minimum_vote_turnout_percent: 20,
+		minimum_vote_pass_percent: 50,
+		veto_threshold_percent: 33,
+		optimistic_opposition_threshold_percent: 10,
+		expedited_minimum_vote_turnout_percent: 50,
+		expedited_minimum_vote_pass_percent: 66,
+		expedited_voting_period_seconds: 600,
+		min_proposal_expiry_time_seconds: 60,
+		max_proposal_expiry_time_seconds: 7200,
+		execution_expiry_time_seconds: 3600,
+		quiet_ending_window_seconds: 0,
+		quiet_ending_extension_seconds: 0,
+		max_quiet_ending_extensions: 0,
+		min_stake_age_seconds: 0,
+		max_stake_lock_seconds: 0,
+		max_stake_lock_vote_multiplier_percent: 0,
+		quadratic_voting: false,
+		quorum_supply: CourtQuorumSupply::Total,
+		max_user_votes: 0u128.into(),
+		max_user_votes_bps: 0,
+		reveal_period_seconds: 0,
+		vote_share_name: "Test Votes".into(),
+		vote_share_symbol: "TST".into(),
+		vote_share_description: "Test vortessadbjhk,sdfgvgjhlksdfjhgbksdv".into(),
+	}));
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 600000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 300000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	// Would otherwise pass early, but the uncast votes could still veto it
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Pending
+	);
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Vote {
+			id: 0,
+			vote: CourtUserVoteStatus::Veto,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+			.add_attribute("votes", "300000")
+			.add_attribute("vote", "veto")]
+	);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.votes_veto.u128(), 300000);
+	assert_eq!(tally.veto, Decimal::from_ratio(1u128, 3u128));
+	assert_eq!(tally.approval, Decimal::from_ratio(2u128, 3u128));
+	assert_eq!(tally.veto_threshold_percent, 33);
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Vetoed);
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Vetoed
+	);
+
+	// Same votes, but opposed rather than vetoed
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 1, CourtUserVoteStatus::Oppose);
+	// The remaining uncast votes can no longer veto it
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 1).unwrap().unwrap().status,
+		TransactionProposalStatus::Passed
+	);
+}
+
+#[test]
+pub fn quiet_ending_extensions() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			quiet_ending_window_seconds: Some(300),
+			quiet_ending_extension_seconds: Some(600),
+			max_quiet_ending_extensions: Some(2),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 300000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 400000);
+	let start_time = env_deps.0.block.time;
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	let vote = |env_deps: &mut (Env, SeiMockEnvDeps), vote: CourtUserVoteStatus| {
+		helpers::execute(
+			env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Vote { id: 0, vote },
+		)
+		.unwrap()
+		.events
+	};
+	let vote_event = |vote: &str| {
+		cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+			.add_attribute("votes", "400000")
+			.add_attribute("vote", vote)
+	};
+	let expiry_timestamp_ms = |env_deps: &(Env, SeiMockEnvDeps)| {
+		helpers::query_get_proposal(env_deps, 0)
+			.unwrap()
+			.unwrap()
+			.info
+			.expiry_timestamp_ms
+	};
+
+	// Changing the outcome before the final window doesn't extend voting
+	assert_eq!(
+		vote(&mut env_deps, CourtUserVoteStatus::Oppose),
+		vec![vote_event("oppose")]
+	);
+	assert_eq!(expiry_timestamp_ms(&env_deps), start_time.plus_seconds(1200).millis());
+
+	// Changing the outcome within the final window does
+	env_deps.0.block.time = start_time.plus_seconds(1100);
+	assert_eq!(
+		vote(&mut env_deps, CourtUserVoteStatus::Abstain),
+		vec![
+			vote_event("abstain"),
+			cosmwasm_std::Event::new("proposal_extended")
+				.add_attribute("proposal_id", "0")
+				.add_attribute("extensions", "1")
+				.add_attribute(
+					"expiry_timestamp_ms",
+					start_time.plus_seconds(1800).millis().to_string()
+				)
+		]
+	);
+	env_deps.0.block.time = start_time.plus_seconds(1700);
+	assert_eq!(
+		vote(&mut env_deps, CourtUserVoteStatus::Oppose),
+		vec![
+			vote_event("oppose"),
+			cosmwasm_std::Event::new("proposal_extended")
+				.add_attribute("proposal_id", "0")
+				.add_attribute("extensions", "2")
+				.add_attribute(
+					"expiry_timestamp_ms",
+					start_time.plus_seconds(2400).millis().to_string()
+				)
+		]
+	);
+
+	// Up to the configured number of times
+	env_deps.0.block.time = start_time.plus_seconds(2300);
+	assert_eq!(
+		vote(&mut env_deps, CourtUserVoteStatus::Abstain),
+		vec![vote_event("abstain")]
+	);
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.info.extensions, 2);
+	assert_eq!(
+		proposal.info.expiry_timestamp_ms,
+		start_time.plus_seconds(2400).millis()
+	);
+	assert_eq!(proposal.status, TransactionProposalStatus::Pending);
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Passed
+	);
+}
+
+#[test]
+pub fn quadratic_voting() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			quadratic_voting: Some(true),
+			quorum_supply: Some(CourtQuorumSupply::Staked),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	// The 640000 staked votes count as 800, so 80 votes are needed to propose. Unstaked votes don't count.
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 627201);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 6400);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 6399);
+	let propose = |env_deps: &mut (Env, SeiMockEnvDeps), proposer: &str| {
+		helpers::execute(
+			env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(proposer),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::ProposeTransaction {
+				msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+					to: RANDOM_ACCOUNT_1.into(),
+					denom: "usei".into(),
+					amount: 1337u128.into(),
+				}],
+				expiry_time_seconds: 1200,
+				metadata: None,
+				optimistic: false,
+				expedited: false,
+				private: false,
+			},
+		)
+	};
+	assert_eq!(
+		propose(&mut env_deps, SHARES_HOLDER_ACCOUNT_3).unwrap_err(),
+		CourtContractError::InsufficientVotesForProposal
+	);
+	assert_eq!(
+		propose(&mut env_deps, SHARES_HOLDER_ACCOUNT_2).unwrap().events[1],
+		cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+			.add_attribute("votes", "80")
+			.add_attribute("vote", "approve")
+	);
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Vote {
+			id: 0,
+			vote: CourtUserVoteStatus::Oppose,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("votes", "791")
+			.add_attribute("vote", "oppose")]
+	);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.votes_for.u128(), 80);
+	assert_eq!(tally.votes_against.u128(), 791);
+	assert_eq!(tally.total_supply.u128(), 800);
+	// The square roots of votes held by different users add up to more than the square root of their sum
+	assert_eq!(tally.turnout, cosmwasm_std::Decimal::from_ratio(871u128, 800u128));
+	assert_eq!(tally.projected_status, TransactionProposalStatus::Rejected);
+}
+
+#[test]
+pub fn quadratic_voting_never_passes_early() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			quadratic_voting: Some(true),
+			quorum_supply: Some(CourtQuorumSupply::Staked),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	// 800 of the 1000 square-rooted staked supply approve, but the remaining 360000 staked votes split between 36 users
+	// would count for 3600.
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 640000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 360000);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
+		},
+	)
+	.unwrap();
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.votes_for.u128(), 800);
+	assert_eq!(tally.total_supply.u128(), 1000);
+	assert_eq!(tally.status, TransactionProposalStatus::Pending);
+	assert_eq!(tally.votes_needed_to_block.map(|votes| votes.u128()), Some(801));
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Passed
+	);
+}
+
+#[test]
+pub fn quorum_supply_staked() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			quorum_supply: Some(CourtQuorumSupply::Staked),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 300000);
+	assert_eq!(
+		helpers::query_total_supply(&env_deps).unwrap().staked_votes.u128(),
+		400000
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.total_supply.u128(), 400000);
+	assert_eq!(tally.turnout, cosmwasm_std::Decimal::percent(25));
+
+	// Unstaked votes no longer count towards the supply...
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Unstake,
+	)
+	.unwrap();
+	assert_eq!(
+		helpers::query_total_supply(&env_deps).unwrap().staked_votes.u128(),
+		100000
+	);
+	// ...by the proposals made afterwards, existing proposals keep the supply they were made with.
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.total_supply.u128(), 400000);
+	assert_eq!(tally.turnout, cosmwasm_std::Decimal::percent(25));
+	assert_eq!(tally.status, TransactionProposalStatus::Pending);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	let tally = helpers::query_proposal_tally(&env_deps, 1, None).unwrap().unwrap();
+	assert_eq!(tally.total_supply.u128(), 100000);
+	assert_eq!(tally.turnout, cosmwasm_std::Decimal::percent(100));
+	assert_eq!(tally.status, TransactionProposalStatus::Passed);
+	env_deps.0.block.time = env_deps.0.block.time.plus_seconds(1201);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Rejected
+	);
+}
+
+#[test]
+pub fn quorum_supply_circulating() {
+	let mut env_deps = new_env_and_instantiate(None);
+	let vote_shares_denom = helpers::query_denom(&env_deps).unwrap().votes;
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			quorum_supply: Some(CourtQuorumSupply::Circulating),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 200000);
+	// The court holds the staked votes, along with 300000 of its own
+	let contract_address = env_deps.0.contract.address.to_string();
+	env_deps.1.querier.bank.update_balance(
+		contract_address,
+		vec![coin(1000000, "usei"), coin(500000, &vote_shares_denom)],
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, None).unwrap().unwrap();
+	assert_eq!(tally.total_supply.u128(), 700000);
+}
+
+#[test]
+pub fn max_user_votes() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(ADMIN_ACCOUNT),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::Admin(CourtAdminExecuteMsg::ChangeConfig(CourtConfigChanges {
+			max_user_votes: Some(300000u128.into()),
+			// 20% of the 1000000 vote supply, the lower cap applies
+			max_user_votes_bps: Some(2000),
+			..Default::default()
+		})),
+	)
+	.unwrap();
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 500000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 100000);
+	let user_stats = helpers::query_user_stats(&env_deps, SHARES_HOLDER_ACCOUNT_1).unwrap();
+	assert_eq!(user_stats.staked_votes.u128(), 500000);
+	assert_eq!(user_stats.effective_votes.u128(), 200000);
+
+	// The excess votes are ignored when proposing...
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_1),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ProposeTransaction {
+			msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+				to: RANDOM_ACCOUNT_1.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+			expiry_time_seconds: 1200,
+			metadata: None,
+			optimistic: false,
+			expedited: false,
+			private: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events[1],
+		cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_1)
+			.add_attribute("votes", "200000")
+			.add_attribute("vote", "approve")
+	);
+	// ...and when voting, while users under the cap are unaffected
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Oppose);
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.info.votes_for.u128(), 200000);
+	assert_eq!(proposal.info.votes_against.u128(), 100000);
+}
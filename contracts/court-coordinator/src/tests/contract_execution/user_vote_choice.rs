@@ -0,0 +1,237 @@
+use cosmwasm_std::Uint128;
+
+use super::*;
+
+fn grant_options() -> Vec<CourtProposalOption> {
+	[RANDOM_ACCOUNT_1, RANDOM_ACCOUNT_2, RANDOM_ACCOUNT_3]
+		.into_iter()
+		.map(|recipient| CourtProposalOption {
+			label: format!("Grant to {recipient}"),
+			msgs: vec![ProposedCourtMsgJsonable::SendCoin {
+				to: recipient.into(),
+				denom: "usei".into(),
+				amount: 1337u128.into(),
+			}],
+		})
+		.collect()
+}
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, grant_options(), 1200);
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteChoice { id: 0, option: 0 },
+	);
+}
+
+#[test]
+pub fn only_valid_choices() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, grant_options(), 1200);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	for (id, msg) in [
+		// Option doesn't exist
+		(0, CourtExecuteMsg::VoteChoice { id: 0, option: 3 }),
+		// Multiple-choice proposals can't be approved or opposed
+		(
+			0,
+			CourtExecuteMsg::Vote {
+				id: 0,
+				vote: CourtUserVoteStatus::Approve,
+			},
+		),
+		(
+			0,
+			CourtExecuteMsg::VoteWeighted {
+				id: 0,
+				approve: 5000,
+				oppose: 5000,
+				abstain: 0,
+			},
+		),
+		// Regular proposals don't have options
+		(1, CourtExecuteMsg::VoteChoice { id: 1, option: 0 }),
+		(
+			1,
+			CourtExecuteMsg::Vote {
+				id: 1,
+				vote: CourtUserVoteStatus::Choice,
+			},
+		),
+	] {
+		assert_eq!(
+			helpers::execute(
+				&mut env_deps,
+				Some(MessageInfo {
+					sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+					funds: vec![],
+				}),
+				msg,
+			)
+			.unwrap_err(),
+			CourtContractError::InvalidVoteChoice,
+			"proposal {id}"
+		);
+	}
+	// Abstaining is fine
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Abstain);
+	helpers::execute_vote_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, 2);
+}
+
+#[test]
+pub fn emits_vote_event() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, grant_options(), 1200);
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteChoice { id: 0, option: 1 },
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+			.add_attribute("votes", "110000")
+			.add_attribute("vote", "choice")
+			.add_attribute("option", "1")]
+	);
+}
+
+#[test]
+pub fn ties_are_rejected() {
+	let mut env_deps = new_env_and_instantiate(None);
+	// Sanity tests for current config we're testing against
+	assert_eq!(
+		helpers::query_config(&env_deps).unwrap().minimum_vote_turnout_percent,
+		20
+	);
+	assert_eq!(helpers::query_config(&env_deps).unwrap().minimum_vote_pass_percent, 50);
+	assert_eq!(helpers::get_known_vote_supply(&env_deps), 1000000);
+
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 150000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 100000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 100000);
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, grant_options(), 1200);
+	let expiry_timestamp_ms = env_deps.0.block.time.plus_seconds(1200).millis();
+
+	helpers::execute_vote_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, 0);
+	helpers::execute_vote_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 0, 1);
+	let proposal = helpers::query_get_proposal_at_time(&env_deps, 0, expiry_timestamp_ms)
+		.unwrap()
+		.unwrap();
+	assert_eq!(proposal.info.leading_option, None);
+	assert_eq!(proposal.status, TransactionProposalStatus::Rejected);
+
+	// Changing a vote moves it to the other option
+	helpers::execute_vote_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 0, 0);
+	let tally = helpers::query_proposal_tally(&env_deps, 0, Some(expiry_timestamp_ms))
+		.unwrap()
+		.unwrap();
+	assert_eq!(
+		tally.option_votes,
+		vec![Uint128::new(200000), Uint128::zero(), Uint128::zero()]
+	);
+	assert_eq!(tally.votes_for.u128(), 200000);
+	assert_eq!(tally.votes_against.u128(), 0);
+	assert_eq!(tally.votes_abstain.u128(), 150000);
+	assert_eq!(tally.status, TransactionProposalStatus::Passed);
+	assert_eq!(tally.votes_needed_to_pass, None);
+	assert_eq!(tally.votes_needed_to_block, None);
+}
+
+#[test]
+pub fn winning_option_executes() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 150000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 200000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 100000);
+	helpers::execute_propose_multiple_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, grant_options(), 1200);
+
+	helpers::execute_vote_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, 1);
+	helpers::execute_vote_choice(&mut env_deps, SHARES_HOLDER_ACCOUNT_3, 0, 2);
+	// Another option could still win with the remaining votes
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status,
+		TransactionProposalStatus::Pending
+	);
+
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.status, TransactionProposalStatus::Passed);
+	assert_eq!(proposal.info.leading_option, Some(1));
+	assert_eq!(proposal.info.votes_for.u128(), 200000);
+	assert_eq!(proposal.info.votes_against.u128(), 100000);
+	assert_eq!(
+		proposal
+			.options
+			.iter()
+			.map(|option| option.votes.u128())
+			.collect::<Vec<_>>(),
+		vec![0, 200000, 100000]
+	);
+
+	let execute_result = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(RANDOM_ACCOUNT_5),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::ExecuteProposal { id: 0 },
+	)
+	.unwrap();
+	assert_eq!(
+		execute_result.events,
+		vec![cosmwasm_std::Event::new("proposal_executed")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("option", "1")]
+	);
+	assert_eq!(
+		execute_result.messages,
+		vec![cosmwasm_std::SubMsg::new(cosmwasm_std::CosmosMsg::<SeiMsg>::Bank(
+			cosmwasm_std::BankMsg::Send {
+				to_address: RANDOM_ACCOUNT_2.into(),
+				amount: vec![coin(1337, "usei")]
+			}
+		))]
+	);
+
+	// The winning messages are kept while the rest are discarded
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.status, TransactionProposalStatus::Executed);
+	assert_eq!(
+		proposal.messages,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_2.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}]
+	);
+	assert!(proposal.options.iter().all(|option| option.messages.is_empty()));
+	assert_eq!(proposal.options[1].label, format!("Grant to {RANDOM_ACCOUNT_2}"));
+}
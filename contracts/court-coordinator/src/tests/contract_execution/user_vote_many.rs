@@ -0,0 +1,187 @@
+use super::*;
+
+fn new_env_with_proposals() -> MutexGuard<'static, (Env, SeiMockEnvDeps)> {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		600,
+	);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	env_deps
+}
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_with_proposals();
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteMany {
+			votes: vec![(0, CourtUserVoteStatus::Oppose), (1, CourtUserVoteStatus::Approve)],
+			skip_inactive: false,
+		},
+	);
+}
+
+#[test]
+pub fn votes_on_multiple_proposals() {
+	let mut env_deps = new_env_with_proposals();
+	let sender = Some(MessageInfo {
+		sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+		funds: vec![],
+	});
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender.clone(),
+			CourtExecuteMsg::VoteMany {
+				votes: vec![(0, CourtUserVoteStatus::Oppose), (1, CourtUserVoteStatus::Split)],
+				skip_inactive: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteSplit
+	);
+	// Only proposals which are no longer pending may be skipped
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		sender.clone(),
+		CourtExecuteMsg::VoteMany {
+			votes: vec![(0, CourtUserVoteStatus::Oppose), (2, CourtUserVoteStatus::Approve)],
+			skip_inactive: true,
+		},
+	);
+	assert!(execute_response.is_err_and(|err| { err.to_string().contains(" 2 does not exist") }));
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		sender,
+		CourtExecuteMsg::VoteMany {
+			votes: vec![(0, CourtUserVoteStatus::Oppose), (1, CourtUserVoteStatus::Approve)],
+			skip_inactive: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", "0")
+				.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+				.add_attribute("votes", "110000")
+				.add_attribute("vote", "oppose"),
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", "1")
+				.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+				.add_attribute("votes", "110000")
+				.add_attribute("vote", "approve"),
+		]
+	);
+	let proposal = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap();
+	assert_eq!(proposal.info.votes_for.u128(), 140000);
+	assert_eq!(proposal.info.votes_against.u128(), 110000);
+	let proposal = helpers::query_get_proposal(&env_deps, 1).unwrap().unwrap();
+	assert_eq!(proposal.info.votes_for.u128(), 250000);
+	assert_eq!(proposal.info.votes_against.u128(), 0);
+	assert_eq!(
+		helpers::query_get_user_active_proposals(&env_deps, SHARES_HOLDER_ACCOUNT_2, None, None, false)
+			.unwrap()
+			.len(),
+		2
+	);
+}
+
+#[test]
+pub fn skip_inactive_proposals() {
+	let mut env_deps = new_env_with_proposals();
+	helpers::advance_time_to_vote_end(&mut env_deps, 0);
+	let status = helpers::query_get_proposal(&env_deps, 0).unwrap().unwrap().status;
+	assert_ne!(status, TransactionProposalStatus::Pending);
+	let sender = Some(MessageInfo {
+		sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+		funds: vec![],
+	});
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			sender.clone(),
+			CourtExecuteMsg::VoteMany {
+				votes: vec![(0, CourtUserVoteStatus::Oppose), (1, CourtUserVoteStatus::Approve)],
+				skip_inactive: false,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::UnexpectedProposalStatus {
+			expected: TransactionProposalStatus::Pending,
+			actual: status
+		}
+	);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 1)
+			.unwrap()
+			.unwrap()
+			.info
+			.votes_for
+			.u128(),
+		140000
+	);
+
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		sender,
+		CourtExecuteMsg::VoteMany {
+			votes: vec![(0, CourtUserVoteStatus::Oppose), (1, CourtUserVoteStatus::Approve)],
+			skip_inactive: true,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![
+			cosmwasm_std::Event::new("vote_skipped")
+				.add_attribute("proposal_id", "0")
+				.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+				.add_attribute("status", status.to_string()),
+			cosmwasm_std::Event::new("vote")
+				.add_attribute("proposal_id", "1")
+				.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+				.add_attribute("votes", "110000")
+				.add_attribute("vote", "approve"),
+		]
+	);
+	assert_eq!(
+		helpers::query_user_vote_info(&env_deps, SHARES_HOLDER_ACCOUNT_2, 0)
+			.unwrap()
+			.active_votes
+			.u128(),
+		0
+	);
+	assert_eq!(
+		helpers::query_get_proposal(&env_deps, 1)
+			.unwrap()
+			.unwrap()
+			.info
+			.votes_for
+			.u128(),
+		250000
+	);
+}
@@ -0,0 +1,188 @@
+use super::*;
+
+#[test]
+pub fn unfunded_check() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	helpers::assert_unfunded_instruction(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteWeighted {
+			id: 0,
+			approve: 5000,
+			oppose: 5000,
+			abstain: 0,
+		},
+	);
+}
+
+#[test]
+pub fn split_must_add_up() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	for (approve, oppose, abstain) in [(5000, 5000, 1), (3000, 3000, 3000), (u16::MAX, u16::MAX, 0)] {
+		assert_eq!(
+			helpers::execute(
+				&mut env_deps,
+				Some(MessageInfo {
+					sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+					funds: vec![],
+				}),
+				CourtExecuteMsg::VoteWeighted {
+					id: 0,
+					approve,
+					oppose,
+					abstain,
+				},
+			)
+			.unwrap_err(),
+			CourtContractError::InvalidVoteSplit
+		);
+	}
+	// Regular votes can't be split
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::Vote {
+				id: 0,
+				vote: CourtUserVoteStatus::Split,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::InvalidVoteSplit
+	);
+}
+
+#[test]
+pub fn split_tally() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110001);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	let tally = |env_deps: &(Env, SeiMockEnvDeps)| {
+		let info = helpers::query_get_proposal(env_deps, 0).unwrap().unwrap().info;
+		(
+			info.votes_for.u128(),
+			info.votes_against.u128(),
+			info.votes_abstain.u128(),
+		)
+	};
+
+	// Rounding leftovers are counted as abstaining
+	helpers::execute_vote_weighted(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, 3333, 3333, 3334);
+	assert_eq!(tally(&env_deps), (140000 + 36663, 36663, 36675));
+
+	// Changing the split replaces the previous one
+	helpers::execute_vote_weighted(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, 0, 7500, 2500);
+	assert_eq!(tally(&env_deps), (140000, 82500, 27501));
+	assert_eq!(
+		helpers::execute(
+			&mut env_deps,
+			Some(MessageInfo {
+				sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+				funds: vec![],
+			}),
+			CourtExecuteMsg::VoteWeighted {
+				id: 0,
+				approve: 0,
+				oppose: 7500,
+				abstain: 2500,
+			},
+		)
+		.unwrap_err(),
+		CourtContractError::AlreadyVoted
+	);
+
+	// Staking more applies the same split to the new total
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 9999);
+	helpers::execute_vote_weighted(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, 0, 7500, 2500);
+	assert_eq!(tally(&env_deps), (140000, 90000, 30000));
+
+	// Switching between split and regular votes
+	helpers::execute_vote(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 0, CourtUserVoteStatus::Approve);
+	assert_eq!(tally(&env_deps), (260000, 0, 0));
+	helpers::execute_vote_weighted(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 0, 10000, 0, 0);
+	assert_eq!(tally(&env_deps), (260000, 0, 0));
+	helpers::execute_vote_weighted(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 0, 5000, 0, 5000);
+	assert_eq!(tally(&env_deps), (190000, 0, 70000));
+}
+
+#[test]
+pub fn emits_vote_event() {
+	let mut env_deps = new_env_and_instantiate(None);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_1, 140000);
+	helpers::execute_stake_votes(&mut env_deps, SHARES_HOLDER_ACCOUNT_2, 110000);
+	helpers::execute_propose_transaction(
+		&mut env_deps,
+		SHARES_HOLDER_ACCOUNT_1,
+		vec![ProposedCourtMsgJsonable::SendCoin {
+			to: RANDOM_ACCOUNT_1.into(),
+			denom: "usei".into(),
+			amount: 1337u128.into(),
+		}],
+		1200,
+	);
+	let execute_response = helpers::execute(
+		&mut env_deps,
+		Some(MessageInfo {
+			sender: Addr::unchecked(SHARES_HOLDER_ACCOUNT_2),
+			funds: vec![],
+		}),
+		CourtExecuteMsg::VoteWeighted {
+			id: 0,
+			approve: 2500,
+			oppose: 7000,
+			abstain: 500,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		execute_response.events,
+		vec![cosmwasm_std::Event::new("vote")
+			.add_attribute("proposal_id", "0")
+			.add_attribute("voter", SHARES_HOLDER_ACCOUNT_2)
+			.add_attribute("votes", "110000")
+			.add_attribute("vote", "split")
+			.add_attribute("approve_bps", "2500")
+			.add_attribute("oppose_bps", "7000")
+			.add_attribute("abstain_bps", "500")]
+	);
+}
@@ -25,14 +25,19 @@ pub fn mint_to_workaround(
 }
 
 pub fn mint_workaround(denom: &str, amount: u128) -> StdResult<SeiMsg> {
+	record_mint_workaround(denom, amount)?;
+	Ok(SeiMsg::MintTokens {
+		amount: coin(amount, denom),
+	})
+}
+
+pub fn record_mint_workaround(denom: &str, amount: u128) -> StdResult<()> {
 	let cur_supply = total_supply_workaround(denom);
 	storage_write(
 		denom.as_bytes(),
 		&cur_supply.checked_add(amount.into())?.u128().to_le_bytes(),
 	);
-	Ok(SeiMsg::MintTokens {
-		amount: coin(amount, denom),
-	})
+	Ok(())
 }
 
 pub fn total_supply_workaround(denom: &str) -> Uint128 {
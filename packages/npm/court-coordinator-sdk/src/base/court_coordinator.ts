@@ -4,7 +4,7 @@
  * DO NOT MODIFY IT BY HAND.
  * The Rust definition of the associated structs is the source of truth!!
  */
-import {Addr, ArrayOfUint32, ArrayOf_CourtQueryResponseTransactionProposal, ArrayOf_CourtQueryResponseUserVote, ArrayOf_CourtQueryUserWithActiveProposal, CourtAdminExecuteMsg, CourtAppConfigJsonable, CourtExecuteMsg, CourtQueryMsg, CourtQueryResponseDenom, CourtQueryResponseTotalSupply, CourtQueryUserWithActiveProposal, CourtUserStatsJsonable, CourtUserVoteInfoJsonable, CourtUserVoteStatus, Nullable_CourtQueryResponseTransactionProposal, ProposedCourtMsgJsonable, Uint32} from "./types.js";
+import {Addr, ArrayOfUint32, ArrayOf_CourtProposalTrackJsonable, ArrayOf_CourtQueryResponseUserVote, ArrayOf_CourtQueryUserWithActiveProposal, ArrayOf_String, Binary, CourtAdminExecuteMsg, CourtAppConfigJsonable, CourtExecuteMsg, CourtProposalOption, CourtQueryMsg, CourtQueryProposalFilter, CourtQueryResponseDenom, CourtQueryResponseOptimisticAllowlist, CourtQueryResponseProposals, CourtQueryResponseSimulatedProposal, CourtQueryResponseTotalSupply, CourtQueryUserWithActiveProposal, CourtSignedVote, CourtUserStatsJsonable, CourtUserVoteInfoJsonable, CourtUserVoteStatus, Nullable_CourtQueryResponseProposalTally, Nullable_CourtQueryResponseTransactionProposal, ProposedCourtMsgJsonable, TransactionProposalMetadataJsonable, Uint32, Uint641} from "./types.js";
 import {Coin} from "@cosmjs/amino";
 import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
 import {QueryClient} from "@cosmjs/stargate";
@@ -33,8 +33,27 @@ export class CourtCoordinatorContract<Q extends QueryClient & WasmExtension> ext
 		const msg = "proposal_amount" satisfies CourtQueryMsg;
 		return this.query(msg);
 	}
+	/** The `type_url`s which stargate proposals may use */
+	queryStargateAllowlist(): Promise<ArrayOf_String> {
+		const msg = "stargate_allowlist" satisfies CourtQueryMsg;
+		return this.query(msg);
+	}
+	/** Who may make optimistic proposals, and which kinds of messages anyone may make optimistic proposals with */
+	queryOptimisticAllowlist(): Promise<CourtQueryResponseOptimisticAllowlist> {
+		const msg = "optimistic_allowlist" satisfies CourtQueryMsg;
+		return this.query(msg);
+	}
+	/** The proposal tracks in the order proposals are checked against them, `info.track` of a proposal is an index of this */
+	queryProposalTracks(): Promise<ArrayOf_CourtProposalTrackJsonable> {
+		const msg = "proposal_tracks" satisfies CourtQueryMsg;
+		return this.query(msg);
+	}
 	/** Gets a specific proposal, may be null */
 	queryGetProposal(args: {
+		/** Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming nothing else changes. Statuses are projected from the current votes, so past times may not be accurate. */
+		"at_time_ms"?: number | null,
+		/** If `true`, `decoded_messages` will contain human-readable details of each message */
+		"decode"?: boolean | null,
 		/** The proposal ID */
 		"id": number
 	}): Promise<Nullable_CourtQueryResponseTransactionProposal> {
@@ -43,16 +62,45 @@ export class CourtCoordinatorContract<Q extends QueryClient & WasmExtension> ext
 	}
 	/** Returns a list of proposals based on the range provided */
 	queryGetProposals(args: {
+		/** Only include proposals which come after this proposal ID in the specified order, applied before `skip`.
+
+Useful for pagination, as this can be set to the last `proposal_id` of the previous page. */
+		"after"?: number | null,
+		/** Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming nothing else changes. Statuses are projected from the current votes, so past times may not be accurate. */
+		"at_time_ms"?: number | null,
 		/** if `false`, array will be in ascending order. if `true`, descending order. */
 		"descending": boolean,
+		/** Only include proposals which match all the specified criteria */
+		"filter"?: CourtQueryProposalFilter | null,
 		/** The maximum length of the array */
 		"limit"?: number | null,
-		/** Where to start the array from */
+		/** If `true`, `messages` will be empty for every proposal returned, including the messages of each option */
+		"omit_messages"?: boolean | null,
+		/** Where to start the array from, only counting proposals which match the `filter`. Use `after` with the `next_after` cursor returned to get the next page instead. */
 		"skip"?: number | null
-	}): Promise<ArrayOf_CourtQueryResponseTransactionProposal> {
+	}): Promise<CourtQueryResponseProposals> {
 		const msg = {"get_proposals": args} satisfies CourtQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns a breakdown of a proposal's votes and what it would take for it to pass or fail, may be null */
+	queryProposalTally(args: {
+		/** Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming nothing else changes. Statuses are projected from the current votes, so past times may not be accurate. */
+		"at_time_ms"?: number | null,
+		/** The proposal ID */
+		"id": number
+	}): Promise<Nullable_CourtQueryResponseProposalTally> {
+		const msg = {"proposal_tally": args} satisfies CourtQueryMsg;
+		return this.query(msg);
+	}
+	/** Runs the same checks as `ProposeTransaction` without creating a proposal, and returns what would be executed if it passes, assuming no one else votes on it. */
+	querySimulateProposal(args: {
+		"expiry_time_seconds": number,
+		"msgs": ProposedCourtMsgJsonable[],
+		"proposer": Addr
+	}): Promise<CourtQueryResponseSimulatedProposal> {
+		const msg = {"simulate_proposal": args} satisfies CourtQueryMsg;
+		return this.query(msg);
+	}
 	queryUserStats(args: {
 		"user": Addr
 	}): Promise<CourtUserStatsJsonable> {
@@ -66,6 +114,14 @@ export class CourtCoordinatorContract<Q extends QueryClient & WasmExtension> ext
 		const msg = {"user_vote_info": args} satisfies CourtQueryMsg;
 		return this.query(msg);
 	}
+	/** The nonce the next vote the user signs for the proposal must have, see [CourtSignedVote] */
+	querySignedVoteNonce(args: {
+		"proposal_id": number,
+		"user": Addr
+	}): Promise<Uint641> {
+		const msg = {"signed_vote_nonce": args} satisfies CourtQueryMsg;
+		return this.query(msg);
+	}
 	queryGetUsersWithActiveProposals(args: {
 		"after"?: CourtQueryUserWithActiveProposal | null,
 		"descending": boolean,
@@ -102,6 +158,13 @@ export class CourtCoordinatorContract<Q extends QueryClient & WasmExtension> ext
 		const msg = "stake" satisfies CourtExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Like `Stake`, but all your staked votes can't be unstaked until `lock_seconds` from now, and they have more voting power the longer they remain locked for. */
+	buildStakeLockedIx(args: {
+		"lock_seconds": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"stake_locked": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 	/** Have your voting shares which you previously staked returned to you.
 
 You may not do this while you are voting on active proposals */
@@ -117,6 +180,61 @@ You may not do this while you are voting on active proposals */
 		const msg = {"vote": args} satisfies CourtExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Like `Vote`, but your votes are split between the options. The amounts are in basis points and must add up to 10000. */
+	buildVoteWeightedIx(args: {
+		"abstain": number,
+		"approve": number,
+		"id": number,
+		"oppose": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"vote_weighted": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Vote for one of the options of a multiple-choice proposal, the first option is 0. */
+	buildVoteChoiceIx(args: {
+		"id": number,
+		"option": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"vote_choice": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Like `Vote`, but for multiple proposals at once, as a list of `(id, vote)` pairs. */
+	buildVoteManyIx(args: {
+		/** If `true`, proposals which are no longer pending are skipped rather than failing the whole transaction. */
+		"skip_inactive"?: boolean,
+		"votes": [number, CourtUserVoteStatus][]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"vote_many": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Casts votes which users have signed on their behalf, so that they don't need to pay gas to vote. Anyone may submit these. */
+	buildSubmitSignedVotesIx(args: {
+		/** If `true`, votes which can't be cast for any reason, such as an invalid signature or nonce or the proposal no longer being pending, are skipped rather than failing the whole transaction. */
+		"skip_invalid"?: boolean,
+		"votes": CourtSignedVote[]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"submit_signed_votes": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Commit to a vote on a private proposal without revealing it, `hash` is the SHA-256 hash of the [CourtVoteCommitmentPayload] of your vote. You can replace your commitment until voting ends.
+
+Committed votes can't be unstaked until the proposal is finalized, even if they're never revealed. Once it is, `DeactivateVotes` has to be called to unlock them, like with any other vote. */
+	buildCommitVoteIx(args: {
+		"hash": Binary,
+		"id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"commit_vote": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Reveal the vote you committed to once voting on a private proposal has ended, only revealed votes are counted. */
+	buildRevealVoteIx(args: {
+		"id": number,
+		"salt": Binary,
+		"vote": CourtUserVoteStatus
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"reveal_vote": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 	/** This must be done before unstaking */
 	buildDeactivateVotesIx(args: {
 		"limit"?: number | null,
@@ -127,12 +245,42 @@ You may not do this while you are voting on active proposals */
 	}
 	/** Propose a new transaction */
 	buildProposeTransactionIx(args: {
+		/** If `true`, the proposal may pass once the configured expedited voting period has passed, as long as it meets the higher expedited turnout and pass requirements. Otherwise it's treated like any other proposal. */
+		"expedited"?: boolean,
 		"expiry_time_seconds": number,
-		"msgs": ProposedCourtMsgJsonable[]
+		/** What the proposal is about, for display purposes */
+		"metadata"?: TransactionProposalMetadataJsonable | null,
+		"msgs": ProposedCourtMsgJsonable[],
+		/** If `true`, the proposal passes once voting ends unless enough votes oppose or veto it, regardless of turnout. Only designated proposers may do this, unless all messages are of an allowlisted kind. */
+		"optimistic"?: boolean,
+		/** If `true`, votes are committed without revealing them until voting ends, so that the running tally doesn't sway anyone. They're revealed during the configured reveal period, and only the revealed votes count.
+
+Your approving vote is committed with an empty salt, so you have to reveal it like everyone else. You may replace it with a commitment of your own. */
+		"private"?: boolean
 	}, funds?: Coin[]): ExecuteInstruction {
 		const msg = {"propose_transaction": args} satisfies CourtExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Propose a choice between several transactions, only the option with the most votes may be executed.
+
+Your votes are counted as abstaining until you vote for an option yourself. */
+	buildProposeMultipleChoiceIx(args: {
+		"expiry_time_seconds": number,
+		/** What the proposal is about, for display purposes */
+		"metadata"?: TransactionProposalMetadataJsonable | null,
+		"options": CourtProposalOption[]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"propose_multiple_choice": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Propose a non-binding poll which only has a title, a description, and optionally a link to its content. It's voted on like any other proposal, but there's nothing to execute once it passes. */
+	buildProposeSignalIx(args: {
+		"expiry_time_seconds": number,
+		"metadata": TransactionProposalMetadataJsonable
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"propose_signal": args} satisfies CourtExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 	/** If a proposal has passed, this is how you execute it. */
 	buildExecuteProposalIx(args: {
 		"id": number
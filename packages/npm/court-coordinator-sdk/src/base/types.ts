@@ -18,11 +18,6 @@
  * via the `definition` "Addr".
  */
 export type Addr = string;
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "TransactionProposalExecutionStatus".
- */
-export type TransactionProposalExecutionStatus = "not_executed" | "executed";
 /**
  * A thin wrapper around u128 that is using strings for JSON encoding/decoding, such that the full u128 range can be used for clients that convert JSON numbers to floats, like JavaScript and jq.
  *
@@ -40,94 +35,21 @@ export type TransactionProposalExecutionStatus = "not_executed" | "executed";
  * via the `definition` "Uint128".
  */
 export type Uint128 = string;
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "ProposedCourtMsgJsonable".
- */
-export type ProposedCourtMsgJsonable =
-  | {
-      send_coin: {
-        amount: Uint128;
-        denom: FungibleAssetKindString;
-        to: string;
-      };
-    }
-  | {
-      execute_evm_contract: {
-        contract: string;
-        msg: Binary;
-        value: Uint128;
-      };
-    }
-  | {
-      execute_wasm_contract: {
-        contract: Addr;
-        funds: Coin[];
-        msg: Binary;
-      };
-    }
-  | {
-      upgrade_wasm_contract: {
-        contract: Addr;
-        msg: Binary;
-        new_code_id: number;
-      };
-    }
-  | {
-      change_wasm_contract_admin: {
-        contract: Addr;
-        new_admin: Addr;
-      };
-    }
-  | {
-      clear_wasm_contract_admin: {
-        contract: Addr;
-      };
-    }
-  | {
-      tokenfactory_mint: {
-        tokens: Coin;
-      };
-    };
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "FungibleAssetKindString".
  */
 export type FungibleAssetKindString = string;
-/**
- * Binary is a wrapper around Vec<u8> to add base64 de/serialization with serde. It also adds some helper methods to help encode inline.
- *
- * This is only needed as serde-json-{core,wasm} has a horrible encoding for Vec<u8>. See also <https://github.com/CosmWasm/cosmwasm/blob/main/docs/MESSAGE_TYPES.md>.
- *
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Binary".
- */
-export type Binary = string;
-/**
- * Transaction proposal status, this is derived from the actual proposal struct rather than as a property.
- *
- * The way this is derived is documented below. ```rust,ignore let proposal_status = if transaction_executed_status == TransactionExecutionStatus::Executed { TransactionProposalStatus::Executed } else if transaction_executed_status == TransactionExecutionStatus::Expired { TransactionProposalStatus::ExecutionExpired } else if expiry < last_config_change_time { TransactionProposalStatus::Rejected } else if current_time < expiry { if ((votes_for + votes_against) * 100 / token_supply) >= minimum_vote_turnout_percent || (votes_for * 100 / token_supply) >= minimum_vote_pass_percent { TransactionProposalStatus::Passed } else { TransactionProposalStatus::Pending } } else if current_time >= expiry && ( ((votes_for + votes_against) * 100 / token_supply) < minimum_vote_turnout_percent || (votes_for * 100 / (votes_for + votes_against)) < minimum_vote_pass_percent ) { TransactionProposalStatus::Rejected } else { TransactionProposalStatus::Passed } ```
- *
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "TransactionProposalStatus".
- */
-export type TransactionProposalStatus =
-  | "pending"
-  | "rejected"
-  | "passed"
-  | "executed"
-  | "execution_expired"
-  | "rejected_or_expired";
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Array_of_CourtQueryResponseTransactionProposal".
+ * via the `definition` "Array_of_CourtProposalTrackJsonable".
  */
-export type ArrayOf_CourtQueryResponseTransactionProposal = CourtQueryResponseTransactionProposal[];
+export type ArrayOf_CourtProposalTrackJsonable = CourtProposalTrackJsonable[];
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "CourtUserVoteStatus".
  */
-export type CourtUserVoteStatus = "abstain" | "approve" | "oppose";
+export type CourtUserVoteStatus = ("abstain" | "approve" | "oppose") | "split" | "veto" | "choice";
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "Array_of_CourtQueryResponseUserVote".
@@ -138,206 +60,982 @@ export type ArrayOf_CourtQueryResponseUserVote = CourtQueryResponseUserVote[];
  * via the `definition` "Array_of_CourtQueryUserWithActiveProposal".
  */
 export type ArrayOf_CourtQueryUserWithActiveProposal = CourtQueryUserWithActiveProposal[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_String".
+ */
+export type ArrayOf_String = string[];
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "Array_of_uint32".
  */
 export type ArrayOfUint32 = number[];
 /**
+ * The message types of the bank module.
+ *
+ * See https://github.com/cosmos/cosmos-sdk/blob/v0.40.0/proto/cosmos/bank/v1beta1/tx.proto
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtAdminExecuteMsg".
+ * via the `definition` "BankMsg".
  */
-export type CourtAdminExecuteMsg =
+export type BankMsg =
   | {
-      change_config: {
-        execution_expiry_time_seconds?: number | null;
-        max_proposal_expiry_time_seconds?: number | null;
-        minimum_vote_pass_percent?: number | null;
-        minimum_vote_proposal_percent?: number | null;
-        minimum_vote_turnout_percent?: number | null;
+      send: {
+        amount: Coin[];
+        to_address: string;
       };
     }
   | {
-      change_admin: {
-        admin: Addr;
+      burn: {
+        amount: Coin[];
       };
+    };
+/**
+ * Binary is a wrapper around Vec<u8> to add base64 de/serialization with serde. It also adds some helper methods to help encode inline.
+ *
+ * This is only needed as serde-json-{core,wasm} has a horrible encoding for Vec<u8>. See also <https://github.com/CosmWasm/cosmwasm/blob/main/docs/MESSAGE_TYPES.md>.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Binary".
+ */
+export type Binary = string;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "OrderType".
+ */
+export type OrderType = "Limit" | "Market";
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PositionDirection".
+ */
+export type PositionDirection = "Long" | "Short";
+/**
+ * A fixed-point decimal value with 18 fractional digits, i.e. Decimal(1_000_000_000_000_000_000) == 1.0
+ *
+ * The greatest possible value that can be represented is 340282366920938463463.374607431768211455 (which is (2^128 - 1) / 10^18)
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Decimal".
+ */
+export type Decimal = string;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CosmosMsg_for_SeiMsg".
+ */
+export type CosmosMsgFor_SeiMsg =
+  | {
+      bank: BankMsg;
     }
   | {
-      allow_new_proposals: {
-        allowed: boolean;
-      };
+      custom: SeiMsg;
     }
   | {
-      mint_shares: {
-        amount: Uint128;
-        receiver: Addr;
+      stargate: {
+        type_url: string;
+        value: Binary;
       };
+    }
+  | {
+      ibc: IbcMsg;
+    }
+  | {
+      wasm: WasmMsg;
+    }
+  | {
+      gov: GovMsg;
     };
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtExecuteMsg".
+ * via the `definition` "SeiMsg".
  */
-export type CourtExecuteMsg =
+export type SeiMsg =
   | {
-      admin: CourtAdminExecuteMsg;
+      place_orders: {
+        contract_address: Addr;
+        funds: Coin[];
+        orders: Order[];
+      };
     }
-  | "stake"
-  | "unstake"
   | {
-      vote: {
-        id: number;
-        vote: CourtUserVoteStatus;
+      cancel_orders: {
+        cancellations: Cancellation[];
+        contract_address: Addr;
       };
     }
   | {
-      deactivate_votes: {
-        limit?: number | null;
-        user?: Addr | null;
+      create_denom: {
+        subdenom: string;
       };
     }
   | {
-      propose_transaction: {
-        expiry_time_seconds: number;
-        msgs: ProposedCourtMsgJsonable[];
+      mint_tokens: {
+        amount: Coin;
       };
     }
   | {
-      execute_proposal: {
-        id: number;
+      burn_tokens: {
+        amount: Coin;
       };
-    };
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtQueryMsg".
- */
-export type CourtQueryMsg =
-  | "config"
-  | "denom"
-  | "total_supply"
-  | "proposal_amount"
+    }
   | {
-      get_proposal: {
+      change_admin: {
+        denom: string;
+        new_admin_address: string;
+      };
+    }
+  | {
+      set_metadata: {
+        metadata: Metadata;
+      };
+    }
+  | {
+      delegate_call_evm: {
         /**
-         * The proposal ID
+         * Base64 encoded binary data to pass to the contract
          */
-        id: number;
+        data: string;
+        /**
+         * The address of the EVM contract to call
+         */
+        to: string;
       };
     }
   | {
-      get_proposals: {
+      call_evm: {
         /**
-         * if `false`, array will be in ascending order. if `true`, descending order.
+         * Base64 encoded binary data to pass to the contract
          */
-        descending: boolean;
+        data: string;
         /**
-         * The maximum length of the array
+         * The address of the EVM contract to call
          */
-        limit?: number | null;
+        to: string;
         /**
-         * Where to start the array from
+         * The amount to send along with the transaction. 0 if non-payable function is called.
          */
-        skip?: number | null;
-      };
-    }
-  | {
-      user_stats: {
-        user: Addr;
-      };
-    }
-  | {
-      user_vote_info: {
-        proposal_id: number;
-        user: Addr;
+        value: Uint128;
       };
-    }
+    };
+/**
+ * These are messages in the IBC lifecycle. Only usable by IBC-enabled contracts (contracts that directly speak the IBC protocol via 6 entry points)
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "IbcMsg".
+ */
+export type IbcMsg =
   | {
-      get_users_with_active_proposals: {
-        after?: CourtQueryUserWithActiveProposal | null;
-        descending: boolean;
-        limit?: number | null;
+      transfer: {
+        /**
+         * packet data only supports one coin https://github.com/cosmos/cosmos-sdk/blob/v0.40.0/proto/ibc/applications/transfer/v1/transfer.proto#L11-L20
+         */
+        amount: Coin;
+        /**
+         * existing channel to send the tokens over
+         */
+        channel_id: string;
+        /**
+         * when packet times out, measured on remote chain
+         */
+        timeout: IbcTimeout;
+        /**
+         * address on the remote chain to receive these tokens
+         */
+        to_address: string;
       };
     }
   | {
-      get_user_active_proposals: {
-        descending: boolean;
-        limit?: number | null;
-        skip?: number | null;
-        user: Addr;
+      send_packet: {
+        channel_id: string;
+        data: Binary;
+        /**
+         * when packet times out, measured on remote chain
+         */
+        timeout: IbcTimeout;
       };
     }
   | {
-      get_proposal_user_votes: {
-        after?: Addr | null;
-        descending: boolean;
-        limit?: number | null;
-        proposal_id: number;
+      close_channel: {
+        channel_id: string;
       };
     };
 /**
+ * A point in time in nanosecond precision.
+ *
+ * This type can represent times from 1970-01-01T00:00:00Z to 2554-07-21T23:34:33Z.
+ *
+ * ## Examples
+ *
+ * ``` # use cosmwasm_std::Timestamp; let ts = Timestamp::from_nanos(1_000_000_202); assert_eq!(ts.nanos(), 1_000_000_202); assert_eq!(ts.seconds(), 1); assert_eq!(ts.subsec_nanos(), 202);
+ *
+ * let ts = ts.plus_seconds(2); assert_eq!(ts.nanos(), 3_000_000_202); assert_eq!(ts.seconds(), 3); assert_eq!(ts.subsec_nanos(), 202); ```
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Nullable_CourtQueryResponseTransactionProposal".
- */
-export type Nullable_CourtQueryResponseTransactionProposal = CourtQueryResponseTransactionProposal | null;
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "uint32".
- */
-export type Uint32 = number;
-
-export interface CrownfiSdkMakerAutogen {}
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtQueryResponseTransactionProposal".
+ * via the `definition` "Timestamp".
  */
-export interface CourtQueryResponseTransactionProposal {
-  info: TransactionProposalInfoJsonable;
-  messages: ProposedCourtMsgJsonable[];
-  proposal_id: number;
-  status: TransactionProposalStatus;
-}
+export type Timestamp = Uint64;
 /**
+ * A thin wrapper around u64 that is using strings for JSON encoding/decoding, such that the full u64 range can be used for clients that convert JSON numbers to floats, like JavaScript and jq.
+ *
+ * # Examples
+ *
+ * Use `from` to create instances of this and `u64` to get the value out:
+ *
+ * ``` # use cosmwasm_std::Uint64; let a = Uint64::from(42u64); assert_eq!(a.u64(), 42);
+ *
+ * let b = Uint64::from(70u32); assert_eq!(b.u64(), 70); ```
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "TransactionProposalInfoJsonable".
+ * via the `definition` "Uint64".
  */
-export interface TransactionProposalInfoJsonable {
-  execution_status: TransactionProposalExecutionStatus;
-  expiry_timestamp_ms: number;
-  proposer: Addr;
-  votes_abstain: Uint128;
-  votes_against: Uint128;
-  votes_for: Uint128;
-}
+export type Uint64 = string;
 /**
+ * The message types of the wasm module.
+ *
+ * See https://github.com/CosmWasm/wasmd/blob/v0.14.0/x/wasm/internal/types/tx.proto
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Coin".
+ * via the `definition` "WasmMsg".
  */
-export interface Coin {
+export type WasmMsg =
+  | {
+      execute: {
+        contract_addr: string;
+        funds: Coin[];
+        /**
+         * msg is the json-encoded ExecuteMsg struct (as raw Binary)
+         */
+        msg: Binary;
+      };
+    }
+  | {
+      instantiate: {
+        admin?: string | null;
+        code_id: number;
+        funds: Coin[];
+        /**
+         * A human-readable label for the contract.
+         *
+         * Valid values should: - not be empty - not be bigger than 128 bytes (or some chain-specific limit) - not start / end with whitespace
+         */
+        label: string;
+        /**
+         * msg is the JSON-encoded InstantiateMsg struct (as raw Binary)
+         */
+        msg: Binary;
+      };
+    }
+  | {
+      instantiate2: {
+        admin?: string | null;
+        code_id: number;
+        funds: Coin[];
+        /**
+         * A human-readable label for the contract.
+         *
+         * Valid values should: - not be empty - not be bigger than 128 bytes (or some chain-specific limit) - not start / end with whitespace
+         */
+        label: string;
+        /**
+         * msg is the JSON-encoded InstantiateMsg struct (as raw Binary)
+         */
+        msg: Binary;
+        salt: Binary;
+      };
+    }
+  | {
+      migrate: {
+        contract_addr: string;
+        /**
+         * msg is the json-encoded MigrateMsg struct that will be passed to the new code
+         */
+        msg: Binary;
+        /**
+         * the code_id of the new logic to place in the given contract
+         */
+        new_code_id: number;
+      };
+    }
+  | {
+      update_admin: {
+        admin: string;
+        contract_addr: string;
+      };
+    }
+  | {
+      clear_admin: {
+        contract_addr: string;
+      };
+    };
+/**
+ * This message type allows the contract interact with the [x/gov] module in order to cast votes.
+ *
+ * [x/gov]: https://github.com/cosmos/cosmos-sdk/tree/v0.45.12/x/gov
+ *
+ * ## Examples
+ *
+ * Cast a simple vote:
+ *
+ * ``` # use cosmwasm_std::{ #     HexBinary, #     Storage, Api, Querier, DepsMut, Deps, entry_point, Env, StdError, MessageInfo, #     Response, QueryResponse, # }; # type ExecuteMsg = (); use cosmwasm_std::{GovMsg, VoteOption};
+ *
+ * #[entry_point] pub fn execute( deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg, ) -> Result<Response, StdError> { // ... Ok(Response::new().add_message(GovMsg::Vote { proposal_id: 4, vote: VoteOption::Yes, })) } ```
+ *
+ * Cast a weighted vote:
+ *
+ * ``` # use cosmwasm_std::{ #     HexBinary, #     Storage, Api, Querier, DepsMut, Deps, entry_point, Env, StdError, MessageInfo, #     Response, QueryResponse, # }; # type ExecuteMsg = (); # #[cfg(feature = "cosmwasm_1_2")] use cosmwasm_std::{Decimal, GovMsg, VoteOption, WeightedVoteOption};
+ *
+ * # #[cfg(feature = "cosmwasm_1_2")] #[entry_point] pub fn execute( deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg, ) -> Result<Response, StdError> { // ... Ok(Response::new().add_message(GovMsg::VoteWeighted { proposal_id: 4, options: vec![ WeightedVoteOption { option: VoteOption::Yes, weight: Decimal::percent(65), }, WeightedVoteOption { option: VoteOption::Abstain, weight: Decimal::percent(35), }, ], })) } ```
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "GovMsg".
+ */
+export type GovMsg =
+  | {
+      vote: {
+        proposal_id: number;
+        /**
+         * The vote option.
+         *
+         * This should be called "option" for consistency with Cosmos SDK. Sorry for that. See <https://github.com/CosmWasm/cosmwasm/issues/1571>.
+         */
+        vote: VoteOption;
+      };
+    }
+  | {
+      vote_weighted: {
+        options: WeightedVoteOption[];
+        proposal_id: number;
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "VoteOption".
+ */
+export type VoteOption = "yes" | "no" | "abstain" | "no_with_veto";
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtAdminExecuteMsg".
+ */
+export type CourtAdminExecuteMsg =
+  | {
+      change_config: CourtConfigChanges;
+    }
+  | {
+      change_admin: {
+        admin: Addr;
+      };
+    }
+  | {
+      allow_new_proposals: {
+        allowed: boolean;
+      };
+    }
+  | {
+      mint_shares: {
+        amount: Uint128;
+        receiver: Addr;
+      };
+    }
+  | {
+      allow_stargate_messages: {
+        allowed: boolean;
+        type_urls: string[];
+      };
+    }
+  | {
+      allow_optimistic_proposals: {
+        allowed: boolean;
+        msg_kinds: string[];
+        proposers: Addr[];
+      };
+    }
+  | {
+      set_proposal_track: {
+        track: CourtProposalTrackJsonable;
+      };
+    }
+  | {
+      remove_proposal_track: {
+        name: string;
+      };
+    };
+/**
+ * Which votes proposal turnout is measured against
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQuorumSupply".
+ */
+export type CourtQuorumSupply = "total" | "staked" | "circulating";
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtExecuteMsg".
+ */
+export type CourtExecuteMsg =
+  | {
+      admin: CourtAdminExecuteMsg;
+    }
+  | "stake"
+  | {
+      stake_locked: {
+        lock_seconds: number;
+      };
+    }
+  | "unstake"
+  | {
+      vote: {
+        id: number;
+        vote: CourtUserVoteStatus;
+      };
+    }
+  | {
+      vote_weighted: {
+        abstain: number;
+        approve: number;
+        id: number;
+        oppose: number;
+      };
+    }
+  | {
+      vote_choice: {
+        id: number;
+        option: number;
+      };
+    }
+  | {
+      vote_many: {
+        /**
+         * If `true`, proposals which are no longer pending are skipped rather than failing the whole transaction.
+         */
+        skip_inactive?: boolean;
+        votes: [number, CourtUserVoteStatus][];
+      };
+    }
+  | {
+      submit_signed_votes: {
+        /**
+         * If `true`, votes which can't be cast for any reason, such as an invalid signature or nonce or the proposal no longer being pending, are skipped rather than failing the whole transaction.
+         */
+        skip_invalid?: boolean;
+        votes: CourtSignedVote[];
+      };
+    }
+  | {
+      commit_vote: {
+        hash: Binary;
+        id: number;
+      };
+    }
+  | {
+      reveal_vote: {
+        id: number;
+        salt: Binary;
+        vote: CourtUserVoteStatus;
+      };
+    }
+  | {
+      deactivate_votes: {
+        limit?: number | null;
+        user?: Addr | null;
+      };
+    }
+  | {
+      propose_transaction: {
+        /**
+         * If `true`, the proposal may pass once the configured expedited voting period has passed, as long as it meets the higher expedited turnout and pass requirements. Otherwise it's treated like any other proposal.
+         */
+        expedited?: boolean;
+        expiry_time_seconds: number;
+        /**
+         * What the proposal is about, for display purposes
+         */
+        metadata?: TransactionProposalMetadataJsonable | null;
+        msgs: ProposedCourtMsgJsonable[];
+        /**
+         * If `true`, the proposal passes once voting ends unless enough votes oppose or veto it, regardless of turnout. Only designated proposers may do this, unless all messages are of an allowlisted kind.
+         */
+        optimistic?: boolean;
+        /**
+         * If `true`, votes are committed without revealing them until voting ends, so that the running tally doesn't sway anyone. They're revealed during the configured reveal period, and only the revealed votes count.
+         *
+         * Your approving vote is committed with an empty salt, so you have to reveal it like everyone else. You may replace it with a commitment of your own.
+         */
+        private?: boolean;
+      };
+    }
+  | {
+      propose_multiple_choice: {
+        expiry_time_seconds: number;
+        /**
+         * What the proposal is about, for display purposes
+         */
+        metadata?: TransactionProposalMetadataJsonable | null;
+        options: CourtProposalOption[];
+      };
+    }
+  | {
+      propose_signal: {
+        expiry_time_seconds: number;
+        metadata: TransactionProposalMetadataJsonable;
+      };
+    }
+  | {
+      execute_proposal: {
+        id: number;
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtVoteSignatureType".
+ */
+export type CourtVoteSignatureType = "secp256k1" | "eip712";
+/**
+ * This is a wrapper around Vec<u8> to add hex de/serialization with serde. It also adds some helper methods to help encode inline.
+ *
+ * This is similar to `cosmwasm_std::Binary` but uses hex. See also <https://github.com/CosmWasm/cosmwasm/blob/main/docs/MESSAGE_TYPES.md>.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "HexBinary".
+ */
+export type HexBinary = string;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "ProposedCourtMsgJsonable".
+ */
+export type ProposedCourtMsgJsonable =
+  | {
+      send_coin: {
+        amount: Uint128;
+        denom: FungibleAssetKindString;
+        to: string;
+      };
+    }
+  | {
+      execute_evm_contract: {
+        contract: string;
+        msg: Binary;
+        value: Uint128;
+      };
+    }
+  | {
+      execute_wasm_contract: {
+        contract: Addr;
+        funds: Coin[];
+        msg: Binary;
+      };
+    }
+  | {
+      upgrade_wasm_contract: {
+        contract: Addr;
+        msg: Binary;
+        new_code_id: number;
+      };
+    }
+  | {
+      change_wasm_contract_admin: {
+        contract: Addr;
+        new_admin: Addr;
+      };
+    }
+  | {
+      clear_wasm_contract_admin: {
+        contract: Addr;
+      };
+    }
+  | {
+      tokenfactory_mint: {
+        tokens: Coin;
+      };
+    }
+  | {
+      stargate: {
+        type_url: string;
+        value: Binary;
+      };
+    }
+  | {
+      gov_vote: {
+        option: VoteOption;
+        proposal_id: number;
+      };
+    }
+  | {
+      gov_vote_weighted: {
+        options: WeightedVoteOption[];
+        proposal_id: number;
+      };
+    }
+  | {
+      gov_vote_by_tally: {
+        proposal_id: number;
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryMsg".
+ */
+export type CourtQueryMsg =
+  | "config"
+  | "denom"
+  | "total_supply"
+  | "proposal_amount"
+  | "stargate_allowlist"
+  | "optimistic_allowlist"
+  | "proposal_tracks"
+  | {
+      get_proposal: {
+        /**
+         * Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming nothing else changes. Statuses are projected from the current votes, so past times may not be accurate.
+         */
+        at_time_ms?: number | null;
+        /**
+         * If `true`, `decoded_messages` will contain human-readable details of each message
+         */
+        decode?: boolean | null;
+        /**
+         * The proposal ID
+         */
+        id: number;
+      };
+    }
+  | {
+      get_proposals: {
+        /**
+         * Only include proposals which come after this proposal ID in the specified order, applied before `skip`.
+         *
+         * Useful for pagination, as this can be set to the last `proposal_id` of the previous page.
+         */
+        after?: number | null;
+        /**
+         * Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming nothing else changes. Statuses are projected from the current votes, so past times may not be accurate.
+         */
+        at_time_ms?: number | null;
+        /**
+         * if `false`, array will be in ascending order. if `true`, descending order.
+         */
+        descending: boolean;
+        /**
+         * Only include proposals which match all the specified criteria
+         */
+        filter?: CourtQueryProposalFilter | null;
+        /**
+         * The maximum length of the array
+         */
+        limit?: number | null;
+        /**
+         * If `true`, `messages` will be empty for every proposal returned, including the messages of each option
+         */
+        omit_messages?: boolean | null;
+        /**
+         * Where to start the array from, only counting proposals which match the `filter`. Use `after` with the `next_after` cursor returned to get the next page instead.
+         */
+        skip?: number | null;
+      };
+    }
+  | {
+      proposal_tally: {
+        /**
+         * Evaluate the status at this unix timestamp (in milliseconds) instead of the current block time, assuming nothing else changes. Statuses are projected from the current votes, so past times may not be accurate.
+         */
+        at_time_ms?: number | null;
+        /**
+         * The proposal ID
+         */
+        id: number;
+      };
+    }
+  | {
+      simulate_proposal: {
+        expiry_time_seconds: number;
+        msgs: ProposedCourtMsgJsonable[];
+        proposer: Addr;
+      };
+    }
+  | {
+      user_stats: {
+        user: Addr;
+      };
+    }
+  | {
+      user_vote_info: {
+        proposal_id: number;
+        user: Addr;
+      };
+    }
+  | {
+      signed_vote_nonce: {
+        proposal_id: number;
+        user: Addr;
+      };
+    }
+  | {
+      get_users_with_active_proposals: {
+        after?: CourtQueryUserWithActiveProposal | null;
+        descending: boolean;
+        limit?: number | null;
+      };
+    }
+  | {
+      get_user_active_proposals: {
+        descending: boolean;
+        limit?: number | null;
+        skip?: number | null;
+        user: Addr;
+      };
+    }
+  | {
+      get_proposal_user_votes: {
+        after?: Addr | null;
+        descending: boolean;
+        limit?: number | null;
+        proposal_id: number;
+      };
+    };
+/**
+ * Transaction proposal status, this is derived from the actual proposal struct rather than as a property.
+ *
+ * The way this is derived is documented below. `quorum_supply` is the vote supply which turnout is measured against as of when the proposal was made, see [quorum_supply](crate::contract::shares::quorum_supply). As locked votes count for more, `max_total_votes` is `quorum_supply` multiplied by `max_stake_lock_vote_multiplier_percent`, and `uncast_votes` is the difference between it and `total_votes`. With quadratic voting, neither of these are bounded, so proposals can only pass before they expire if they're expedited. ```rust,ignore let proposal_status = if transaction_executed_status == TransactionExecutionStatus::Executed { if is_signal { TransactionProposalStatus::Passed } else { TransactionProposalStatus::Executed } } else if transaction_executed_status == TransactionExecutionStatus::Expired { TransactionProposalStatus::ExecutionExpired } else if expiry < last_config_change_time { TransactionProposalStatus::Rejected } else if current_time < expiry && is_private { TransactionProposalStatus::Pending } else if is_private && current_time < reveal_expiry { TransactionProposalStatus::Revealing } else if current_time < expiry && is_optimistic { if !((votes_against + votes_veto + uncast_votes) * 100 > optimistic_opposition_threshold_percent * quorum_supply) { TransactionProposalStatus::Passed } else { TransactionProposalStatus::Pending } } else if current_time < expiry { if ((votes_for + votes_against) * 100 / quorum_supply) >= minimum_vote_turnout_percent || (votes_for * 100 / max_total_votes) >= minimum_vote_pass_percent && !(the proposal would be vetoed if all remaining votes were vetoes) && !(is_multiple_choice && another option could still win if it got all the remaining votes) { TransactionProposalStatus::Passed } else if is_expedited && current_time >= expedited_expiry && (total_votes * 100 / quorum_supply) >= expedited_minimum_vote_turnout_percent && (votes_for * 100 / (votes_for + votes_against + votes_veto)) >= expedited_minimum_vote_pass_percent && !(veto_threshold_percent != 0 && votes_veto * 100 > veto_threshold_percent * total_votes) { TransactionProposalStatus::Passed } else { TransactionProposalStatus::Pending } } else if is_optimistic { if (votes_against + votes_veto) * 100 > optimistic_opposition_threshold_percent * quorum_supply { TransactionProposalStatus::Rejected } else if current_time > expiry + execution_expiry_time { TransactionProposalStatus::ExecutionExpired } else { TransactionProposalStatus::Passed } } else if ((votes_for + votes_against) * 100 / quorum_supply) < minimum_vote_turnout_percent { TransactionProposalStatus::Rejected } else if is_multiple_choice && (no option has more votes than every other option) { TransactionProposalStatus::Rejected } else if veto_threshold_percent != 0 && votes_veto * 100 > veto_threshold_percent * total_votes { TransactionProposalStatus::Vetoed } else if (votes_for * 100 / (votes_for + votes_against + votes_veto)) < minimum_vote_pass_percent { TransactionProposalStatus::Rejected } else if !is_signal && current_time > max(expiry, reveal_expiry) + execution_expiry_time { TransactionProposalStatus::ExecutionExpired } else { TransactionProposalStatus::Passed } ```
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "TransactionProposalStatus".
+ */
+export type TransactionProposalStatus =
+  | "pending"
+  | "rejected"
+  | "passed"
+  | "executed"
+  | "execution_expired"
+  | "rejected_or_expired"
+  | "vetoed"
+  | "revealing";
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "TransactionProposalExecutionStatus".
+ */
+export type TransactionProposalExecutionStatus = "not_executed" | "executed";
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_CourtQueryResponseProposalTally".
+ */
+export type Nullable_CourtQueryResponseProposalTally = CourtQueryResponseProposalTally | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_CourtQueryResponseTransactionProposal".
+ */
+export type Nullable_CourtQueryResponseTransactionProposal = CourtQueryResponseTransactionProposal | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "uint32".
+ */
+export type Uint32 = number;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "uint64".
+ */
+export type Uint641 = number;
+
+export interface CrownfiSdkMakerAutogen {}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtProposalTrackJsonable".
+ */
+export interface CourtProposalTrackJsonable {
+  execution_expiry_time_seconds: number;
+  max_proposal_expiry_time_seconds: number;
+  /**
+   * Messages must send or mint at least this amount of the asset to match.
+   */
+  min_amount?: CourtProposalTrackAmount | null;
+  minimum_vote_pass_percent: number;
+  minimum_vote_proposal_percent: number;
+  minimum_vote_turnout_percent: number;
+  /**
+   * Messages must be one of these kinds (e.g. "send_coin" or "upgrade_wasm_contract") to match, empty matches any kind.
+   */
+  msg_kinds?: string[];
+  name: string;
+  /**
+   * Messages must target one of these contracts to match, empty matches any message. EVM contracts may be specified with their 0x address, but are listed with the equivalent sei1 address.
+   */
+  targets?: string[];
+  /**
+   * Proposals can't be executed until this long after voting ends, even if they pass early. The execution expiry time only starts counting down once this has passed.
+   */
+  timelock_seconds?: number;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtProposalTrackAmount".
+ */
+export interface CourtProposalTrackAmount {
   amount: Uint128;
+  denom: FungibleAssetKindString;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseUserVote".
+ */
+export interface CourtQueryResponseUserVote {
+  info: CourtUserVoteInfoJsonable;
+  user: Addr;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtUserVoteInfoJsonable".
+ */
+export interface CourtUserVoteInfoJsonable {
+  active_votes: Uint128;
+  /**
+   * Only present when `vote` is "choice"
+   */
+  choice?: number | null;
+  /**
+   * Only present when `vote` is "split"
+   */
+  split?: CourtUserVoteSplit | null;
+  vote: CourtUserVoteStatus;
+}
+/**
+ * How a user's votes are split between the options, in basis points. These must add up to 10000.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtUserVoteSplit".
+ */
+export interface CourtUserVoteSplit {
+  abstain_bps: number;
+  approve_bps: number;
+  oppose_bps: number;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryUserWithActiveProposal".
+ */
+export interface CourtQueryUserWithActiveProposal {
+  proposal_id: number;
+  user: Addr;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Coin".
+ */
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Cancellation".
+ */
+export interface Cancellation {
+  asset_denom: string;
+  contract_address: string;
+  id: number;
+  order_type: OrderType;
+  position_direction: PositionDirection;
+  price: Decimal;
+  price_denom: string;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Order".
+ */
+export interface Order {
+  asset_denom: string;
+  data: string;
+  nominal: Decimal;
+  order_type: OrderType;
+  position_direction: PositionDirection;
+  price: Decimal;
+  price_denom: string;
+  quantity: Decimal;
+  status_description: string;
+}
+/**
+ * Replicates the cosmos-sdk bank module Metadata type
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Metadata".
+ */
+export interface Metadata {
+  base: string;
+  denom_units: DenomUnit[];
+  description: string;
+  display: string;
+  name: string;
+  symbol: string;
+}
+/**
+ * Replicates the cosmos-sdk bank module DenomUnit type
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "DenomUnit".
+ */
+export interface DenomUnit {
+  aliases: string[];
   denom: string;
+  exponent: number;
 }
 /**
+ * In IBC each package must set at least one type of timeout: the timestamp or the block height. Using this rather complex enum instead of two timeout fields we ensure that at least one timeout is set.
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtQueryResponseUserVote".
+ * via the `definition` "IbcTimeout".
  */
-export interface CourtQueryResponseUserVote {
-  info: CourtUserVoteInfoJsonable;
-  user: Addr;
+export interface IbcTimeout {
+  block?: IbcTimeoutBlock | null;
+  timestamp?: Timestamp | null;
 }
 /**
+ * IBCTimeoutHeight Height is a monotonically increasing data type that can be compared against another Height for the purposes of updating and freezing clients. Ordering is (revision_number, timeout_height)
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtUserVoteInfoJsonable".
+ * via the `definition` "IbcTimeoutBlock".
  */
-export interface CourtUserVoteInfoJsonable {
-  active_votes: Uint128;
-  vote: CourtUserVoteStatus;
+export interface IbcTimeoutBlock {
+  /**
+   * block height after which the packet times out. the height within the given revision
+   */
+  height: number;
+  /**
+   * the version that the client is currently on (e.g. after resetting the chain this could increment 1 as height drops to 0)
+   */
+  revision: number;
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "CourtQueryUserWithActiveProposal".
+ * via the `definition` "WeightedVoteOption".
  */
-export interface CourtQueryUserWithActiveProposal {
-  proposal_id: number;
-  user: Addr;
+export interface WeightedVoteOption {
+  option: VoteOption;
+  weight: Decimal;
+}
+/**
+ * Config options to change, options which aren't specified are left as-is.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtConfigChanges".
+ */
+export interface CourtConfigChanges {
+  execution_expiry_time_seconds?: number | null;
+  expedited_minimum_vote_pass_percent?: number | null;
+  expedited_minimum_vote_turnout_percent?: number | null;
+  expedited_voting_period_seconds?: number | null;
+  max_proposal_expiry_time_seconds?: number | null;
+  max_quiet_ending_extensions?: number | null;
+  max_stake_lock_seconds?: number | null;
+  max_stake_lock_vote_multiplier_percent?: number | null;
+  max_user_votes?: Uint128 | null;
+  max_user_votes_bps?: number | null;
+  min_proposal_expiry_time_seconds?: number | null;
+  min_stake_age_seconds?: number | null;
+  minimum_vote_pass_percent?: number | null;
+  minimum_vote_proposal_percent?: number | null;
+  minimum_vote_turnout_percent?: number | null;
+  optimistic_opposition_threshold_percent?: number | null;
+  quadratic_voting?: boolean | null;
+  quiet_ending_extension_seconds?: number | null;
+  quiet_ending_window_seconds?: number | null;
+  quorum_supply?: CourtQuorumSupply | null;
+  reveal_period_seconds?: number | null;
+  veto_threshold_percent?: number | null;
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
@@ -347,11 +1045,121 @@ export interface CourtAppConfigJsonable {
   admin: Addr;
   allow_new_proposals: boolean;
   execution_expiry_time_seconds: number;
+  /**
+   * ...and this percentage of the votes cast approve. These should be higher than the normal requirements.
+   */
+  expedited_minimum_vote_pass_percent: number;
+  /**
+   * Expedited proposals pass once `expedited_voting_period_seconds` have passed if this percentage of the vote supply has voted...
+   */
+  expedited_minimum_vote_turnout_percent: number;
+  /**
+   * 0 disables expedited proposals.
+   */
+  expedited_voting_period_seconds: number;
   last_config_change_timestamp_ms: number;
   max_proposal_expiry_time_seconds: number;
+  /**
+   * ...up to this many times. 0 disables extensions.
+   */
+  max_quiet_ending_extensions: number;
+  /**
+   * The longest users may lock their staked votes for, 0 disables locking.
+   */
+  max_stake_lock_seconds: number;
+  /**
+   * How much voting power votes locked for `max_stake_lock_seconds` have, e.g. 400 is 4x. Votes which remain locked for less time have proportionally less. This is capped at [MAX_STAKE_LOCK_VOTE_MULTIPLIER_PERCENT].
+   *
+   * Proposals only pass before voting ends if they would still pass with this many times the vote supply voting, so a higher multiplier makes early passes rarer. Changing it also changes the voting power of votes which are already locked.
+   */
+  max_stake_lock_vote_multiplier_percent: number;
+  /**
+   * The most votes a single user may vote or propose with, any more are ignored. 0 disables this cap.
+   */
+  max_user_votes?: Uint128;
+  /**
+   * Like `max_user_votes`, but in basis points of the vote supply which turnout is measured against. 0 disables this cap. If both are set, the lower one applies. Like the votes they cap, both are square roots if quadratic voting is enabled.
+   */
+  max_user_votes_bps?: number;
+  min_proposal_expiry_time_seconds: number;
+  /**
+   * Newly staked votes can't be voted or proposed with until they've been staked for this long, so that votes can't be bought to sway a proposal within a single block. 0 makes them usable immediately.
+   */
+  min_stake_age_seconds: number;
   minimum_vote_pass_percent: number;
   minimum_vote_proposal_percent: number;
   minimum_vote_turnout_percent: number;
+  /**
+   * Optimistic proposals are rejected if the votes opposing or vetoing them are more than this percentage of the vote supply.
+   */
+  optimistic_opposition_threshold_percent: number;
+  /**
+   * If enabled, users vote and propose with the square root of their voting power, and turnout is measured against the square root of the staked votes, so `quorum_supply` must be `staked`. Votes split between many addresses count for more than if they were held by one, so this relies on each voter only using one address. As there's no bound on how many votes could still be cast, proposals can't pass before voting ends unless they're expedited.
+   */
+  quadratic_voting?: boolean;
+  /**
+   * ...voting on it is extended by this many seconds...
+   */
+  quiet_ending_extension_seconds: number;
+  /**
+   * If a vote cast within this many seconds of a proposal's expiry changes its outcome...
+   */
+  quiet_ending_window_seconds: number;
+  /**
+   * Which votes turnout is measured against, this must be `staked` if quadratic voting is enabled.
+   */
+  quorum_supply?: CourtQuorumSupply;
+  /**
+   * How long votes committed to private proposals may be revealed for once voting on them ends. 0 disables private proposals.
+   */
+  reveal_period_seconds?: number;
+  /**
+   * Proposals are vetoed if more than this percentage of votes cast are vetoes, 0 disables vetoing.
+   */
+  veto_threshold_percent: number;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtSignedVote".
+ */
+export interface CourtSignedVote {
+  /**
+   * Must be the voter's `SignedVoteNonce` for the proposal, which is incremented by every vote of theirs which is cast on it, signed or not. Voting directly therefore invalidates any signed votes which haven't been submitted yet. Nonces are kept per proposal so that a withheld signed vote only holds up later signed votes on the same proposal, and the voter can always vote on it themselves instead.
+   */
+  nonce: number;
+  /**
+   * The option of a multiple-choice proposal which is voted for, the first option is 0.
+   */
+  option?: number | null;
+  proposal_id: number;
+  pubkey?: Binary;
+  signature: Binary;
+  signature_type?: CourtVoteSignatureType;
+  vote: CourtUserVoteStatus;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "TransactionProposalMetadataJsonable".
+ */
+export interface TransactionProposalMetadataJsonable {
+  /**
+   * The SHA-256 hash of the content found at `content_uri`, so that it can be verified
+   */
+  content_sha256?: HexBinary | null;
+  /**
+   * Where the full text of the proposal can be found, e.g. an ipfs:// or https:// URI
+   */
+  content_uri?: string | null;
+  description?: string;
+  title: string;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtProposalOption".
+ */
+export interface CourtProposalOption {
+  label: string;
+  msgs: ProposedCourtMsgJsonable[];
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
@@ -360,16 +1168,95 @@ export interface CourtAppConfigJsonable {
 export interface CourtInstantiateMsg {
   admin: Addr;
   execution_expiry_time_seconds: number;
+  /**
+   * ...and this percentage of the votes cast approve.
+   */
+  expedited_minimum_vote_pass_percent?: number;
+  /**
+   * Expedited proposals pass once `expedited_voting_period_seconds` have passed if this percentage of the vote supply has voted...
+   */
+  expedited_minimum_vote_turnout_percent?: number;
+  /**
+   * 0 disables expedited proposals.
+   */
+  expedited_voting_period_seconds?: number;
   max_proposal_expiry_time_seconds: number;
+  /**
+   * ...up to this many times. 0 disables extensions.
+   */
+  max_quiet_ending_extensions?: number;
+  /**
+   * The longest users may lock their staked votes for, 0 disables locking.
+   */
+  max_stake_lock_seconds?: number;
+  /**
+   * How much voting power votes locked for `max_stake_lock_seconds` have, e.g. 400 is 4x. Votes which remain locked for less time have proportionally less.
+   */
+  max_stake_lock_vote_multiplier_percent?: number;
+  max_user_votes?: Uint128;
+  /**
+   * Like `max_user_votes`, but in basis points of the vote supply which turnout is measured against. 0 disables this cap. Like the votes they cap, both are square roots if quadratic voting is enabled.
+   */
+  max_user_votes_bps?: number;
+  min_proposal_expiry_time_seconds?: number;
+  /**
+   * Newly staked votes can't be voted or proposed with until they've been staked for this long. 0 makes them usable immediately.
+   */
+  min_stake_age_seconds?: number;
   minimum_vote_pass_percent: number;
   minimum_vote_proposal_percent: number;
   minimum_vote_turnout_percent: number;
+  /**
+   * Optimistic proposals are rejected if the votes opposing or vetoing them are more than this percentage of the vote supply. By default, any opposition rejects them.
+   */
+  optimistic_opposition_threshold_percent?: number;
+  /**
+   * If enabled, users vote and propose with the square root of their voting power, and turnout is measured against the square root of the staked votes, so `quorum_supply` must be `staked`. Votes split between many addresses count for more than if they were held by one, so this relies on each voter only using one address. As there's no bound on how many votes could still be cast, proposals can't pass before voting ends unless they're expedited.
+   */
+  quadratic_voting?: boolean;
+  /**
+   * ...voting on it is extended by this many seconds...
+   */
+  quiet_ending_extension_seconds?: number;
+  /**
+   * If a vote cast within this many seconds of a proposal's expiry changes its outcome...
+   */
+  quiet_ending_window_seconds?: number;
+  quorum_supply?: CourtQuorumSupply;
+  /**
+   * How long votes committed to private proposals may be revealed for once voting on them ends. 0 disables private proposals.
+   */
+  reveal_period_seconds?: number;
   shares_mint_amount: Uint128;
   shares_mint_receiver: Addr;
+  /**
+   * Proposals are vetoed if more than this percentage of votes cast are vetoes, 0 disables vetoing.
+   */
+  veto_threshold_percent?: number;
   vote_share_description: string;
   vote_share_name: string;
   vote_share_symbol: string;
 }
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryProposalFilter".
+ */
+export interface CourtQueryProposalFilter {
+  /**
+   * Only include proposals made by this user
+   */
+  proposer?: Addr | null;
+  /**
+   * Only include proposals with this status
+   *
+   * Unless `proposer` or `target` is also specified, or the status is `pending` at the current time, every proposal checked counts towards `limit`, even if it doesn't match. So fewer proposals may be returned even though more match, use the `next_after` cursor to continue from where the search stopped.
+   */
+  status?: TransactionProposalStatus | null;
+  /**
+   * Only include proposals with messages which interact with this contract, may be a 0x address.
+   */
+  target?: string | null;
+}
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "CourtQueryResponseDenom".
@@ -380,11 +1267,209 @@ export interface CourtQueryResponseDenom {
    */
   votes: string;
 }
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseOptimisticAllowlist".
+ */
+export interface CourtQueryResponseOptimisticAllowlist {
+  msg_kinds: string[];
+  proposers: Addr[];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseProposalOption".
+ */
+export interface CourtQueryResponseProposalOption {
+  label: string;
+  /**
+   * Empty once the proposal has been executed, the winning option's messages are moved to the proposal's messages
+   */
+  messages: ProposedCourtMsgJsonable[];
+  votes: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseProposalTally".
+ */
+export interface CourtQueryResponseProposalTally {
+  /**
+   * Votes for divided by votes for, against, and vetoes, zero if no one has voted either way
+   */
+  approval: Decimal;
+  /**
+   * Milliseconds until this proposal can no longer be executed, assuming it passes
+   */
+  execution_time_remaining_ms: number;
+  minimum_vote_pass_percent: number;
+  minimum_vote_turnout_percent: number;
+  /**
+   * The votes each option has, only present for multiple-choice proposals
+   */
+  option_votes?: Uint128[];
+  /**
+   * The status this proposal will have once voting ends, assuming no one else votes or changes their vote. For private proposals, this is once the reveal period ends and assumes no more votes are revealed.
+   */
+  projected_status: TransactionProposalStatus;
+  proposal_id: number;
+  status: TransactionProposalStatus;
+  /**
+   * Milliseconds until this proposal can be executed because of its track's timelock, assuming it passes
+   */
+  timelock_remaining_ms: number;
+  /**
+   * The vote supply which turnout is measured against as of when the proposal was made, or its square root if quadratic voting is enabled
+   */
+  total_supply: Uint128;
+  /**
+   * All votes cast divided by the total supply
+   */
+  turnout: Decimal;
+  /**
+   * Vetoes divided by all votes cast
+   */
+  veto: Decimal;
+  veto_threshold_percent: number;
+  votes_abstain: Uint128;
+  votes_against: Uint128;
+  votes_for: Uint128;
+  /**
+   * The additional opposing or veto votes needed to prevent this proposal from passing, null if there aren't enough uncast votes
+   */
+  votes_needed_to_block?: Uint128 | null;
+  /**
+   * The additional approving votes needed for this proposal to pass, null if there aren't enough uncast votes
+   */
+  votes_needed_to_pass?: Uint128 | null;
+  votes_veto: Uint128;
+  /**
+   * Milliseconds until voting ends
+   */
+  voting_time_remaining_ms: number;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseProposals".
+ */
+export interface CourtQueryResponseProposals {
+  /**
+   * If there may be more proposals, set `after` to this to get the next page. This may be set even if `proposals` is empty, as only so many proposals are checked when filtering by status.
+   */
+  next_after?: number | null;
+  proposals: CourtQueryResponseTransactionProposal[];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseTransactionProposal".
+ */
+export interface CourtQueryResponseTransactionProposal {
+  /**
+   * Only present when requested, see [ProposedCourtMsgJsonable::decode]
+   */
+  decoded_messages?: ProposedCourtMsgDecoded[] | null;
+  info: TransactionProposalInfoJsonable;
+  messages: ProposedCourtMsgJsonable[];
+  metadata?: TransactionProposalMetadataJsonable | null;
+  /**
+   * Only present for multiple-choice proposals
+   */
+  options?: CourtQueryResponseProposalOption[];
+  proposal_id: number;
+  status: TransactionProposalStatus;
+}
+/**
+ * Human-readable details of a proposed message, see [ProposedCourtMsgJsonable::decode]
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "ProposedCourtMsgDecoded".
+ */
+export interface ProposedCourtMsgDecoded {
+  /**
+   * The native coins this message sends or mints, amounts of usei are shown in sei
+   */
+  amounts: string[];
+  /**
+   * The payload of the message as a JSON value, if it has one which could be decoded. This is the `msg` of Wasm messages, the fields of stargate messages keyed by their protobuf field number, or the vote of governance messages.
+   */
+  msg_json?: unknown;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "TransactionProposalInfoJsonable".
+ */
+export interface TransactionProposalInfoJsonable {
+  execution_status: TransactionProposalExecutionStatus;
+  /**
+   * When an expedited proposal may pass early if it meets the higher expedited requirements, null if this isn't an expedited proposal.
+   */
+  expedited_expiry_timestamp_ms?: number | null;
+  expiry_timestamp_ms: number;
+  /**
+   * How many times voting on this proposal has been extended due to late votes changing its outcome.
+   */
+  extensions?: number;
+  /**
+   * Optimistic proposals pass once voting ends unless enough votes oppose or veto them, regardless of turnout.
+   */
+  is_optimistic?: boolean;
+  /**
+   * Signalling proposals have no messages and are never executed, they only record the opinion of the voters.
+   */
+  is_signal?: boolean;
+  /**
+   * The option of a multiple-choice proposal which has the most votes, null if there's a tie.
+   */
+  leading_option?: number | null;
+  /**
+   * The amount of options a multiple-choice proposal has, 0 for regular proposals.
+   *
+   * For multiple-choice proposals, `votes_for` are the votes of the leading option, and `votes_against` are the votes of all the other options.
+   */
+  option_count?: number;
+  proposer: Addr;
+  /**
+   * The vote supply which turnout is measured against, as of when this proposal was made. This is its square root if quadratic voting is enabled.
+   */
+  quorum_supply?: Uint128;
+  /**
+   * When the votes committed to a private proposal can no longer be revealed, null if this isn't a private proposal.
+   */
+  reveal_expiry_timestamp_ms?: number | null;
+  /**
+   * The index of the proposal track this proposal belongs to, null if it uses the voting config as-is.
+   */
+  track?: number | null;
+  votes_abstain: Uint128;
+  votes_against: Uint128;
+  votes_for: Uint128;
+  votes_veto: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "CourtQueryResponseSimulatedProposal".
+ */
+export interface CourtQueryResponseSimulatedProposal {
+  /**
+   * The messages which would be sent when the proposal is executed
+   */
+  cosmos_msgs: CosmosMsgFor_SeiMsg[];
+  /**
+   * The proposal's messages as they would be stored, with any addresses resolved
+   */
+  messages: ProposedCourtMsgJsonable[];
+  /**
+   * The index of the proposal track the proposal would belong to, see the `ProposalTracks` query
+   */
+  track?: number | null;
+}
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "CourtQueryResponseTotalSupply".
  */
 export interface CourtQueryResponseTotalSupply {
+  /**
+   * Voting shares which users have staked
+   */
+  staked_votes?: Uint128;
   /**
    * Total supply of voting shares
    */
@@ -395,5 +1480,25 @@ export interface CourtQueryResponseTotalSupply {
  * via the `definition` "CourtUserStatsJsonable".
  */
 export interface CourtUserStatsJsonable {
+  /**
+   * What the staked votes currently count for when voting or proposing, after applying the lock multiplier, quadratic voting, and the per-user cap. Only present in query responses.
+   */
+  effective_votes?: Uint128;
+  /**
+   * The staked votes can't be unstaked until this time, 0 if they were never locked.
+   */
+  lock_expiry_timestamp_ms?: number;
+  /**
+   * Votes which were staked too recently to vote or propose with.
+   */
+  pending_votes?: Uint128;
+  /**
+   * When votes were last staked while some were pending, the pending votes become usable once they've been staked for the configured `min_stake_age_seconds` since then. 0 if there are no pending votes.
+   */
+  pending_votes_timestamp_ms?: number;
   staked_votes: Uint128;
+  /**
+   * The voting power the staked votes currently have due to being locked, in percent. This decreases as `lock_expiry_timestamp_ms` approaches, and is 0 if they aren't locked. Only present in query responses.
+   */
+  vote_multiplier_percent?: number;
 }
@@ -39,5 +39,6 @@ export function isProposalFinalized(proposalStatus: TransactionProposalStatus) {
 	return proposalStatus == "executed" ||
 		proposalStatus == "execution_expired" ||
 		proposalStatus == "rejected" ||
-		proposalStatus == "rejected_or_expired";
+		proposalStatus == "rejected_or_expired" ||
+		proposalStatus == "vetoed";
 }